@@ -0,0 +1,93 @@
+// Compares streaming rendering with building a String per element.
+//
+// Run with `cargo bench --bench render`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use htmlatron::prelude::*;
+
+const ROWS: usize = 500;
+const COLUMNS: usize = 8;
+const ITERATIONS: u32 = 50;
+
+fn table() -> Element {
+    let rows: Vec<Element> = (0..ROWS)
+        .map(|row| {
+            let cells: Vec<Element> = (0..COLUMNS)
+                .map(|column| {
+                    ElementBuilder::new(Tag::Td)
+                        .attrs(Attrs::new().class(vec!["cell"]).build())
+                        .children(vec![ElementBuilder::new(Tag::Span)
+                            .content(format!("row {row} column {column} & more"))
+                            .build()])
+                        .build()
+                })
+                .collect();
+            ElementBuilder::new(Tag::Tr).children(cells).build()
+        })
+        .collect();
+    // Nest the table a few levels deep, like a real page layout.
+    let mut element =
+        ElementBuilder::new(Tag::Table)
+            .children(vec![ElementBuilder::new(Tag::Tbody).children(rows).build()]);
+    for _ in 0..6 {
+        element = ElementBuilder::new(Tag::Div).children(vec![element.build()]);
+    }
+    ElementBuilder::new(Tag::Body)
+        .children(vec![element.build()])
+        .build()
+}
+
+// The previous approach: every element renders its children into a new String.
+fn nested_strings(element: &Element) -> String {
+    let attributes = element
+        .attrs
+        .as_ref()
+        .map_or(vec![], |attrs| attrs.get_attrs(&element.tag));
+    let open_tag = Element::open_tag(&element.tag, attributes.join(" "));
+    let content = element
+        .content
+        .as_ref()
+        .map_or(String::new(), |content| escape_text(content).into_owned());
+    let children: String = element.children.as_ref().map_or(String::new(), |children| {
+        children.iter().map(nested_strings).collect()
+    });
+    let close_tag = Element::close_tag(&element.tag);
+    format!("{open_tag}{content}{children}{close_tag}")
+}
+
+fn bench(name: &str, mut f: impl FnMut() -> usize) -> Duration {
+    // Warm up.
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+    println!("{name:<24} {elapsed:>12.2?} per render");
+    elapsed
+}
+
+fn main() {
+    let page = table();
+    assert_eq!(nested_strings(&page), page.to_string());
+
+    let baseline = bench("nested strings", || nested_strings(&page).len());
+    bench("to_string", || page.to_string().len());
+    let mut buffer = String::new();
+    let streaming = bench("render_to (reused)", || {
+        buffer.clear();
+        page.render_to(&mut buffer).unwrap();
+        buffer.len()
+    });
+    let mut sink = std::io::sink();
+    bench("render_io (sink)", || {
+        page.render_io(&mut sink).unwrap();
+        0
+    });
+
+    println!(
+        "render_to is {:.1}x faster than nested strings",
+        baseline.as_secs_f64() / streaming.as_secs_f64()
+    );
+}
@@ -0,0 +1,363 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, Attribute, Data, DataEnum, DeriveInput, Error, Expr, ExprLit, Fields,
+    FieldsNamed, Ident, Lit, LitStr, Meta, Result,
+};
+
+// Derive `HtmlForm` for a struct with named fields.
+//
+// Struct options, set with `#[html_form(...)]`:
+// - `action = "/path"`, the URL the form is submitted to.
+// - `method = "get"`, one of `get`, `post` (the default) or `dialog`.
+// - `submit = "Save"`, the text of the submit button.
+//
+// Field options:
+// - `label = "..."`, the label text. It defaults to the doc comment, or the
+//   name written as a sentence.
+// - `input = "email"`, the input type, in place of the one for the field type.
+// - `placeholder = "..."`, the placeholder of the input.
+// - `skip`, leaving the field out of the form.
+#[proc_macro_derive(HtmlForm, attributes(html_form))]
+pub fn derive_html_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let expanded = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => derive_struct(&input, fields),
+            _ => Err(Error::new_spanned(
+                &input.ident,
+                "HtmlForm can only be derived for structs with named fields",
+            )),
+        },
+        Data::Enum(_) => Err(Error::new_spanned(
+            &input.ident,
+            "HtmlForm cannot be derived for enums, derive FormValue to edit one with a select",
+        )),
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "HtmlForm cannot be derived for unions",
+        )),
+    };
+    expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+// Derive `FormValue` for an enum with unit variants, which is edited with a
+// `select`.
+//
+// Variant options, set with `#[html_form(...)]`:
+// - `label = "..."`, the option text. It defaults to the doc comment, or the
+//   name written as a sentence.
+// - `value = "..."`, the submitted value, which defaults to the variant name
+//   in snake case.
+#[proc_macro_derive(FormValue, attributes(html_form))]
+pub fn derive_form_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let expanded = match &input.data {
+        Data::Enum(data) => derive_enum(&input, data),
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            "FormValue can only be derived for enums with unit variants",
+        )),
+    };
+    expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+// Where `#[html_form(...)]` is written, which decides the options it takes.
+#[derive(Clone, Copy)]
+enum Place {
+    Struct,
+    Field,
+    Enum,
+    Variant,
+}
+
+impl Place {
+    fn allows(self, key: &str) -> bool {
+        let keys: &[&str] = match self {
+            Place::Struct => &["action", "method", "submit"],
+            Place::Field => &["label", "input", "placeholder", "skip"],
+            Place::Enum => &[],
+            Place::Variant => &["label", "value"],
+        };
+        keys.contains(&key)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Place::Struct => "a struct",
+            Place::Field => "a field",
+            Place::Enum => "an enum",
+            Place::Variant => "a variant",
+        }
+    }
+}
+
+// The `#[html_form(...)]` options of a struct, field or variant.
+#[derive(Default)]
+struct Options {
+    action: Option<LitStr>,
+    method: Option<LitStr>,
+    submit: Option<LitStr>,
+    label: Option<LitStr>,
+    input: Option<LitStr>,
+    placeholder: Option<LitStr>,
+    value: Option<LitStr>,
+    skip: bool,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute], place: Place) -> Result<Self> {
+        let mut options = Options::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("html_form"))
+        {
+            attr.parse_nested_meta(|meta| {
+                let key = meta
+                    .path
+                    .get_ident()
+                    .map(Ident::to_string)
+                    .unwrap_or_default();
+                let known = [Place::Struct, Place::Field, Place::Variant]
+                    .iter()
+                    .any(|place| place.allows(&key));
+                if known && !place.allows(&key) {
+                    return Err(meta.error(format_args!(
+                        "`{key}` is not an html_form option of {}",
+                        place.name()
+                    )));
+                }
+                let slot = match key.as_str() {
+                    "skip" => {
+                        options.skip = true;
+                        return Ok(());
+                    }
+                    "action" => &mut options.action,
+                    "method" => &mut options.method,
+                    "submit" => &mut options.submit,
+                    "label" => &mut options.label,
+                    "input" => &mut options.input,
+                    "placeholder" => &mut options.placeholder,
+                    "value" => &mut options.value,
+                    _ => return Err(meta.error("unknown html_form option")),
+                };
+                *slot = Some(meta.value()?.parse()?);
+                Ok(())
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+fn derive_struct(input: &DeriveInput, fields: &FieldsNamed) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let options = Options::parse(&input.attrs, Place::Struct)?;
+
+    let mut controls = vec![];
+    for field in &fields.named {
+        let field_options = Options::parse(&field.attrs, Place::Field)?;
+        if field_options.skip {
+            continue;
+        }
+        let name = field.ident.as_ref().expect("named field");
+        let id = name.to_string();
+        let ty = &field.ty;
+        let label = label(&field_options, &field.attrs, &id);
+        let input = match &field_options.input {
+            Some(input) => {
+                let typ = input_type(input)?;
+                quote!(.input(::htmlatron::form::InputType::#typ))
+            }
+            None => quote!(),
+        };
+        let attrs = match &field_options.placeholder {
+            Some(placeholder) => {
+                quote!(.attrs(::htmlatron::attribute::Attrs::new().placeholder(#placeholder)))
+            }
+            None => quote!(),
+        };
+        controls.push(quote! {
+            let field = ::htmlatron::form::Field::new(#id, #label)
+                .required(<#ty as ::htmlatron::form::FormValue>::required());
+            let field = <#ty as ::htmlatron::form::FormValue>::field(
+                ::std::option::Option::Some(&self.#name),
+                field,
+            )
+            #input
+            #attrs;
+            let field = errors
+                .get(#id)
+                .iter()
+                .fold(field, |field, message| field.error(message.clone()));
+            children.extend(field.build());
+        });
+    }
+
+    let method = match &options.method {
+        Some(method) => method_variant(method)?,
+        None => Ident::new("Post", Span::call_site()),
+    };
+    let action = match &options.action {
+        Some(action) => quote!(.action(#action)),
+        None => quote!(),
+    };
+    let submit = match &options.submit {
+        Some(submit) => submit.clone(),
+        None => LitStr::new("Submit", Span::call_site()),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::htmlatron::form::HtmlForm for #ident #ty_generics #where_clause {
+            fn html_form_with_errors(
+                &self,
+                errors: &::htmlatron::form::FormErrors,
+            ) -> ::htmlatron::element::Element {
+                let mut children = ::std::vec::Vec::new();
+                #(#controls)*
+                children.push(
+                    ::htmlatron::element::ElementBuilder::new(::htmlatron::tag::Tag::Button)
+                        .attrs(
+                            ::htmlatron::attribute::Attrs::new()
+                                .typ(::htmlatron::form::InputType::Submit)
+                                .build(),
+                        )
+                        .content(#submit)
+                        .build(),
+                );
+                ::htmlatron::element::ElementBuilder::new(::htmlatron::tag::Tag::Form)
+                    .attrs(
+                        ::htmlatron::attribute::Attrs::new()
+                            .method(::htmlatron::form::Method::#method)
+                            #action
+                            .build(),
+                    )
+                    .children(children)
+                    .build()
+            }
+        }
+    })
+}
+
+fn derive_enum(input: &DeriveInput, data: &DataEnum) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Options::parse(&input.attrs, Place::Enum)?;
+
+    let mut options = vec![];
+    let mut arms = vec![];
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "FormValue can only be derived for enums with unit variants",
+            ));
+        }
+        let variant_options = Options::parse(&variant.attrs, Place::Variant)?;
+        let name = &variant.ident;
+        let value = match &variant_options.value {
+            Some(value) => value.value(),
+            None => snake_case(&name.to_string()),
+        };
+        let label = label(&variant_options, &variant.attrs, &value);
+        options.push(quote!((#value, #label)));
+        arms.push(quote!(#ident::#name => #value));
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::htmlatron::form::FormValue for #ident #ty_generics #where_clause {
+            fn field(
+                value: ::std::option::Option<&Self>,
+                field: ::htmlatron::form::FieldBuilder,
+            ) -> ::htmlatron::form::FieldBuilder {
+                let field = field.select([#(#options),*]);
+                match value {
+                    ::std::option::Option::Some(value) => field.value(match value {
+                        #(#arms),*
+                    }),
+                    ::std::option::Option::None => field,
+                }
+            }
+        }
+    })
+}
+
+// The label from the options, the doc comment or the name, in that order.
+fn label(options: &Options, attrs: &[Attribute], name: &str) -> String {
+    if let Some(label) = &options.label {
+        return label.value();
+    }
+    let doc: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(doc), ..
+                }) => Some(doc.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+    if !doc.is_empty() {
+        return doc.join(" ");
+    }
+    // `first_name` becomes "First name".
+    let words = name.replace('_', " ");
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// `datetime-local` becomes `DatetimeLocal`.
+fn input_type(input: &LitStr) -> Result<Ident> {
+    let camel: String = input
+        .value()
+        .split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    syn::parse_str::<Ident>(&camel)
+        .map(|ident| Ident::new(&ident.to_string(), input.span()))
+        .map_err(|_| Error::new_spanned(input, "invalid input type"))
+}
+
+fn method_variant(method: &LitStr) -> Result<Ident> {
+    let variant = match method.value().to_ascii_lowercase().as_str() {
+        "get" => "Get",
+        "post" => "Post",
+        "dialog" => "Dialog",
+        _ => {
+            return Err(Error::new_spanned(
+                method,
+                "method must be \"get\", \"post\" or \"dialog\"",
+            ))
+        }
+    };
+    Ok(Ident::new(variant, method.span()))
+}
+
+// `ReadOnly` becomes `read_only`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
@@ -0,0 +1,551 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::{Attr, AttrsBuilder};
+
+// WAI-ARIA roles, for the `role` attribute. Abstract roles are left out as
+// they cannot be used in content.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Role {
+    Alert,
+    AlertDialog,
+    Application,
+    Article,
+    Banner,
+    Blockquote,
+    Button,
+    Caption,
+    Cell,
+    Checkbox,
+    Code,
+    ColumnHeader,
+    ComboBox,
+    Complementary,
+    ContentInfo,
+    Definition,
+    Deletion,
+    Dialog,
+    Document,
+    Emphasis,
+    Feed,
+    Figure,
+    Form,
+    Generic,
+    Grid,
+    GridCell,
+    Group,
+    Heading,
+    Img,
+    Insertion,
+    Link,
+    List,
+    ListBox,
+    ListItem,
+    Log,
+    Main,
+    Marquee,
+    Math,
+    Menu,
+    MenuBar,
+    MenuItem,
+    MenuItemCheckbox,
+    MenuItemRadio,
+    Meter,
+    Navigation,
+    None,
+    Note,
+    Option,
+    Paragraph,
+    Presentation,
+    ProgressBar,
+    Radio,
+    RadioGroup,
+    Region,
+    Row,
+    RowGroup,
+    RowHeader,
+    ScrollBar,
+    Search,
+    SearchBox,
+    Separator,
+    Slider,
+    SpinButton,
+    Status,
+    Strong,
+    Subscript,
+    Superscript,
+    Switch,
+    Tab,
+    Table,
+    TabList,
+    TabPanel,
+    Term,
+    TextBox,
+    Time,
+    Timer,
+    Toolbar,
+    Tooltip,
+    Tree,
+    TreeGrid,
+    TreeItem,
+}
+impl Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::Alert => write!(f, "alert"),
+            Role::AlertDialog => write!(f, "alertdialog"),
+            Role::Application => write!(f, "application"),
+            Role::Article => write!(f, "article"),
+            Role::Banner => write!(f, "banner"),
+            Role::Blockquote => write!(f, "blockquote"),
+            Role::Button => write!(f, "button"),
+            Role::Caption => write!(f, "caption"),
+            Role::Cell => write!(f, "cell"),
+            Role::Checkbox => write!(f, "checkbox"),
+            Role::Code => write!(f, "code"),
+            Role::ColumnHeader => write!(f, "columnheader"),
+            Role::ComboBox => write!(f, "combobox"),
+            Role::Complementary => write!(f, "complementary"),
+            Role::ContentInfo => write!(f, "contentinfo"),
+            Role::Definition => write!(f, "definition"),
+            Role::Deletion => write!(f, "deletion"),
+            Role::Dialog => write!(f, "dialog"),
+            Role::Document => write!(f, "document"),
+            Role::Emphasis => write!(f, "emphasis"),
+            Role::Feed => write!(f, "feed"),
+            Role::Figure => write!(f, "figure"),
+            Role::Form => write!(f, "form"),
+            Role::Generic => write!(f, "generic"),
+            Role::Grid => write!(f, "grid"),
+            Role::GridCell => write!(f, "gridcell"),
+            Role::Group => write!(f, "group"),
+            Role::Heading => write!(f, "heading"),
+            Role::Img => write!(f, "img"),
+            Role::Insertion => write!(f, "insertion"),
+            Role::Link => write!(f, "link"),
+            Role::List => write!(f, "list"),
+            Role::ListBox => write!(f, "listbox"),
+            Role::ListItem => write!(f, "listitem"),
+            Role::Log => write!(f, "log"),
+            Role::Main => write!(f, "main"),
+            Role::Marquee => write!(f, "marquee"),
+            Role::Math => write!(f, "math"),
+            Role::Menu => write!(f, "menu"),
+            Role::MenuBar => write!(f, "menubar"),
+            Role::MenuItem => write!(f, "menuitem"),
+            Role::MenuItemCheckbox => write!(f, "menuitemcheckbox"),
+            Role::MenuItemRadio => write!(f, "menuitemradio"),
+            Role::Meter => write!(f, "meter"),
+            Role::Navigation => write!(f, "navigation"),
+            Role::None => write!(f, "none"),
+            Role::Note => write!(f, "note"),
+            Role::Option => write!(f, "option"),
+            Role::Paragraph => write!(f, "paragraph"),
+            Role::Presentation => write!(f, "presentation"),
+            Role::ProgressBar => write!(f, "progressbar"),
+            Role::Radio => write!(f, "radio"),
+            Role::RadioGroup => write!(f, "radiogroup"),
+            Role::Region => write!(f, "region"),
+            Role::Row => write!(f, "row"),
+            Role::RowGroup => write!(f, "rowgroup"),
+            Role::RowHeader => write!(f, "rowheader"),
+            Role::ScrollBar => write!(f, "scrollbar"),
+            Role::Search => write!(f, "search"),
+            Role::SearchBox => write!(f, "searchbox"),
+            Role::Separator => write!(f, "separator"),
+            Role::Slider => write!(f, "slider"),
+            Role::SpinButton => write!(f, "spinbutton"),
+            Role::Status => write!(f, "status"),
+            Role::Strong => write!(f, "strong"),
+            Role::Subscript => write!(f, "subscript"),
+            Role::Superscript => write!(f, "superscript"),
+            Role::Switch => write!(f, "switch"),
+            Role::Tab => write!(f, "tab"),
+            Role::Table => write!(f, "table"),
+            Role::TabList => write!(f, "tablist"),
+            Role::TabPanel => write!(f, "tabpanel"),
+            Role::Term => write!(f, "term"),
+            Role::TextBox => write!(f, "textbox"),
+            Role::Time => write!(f, "time"),
+            Role::Timer => write!(f, "timer"),
+            Role::Toolbar => write!(f, "toolbar"),
+            Role::Tooltip => write!(f, "tooltip"),
+            Role::Tree => write!(f, "tree"),
+            Role::TreeGrid => write!(f, "treegrid"),
+            Role::TreeItem => write!(f, "treeitem"),
+        }
+    }
+}
+impl FromStr for Role {
+    type Err = UnknownRole;
+
+    // The reverse of `Display`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let role = match s.to_ascii_lowercase().as_str() {
+            "alert" => Role::Alert,
+            "alertdialog" => Role::AlertDialog,
+            "application" => Role::Application,
+            "article" => Role::Article,
+            "banner" => Role::Banner,
+            "blockquote" => Role::Blockquote,
+            "button" => Role::Button,
+            "caption" => Role::Caption,
+            "cell" => Role::Cell,
+            "checkbox" => Role::Checkbox,
+            "code" => Role::Code,
+            "columnheader" => Role::ColumnHeader,
+            "combobox" => Role::ComboBox,
+            "complementary" => Role::Complementary,
+            "contentinfo" => Role::ContentInfo,
+            "definition" => Role::Definition,
+            "deletion" => Role::Deletion,
+            "dialog" => Role::Dialog,
+            "document" => Role::Document,
+            "emphasis" => Role::Emphasis,
+            "feed" => Role::Feed,
+            "figure" => Role::Figure,
+            "form" => Role::Form,
+            "generic" => Role::Generic,
+            "grid" => Role::Grid,
+            "gridcell" => Role::GridCell,
+            "group" => Role::Group,
+            "heading" => Role::Heading,
+            "img" => Role::Img,
+            "insertion" => Role::Insertion,
+            "link" => Role::Link,
+            "list" => Role::List,
+            "listbox" => Role::ListBox,
+            "listitem" => Role::ListItem,
+            "log" => Role::Log,
+            "main" => Role::Main,
+            "marquee" => Role::Marquee,
+            "math" => Role::Math,
+            "menu" => Role::Menu,
+            "menubar" => Role::MenuBar,
+            "menuitem" => Role::MenuItem,
+            "menuitemcheckbox" => Role::MenuItemCheckbox,
+            "menuitemradio" => Role::MenuItemRadio,
+            "meter" => Role::Meter,
+            "navigation" => Role::Navigation,
+            "none" => Role::None,
+            "note" => Role::Note,
+            "option" => Role::Option,
+            "paragraph" => Role::Paragraph,
+            "presentation" => Role::Presentation,
+            "progressbar" => Role::ProgressBar,
+            "radio" => Role::Radio,
+            "radiogroup" => Role::RadioGroup,
+            "region" => Role::Region,
+            "row" => Role::Row,
+            "rowgroup" => Role::RowGroup,
+            "rowheader" => Role::RowHeader,
+            "scrollbar" => Role::ScrollBar,
+            "search" => Role::Search,
+            "searchbox" => Role::SearchBox,
+            "separator" => Role::Separator,
+            "slider" => Role::Slider,
+            "spinbutton" => Role::SpinButton,
+            "status" => Role::Status,
+            "strong" => Role::Strong,
+            "subscript" => Role::Subscript,
+            "superscript" => Role::Superscript,
+            "switch" => Role::Switch,
+            "tab" => Role::Tab,
+            "table" => Role::Table,
+            "tablist" => Role::TabList,
+            "tabpanel" => Role::TabPanel,
+            "term" => Role::Term,
+            "textbox" => Role::TextBox,
+            "time" => Role::Time,
+            "timer" => Role::Timer,
+            "toolbar" => Role::Toolbar,
+            "tooltip" => Role::Tooltip,
+            "tree" => Role::Tree,
+            "treegrid" => Role::TreeGrid,
+            "treeitem" => Role::TreeItem,
+            _ => return Err(UnknownRole(s.to_string())),
+        };
+        Ok(role)
+    }
+}
+impl From<Role> for String {
+    fn from(role: Role) -> Self {
+        role.to_string()
+    }
+}
+
+// Error returned when a name does not match a `Role` variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownRole(pub String);
+
+impl Display for UnknownRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown ARIA role '{}'", self.0)
+    }
+}
+impl std::error::Error for UnknownRole {}
+
+// Values for `aria-checked` and `aria-pressed`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tristate {
+    True,
+    False,
+    Mixed,
+}
+impl Display for Tristate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tristate::True => write!(f, "true"),
+            Tristate::False => write!(f, "false"),
+            Tristate::Mixed => write!(f, "mixed"),
+        }
+    }
+}
+impl From<bool> for Tristate {
+    fn from(value: bool) -> Self {
+        match value {
+            true => Tristate::True,
+            false => Tristate::False,
+        }
+    }
+}
+
+// Values for `aria-current`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AriaCurrent {
+    Page,
+    Step,
+    Location,
+    Date,
+    Time,
+    True,
+    False,
+}
+impl Display for AriaCurrent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AriaCurrent::Page => write!(f, "page"),
+            AriaCurrent::Step => write!(f, "step"),
+            AriaCurrent::Location => write!(f, "location"),
+            AriaCurrent::Date => write!(f, "date"),
+            AriaCurrent::Time => write!(f, "time"),
+            AriaCurrent::True => write!(f, "true"),
+            AriaCurrent::False => write!(f, "false"),
+        }
+    }
+}
+
+// Values for `aria-haspopup`: the kind of popup the element opens, where
+// `True` means a menu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HasPopup {
+    True,
+    False,
+    Menu,
+    Listbox,
+    Tree,
+    Grid,
+    Dialog,
+}
+impl Display for HasPopup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HasPopup::True => write!(f, "true"),
+            HasPopup::False => write!(f, "false"),
+            HasPopup::Menu => write!(f, "menu"),
+            HasPopup::Listbox => write!(f, "listbox"),
+            HasPopup::Tree => write!(f, "tree"),
+            HasPopup::Grid => write!(f, "grid"),
+            HasPopup::Dialog => write!(f, "dialog"),
+        }
+    }
+}
+impl From<bool> for HasPopup {
+    fn from(value: bool) -> Self {
+        match value {
+            true => HasPopup::True,
+            false => HasPopup::False,
+        }
+    }
+}
+
+// Values for `aria-live`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AriaLive {
+    Off,
+    Polite,
+    Assertive,
+}
+impl Display for AriaLive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AriaLive::Off => write!(f, "off"),
+            AriaLive::Polite => write!(f, "polite"),
+            AriaLive::Assertive => write!(f, "assertive"),
+        }
+    }
+}
+
+// ARIA builder methods, each setting the matching `aria-*` attribute.
+impl AttrsBuilder {
+    pub fn role(self, role: Role) -> Self {
+        self.attr(Attr::Role.to_string(), role)
+    }
+
+    // Any `aria-*` attribute, for those without a typed method.
+    pub fn aria(self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attr(format!("{}-{}", Attr::Aria, name.into()), value)
+    }
+
+    pub fn aria_label(self, label: impl Into<String>) -> Self {
+        self.aria("label", label)
+    }
+
+    // The ids of the elements that label this one.
+    pub fn aria_labelledby(self, ids: impl Into<String>) -> Self {
+        self.aria("labelledby", ids)
+    }
+
+    // The ids of the elements that describe this one.
+    pub fn aria_describedby(self, ids: impl Into<String>) -> Self {
+        self.aria("describedby", ids)
+    }
+
+    pub fn aria_description(self, description: impl Into<String>) -> Self {
+        self.aria("description", description)
+    }
+
+    // The ids of the elements this one controls.
+    pub fn aria_controls(self, ids: impl Into<String>) -> Self {
+        self.aria("controls", ids)
+    }
+
+    pub fn aria_owns(self, ids: impl Into<String>) -> Self {
+        self.aria("owns", ids)
+    }
+
+    pub fn aria_errormessage(self, id: impl Into<String>) -> Self {
+        self.aria("errormessage", id)
+    }
+
+    pub fn aria_current(self, current: AriaCurrent) -> Self {
+        self.aria("current", current.to_string())
+    }
+
+    pub fn aria_live(self, live: AriaLive) -> Self {
+        self.aria("live", live.to_string())
+    }
+
+    pub fn aria_checked(self, checked: impl Into<Tristate>) -> Self {
+        self.aria("checked", checked.into().to_string())
+    }
+
+    pub fn aria_pressed(self, pressed: impl Into<Tristate>) -> Self {
+        self.aria("pressed", pressed.into().to_string())
+    }
+
+    pub fn aria_atomic(self, atomic: bool) -> Self {
+        self.aria("atomic", atomic.to_string())
+    }
+
+    pub fn aria_busy(self, busy: bool) -> Self {
+        self.aria("busy", busy.to_string())
+    }
+
+    pub fn aria_disabled(self, disabled: bool) -> Self {
+        self.aria("disabled", disabled.to_string())
+    }
+
+    pub fn aria_expanded(self, expanded: bool) -> Self {
+        self.aria("expanded", expanded.to_string())
+    }
+
+    pub fn aria_hidden(self, hidden: bool) -> Self {
+        self.aria("hidden", hidden.to_string())
+    }
+
+    pub fn aria_invalid(self, invalid: bool) -> Self {
+        self.aria("invalid", invalid.to_string())
+    }
+
+    pub fn aria_modal(self, modal: bool) -> Self {
+        self.aria("modal", modal.to_string())
+    }
+
+    pub fn aria_readonly(self, readonly: bool) -> Self {
+        self.aria("readonly", readonly.to_string())
+    }
+
+    pub fn aria_required(self, required: bool) -> Self {
+        self.aria("required", required.to_string())
+    }
+
+    pub fn aria_selected(self, selected: bool) -> Self {
+        self.aria("selected", selected.to_string())
+    }
+
+    pub fn aria_haspopup(self, popup: HasPopup) -> Self {
+        self.aria("haspopup", popup.to_string())
+    }
+
+    pub fn aria_level(self, level: u8) -> Self {
+        self.aria("level", level.to_string())
+    }
+
+    pub fn aria_valuemin(self, min: f64) -> Self {
+        self.aria("valuemin", min.to_string())
+    }
+
+    pub fn aria_valuemax(self, max: f64) -> Self {
+        self.aria("valuemax", max.to_string())
+    }
+
+    pub fn aria_valuenow(self, now: f64) -> Self {
+        self.aria("valuenow", now.to_string())
+    }
+
+    pub fn aria_valuetext(self, text: impl Into<String>) -> Self {
+        self.aria("valuetext", text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_role_display_and_from_str() {
+        assert_eq!(Role::AlertDialog.to_string(), "alertdialog");
+        assert_eq!("Navigation".parse::<Role>(), Ok(Role::Navigation));
+        assert_eq!(
+            "widget".parse::<Role>(),
+            Err(UnknownRole("widget".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_aria_attributes() {
+        let attrs = Attrs::new()
+            .role(Role::Switch)
+            .aria_checked(Tristate::Mixed)
+            .aria_label("Dark mode")
+            .aria_hidden(false)
+            .aria("keyshortcuts", "d")
+            .aria_haspopup(HasPopup::Dialog)
+            .build();
+
+        assert_eq!(
+            attrs.get_attrs(&Tag::Button),
+            vec![
+                r#"role="switch""#,
+                r#"aria-checked="mixed""#,
+                r#"aria-label="Dark mode""#,
+                r#"aria-hidden="false""#,
+                r#"aria-keyshortcuts="d""#,
+                r#"aria-haspopup="dialog""#,
+            ]
+        );
+        assert_eq!(attrs.present(), vec![Attr::Role, Attr::Aria]);
+    }
+}
@@ -0,0 +1,382 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::{Attr, AttrsBuilder};
+
+// DOM events, for the `on*` event handler attributes such as `onkeydown`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    Abort,
+    AfterPrint,
+    AnimationEnd,
+    AnimationIteration,
+    AnimationStart,
+    AuxClick,
+    BeforeInput,
+    BeforePrint,
+    BeforeUnload,
+    Blur,
+    Cancel,
+    CanPlay,
+    CanPlayThrough,
+    Change,
+    Click,
+    Close,
+    ContextMenu,
+    Copy,
+    Cut,
+    DblClick,
+    Drag,
+    DragEnd,
+    DragEnter,
+    DragLeave,
+    DragOver,
+    DragStart,
+    Drop,
+    DurationChange,
+    Ended,
+    Error,
+    Focus,
+    FocusIn,
+    FocusOut,
+    FullscreenChange,
+    HashChange,
+    Input,
+    Invalid,
+    KeyDown,
+    KeyUp,
+    Load,
+    LoadedData,
+    LoadedMetadata,
+    LoadStart,
+    Message,
+    MouseDown,
+    MouseEnter,
+    MouseLeave,
+    MouseMove,
+    MouseOut,
+    MouseOver,
+    MouseUp,
+    Offline,
+    Online,
+    PageHide,
+    PageShow,
+    Paste,
+    Pause,
+    Play,
+    Playing,
+    PointerCancel,
+    PointerDown,
+    PointerEnter,
+    PointerLeave,
+    PointerMove,
+    PointerOut,
+    PointerOver,
+    PointerUp,
+    PopState,
+    Progress,
+    RateChange,
+    Reset,
+    Resize,
+    Scroll,
+    ScrollEnd,
+    Seeked,
+    Seeking,
+    Select,
+    SelectionChange,
+    Stalled,
+    Storage,
+    Submit,
+    Suspend,
+    TimeUpdate,
+    Toggle,
+    TouchCancel,
+    TouchEnd,
+    TouchMove,
+    TouchStart,
+    TransitionEnd,
+    Unload,
+    VolumeChange,
+    Waiting,
+    Wheel,
+}
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Event::Abort => "abort",
+            Event::AfterPrint => "afterprint",
+            Event::AnimationEnd => "animationend",
+            Event::AnimationIteration => "animationiteration",
+            Event::AnimationStart => "animationstart",
+            Event::AuxClick => "auxclick",
+            Event::BeforeInput => "beforeinput",
+            Event::BeforePrint => "beforeprint",
+            Event::BeforeUnload => "beforeunload",
+            Event::Blur => "blur",
+            Event::Cancel => "cancel",
+            Event::CanPlay => "canplay",
+            Event::CanPlayThrough => "canplaythrough",
+            Event::Change => "change",
+            Event::Click => "click",
+            Event::Close => "close",
+            Event::ContextMenu => "contextmenu",
+            Event::Copy => "copy",
+            Event::Cut => "cut",
+            Event::DblClick => "dblclick",
+            Event::Drag => "drag",
+            Event::DragEnd => "dragend",
+            Event::DragEnter => "dragenter",
+            Event::DragLeave => "dragleave",
+            Event::DragOver => "dragover",
+            Event::DragStart => "dragstart",
+            Event::Drop => "drop",
+            Event::DurationChange => "durationchange",
+            Event::Ended => "ended",
+            Event::Error => "error",
+            Event::Focus => "focus",
+            Event::FocusIn => "focusin",
+            Event::FocusOut => "focusout",
+            Event::FullscreenChange => "fullscreenchange",
+            Event::HashChange => "hashchange",
+            Event::Input => "input",
+            Event::Invalid => "invalid",
+            Event::KeyDown => "keydown",
+            Event::KeyUp => "keyup",
+            Event::Load => "load",
+            Event::LoadedData => "loadeddata",
+            Event::LoadedMetadata => "loadedmetadata",
+            Event::LoadStart => "loadstart",
+            Event::Message => "message",
+            Event::MouseDown => "mousedown",
+            Event::MouseEnter => "mouseenter",
+            Event::MouseLeave => "mouseleave",
+            Event::MouseMove => "mousemove",
+            Event::MouseOut => "mouseout",
+            Event::MouseOver => "mouseover",
+            Event::MouseUp => "mouseup",
+            Event::Offline => "offline",
+            Event::Online => "online",
+            Event::PageHide => "pagehide",
+            Event::PageShow => "pageshow",
+            Event::Paste => "paste",
+            Event::Pause => "pause",
+            Event::Play => "play",
+            Event::Playing => "playing",
+            Event::PointerCancel => "pointercancel",
+            Event::PointerDown => "pointerdown",
+            Event::PointerEnter => "pointerenter",
+            Event::PointerLeave => "pointerleave",
+            Event::PointerMove => "pointermove",
+            Event::PointerOut => "pointerout",
+            Event::PointerOver => "pointerover",
+            Event::PointerUp => "pointerup",
+            Event::PopState => "popstate",
+            Event::Progress => "progress",
+            Event::RateChange => "ratechange",
+            Event::Reset => "reset",
+            Event::Resize => "resize",
+            Event::Scroll => "scroll",
+            Event::ScrollEnd => "scrollend",
+            Event::Seeked => "seeked",
+            Event::Seeking => "seeking",
+            Event::Select => "select",
+            Event::SelectionChange => "selectionchange",
+            Event::Stalled => "stalled",
+            Event::Storage => "storage",
+            Event::Submit => "submit",
+            Event::Suspend => "suspend",
+            Event::TimeUpdate => "timeupdate",
+            Event::Toggle => "toggle",
+            Event::TouchCancel => "touchcancel",
+            Event::TouchEnd => "touchend",
+            Event::TouchMove => "touchmove",
+            Event::TouchStart => "touchstart",
+            Event::TransitionEnd => "transitionend",
+            Event::Unload => "unload",
+            Event::VolumeChange => "volumechange",
+            Event::Waiting => "waiting",
+            Event::Wheel => "wheel",
+        };
+        f.write_str(name)
+    }
+}
+impl FromStr for Event {
+    type Err = UnknownEvent;
+
+    // The reverse of `Display`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let event = match s.to_ascii_lowercase().as_str() {
+            "abort" => Event::Abort,
+            "afterprint" => Event::AfterPrint,
+            "animationend" => Event::AnimationEnd,
+            "animationiteration" => Event::AnimationIteration,
+            "animationstart" => Event::AnimationStart,
+            "auxclick" => Event::AuxClick,
+            "beforeinput" => Event::BeforeInput,
+            "beforeprint" => Event::BeforePrint,
+            "beforeunload" => Event::BeforeUnload,
+            "blur" => Event::Blur,
+            "cancel" => Event::Cancel,
+            "canplay" => Event::CanPlay,
+            "canplaythrough" => Event::CanPlayThrough,
+            "change" => Event::Change,
+            "click" => Event::Click,
+            "close" => Event::Close,
+            "contextmenu" => Event::ContextMenu,
+            "copy" => Event::Copy,
+            "cut" => Event::Cut,
+            "dblclick" => Event::DblClick,
+            "drag" => Event::Drag,
+            "dragend" => Event::DragEnd,
+            "dragenter" => Event::DragEnter,
+            "dragleave" => Event::DragLeave,
+            "dragover" => Event::DragOver,
+            "dragstart" => Event::DragStart,
+            "drop" => Event::Drop,
+            "durationchange" => Event::DurationChange,
+            "ended" => Event::Ended,
+            "error" => Event::Error,
+            "focus" => Event::Focus,
+            "focusin" => Event::FocusIn,
+            "focusout" => Event::FocusOut,
+            "fullscreenchange" => Event::FullscreenChange,
+            "hashchange" => Event::HashChange,
+            "input" => Event::Input,
+            "invalid" => Event::Invalid,
+            "keydown" => Event::KeyDown,
+            "keyup" => Event::KeyUp,
+            "load" => Event::Load,
+            "loadeddata" => Event::LoadedData,
+            "loadedmetadata" => Event::LoadedMetadata,
+            "loadstart" => Event::LoadStart,
+            "message" => Event::Message,
+            "mousedown" => Event::MouseDown,
+            "mouseenter" => Event::MouseEnter,
+            "mouseleave" => Event::MouseLeave,
+            "mousemove" => Event::MouseMove,
+            "mouseout" => Event::MouseOut,
+            "mouseover" => Event::MouseOver,
+            "mouseup" => Event::MouseUp,
+            "offline" => Event::Offline,
+            "online" => Event::Online,
+            "pagehide" => Event::PageHide,
+            "pageshow" => Event::PageShow,
+            "paste" => Event::Paste,
+            "pause" => Event::Pause,
+            "play" => Event::Play,
+            "playing" => Event::Playing,
+            "pointercancel" => Event::PointerCancel,
+            "pointerdown" => Event::PointerDown,
+            "pointerenter" => Event::PointerEnter,
+            "pointerleave" => Event::PointerLeave,
+            "pointermove" => Event::PointerMove,
+            "pointerout" => Event::PointerOut,
+            "pointerover" => Event::PointerOver,
+            "pointerup" => Event::PointerUp,
+            "popstate" => Event::PopState,
+            "progress" => Event::Progress,
+            "ratechange" => Event::RateChange,
+            "reset" => Event::Reset,
+            "resize" => Event::Resize,
+            "scroll" => Event::Scroll,
+            "scrollend" => Event::ScrollEnd,
+            "seeked" => Event::Seeked,
+            "seeking" => Event::Seeking,
+            "select" => Event::Select,
+            "selectionchange" => Event::SelectionChange,
+            "stalled" => Event::Stalled,
+            "storage" => Event::Storage,
+            "submit" => Event::Submit,
+            "suspend" => Event::Suspend,
+            "timeupdate" => Event::TimeUpdate,
+            "toggle" => Event::Toggle,
+            "touchcancel" => Event::TouchCancel,
+            "touchend" => Event::TouchEnd,
+            "touchmove" => Event::TouchMove,
+            "touchstart" => Event::TouchStart,
+            "transitionend" => Event::TransitionEnd,
+            "unload" => Event::Unload,
+            "volumechange" => Event::VolumeChange,
+            "waiting" => Event::Waiting,
+            "wheel" => Event::Wheel,
+            _ => return Err(UnknownEvent(s.to_string())),
+        };
+        Ok(event)
+    }
+}
+
+// Error returned when a name does not match an `Event` variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownEvent(pub String);
+
+impl Display for UnknownEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown DOM event '{}'", self.0)
+    }
+}
+impl std::error::Error for UnknownEvent {}
+
+impl Event {
+    // The event handler attribute, such as `onkeydown`.
+    pub fn attr_name(&self) -> String {
+        format!("{}{self}", Attr::Event)
+    }
+    // The event for a handler attribute name, such as `onkeydown`.
+    pub fn from_attr_name(name: &str) -> Option<Event> {
+        match name.get(..2) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("on") => name[2..].parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl AttrsBuilder {
+    // An event handler attribute. The script is escaped as an attribute
+    // value, use `escape_js` for strings placed within it.
+    pub fn on(self, event: Event, script: impl Into<String>) -> Self {
+        self.attr(event.attr_name(), script)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_event_handler_attributes() {
+        let attrs = Attrs::new()
+            .on(Event::KeyDown, format!("search('{}')", escape_js("it's")))
+            .onclick("go()")
+            .build();
+
+        assert_eq!(attrs.present(), vec![Attr::Event, Attr::Onclick]);
+        assert_eq!(
+            attrs.get_attrs(&Tag::Div),
+            vec![r#"onkeydown="search('it\u0027s')""#, r#"onclick="go()""#]
+        );
+        assert_eq!(Attr::from_name("onfocusin"), Some(Attr::Event));
+        assert_eq!(Attr::from_name("onnothing"), None);
+    }
+
+    #[test]
+    fn test_event_names() {
+        for event in [
+            Event::DblClick,
+            Event::KeyDown,
+            Event::PointerUp,
+            Event::Wheel,
+        ] {
+            assert_eq!(event.to_string().parse::<Event>(), Ok(event));
+            assert_eq!(Event::from_attr_name(&event.attr_name()), Some(event));
+        }
+        assert_eq!(Event::DblClick.attr_name(), "ondblclick");
+        assert_eq!("FocusIn".parse::<Event>(), Ok(Event::FocusIn));
+        assert_eq!(Event::from_attr_name("ONCLICK"), Some(Event::Click));
+        assert_eq!(Event::from_attr_name("click"), None);
+        assert_eq!(Event::from_attr_name("onnothing"), None);
+        assert_eq!(
+            "nothing".parse::<Event>().unwrap_err().to_string(),
+            "unknown DOM event 'nothing'"
+        );
+    }
+}
@@ -1,13 +1,13 @@
-use std::fmt::Display;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
 
 use log::warn;
 
+use crate::escape::{Context, Escaped};
+use crate::render::Render;
 use crate::tag::Tag;
 
-use super::{
-    Alt, Az, Charset, Content, Height, Hidden, Href, HttpEquiv, Id, Lang, Name, Onclick, Rel, Src,
-    Tabindex, Target, Type, Width,
-};
+use super::{Event, MathAttr, SvgAttr};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum LinkTarget {
@@ -55,60 +55,227 @@ impl From<HiddenValue> for String {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Attr {
+    Action,
     Alt,
+    Aria,
+    Autocomplete,
     Az,
     Charset,
+    Checked,
+    Cols,
     Content,
     Class,
     Data,
     Defer,
+    Disabled,
+    Enctype,
+    // An `on*` event handler, such as `onkeydown`.
+    Event,
+    For,
     Height,
     Hidden,
     Href,
     HttpEquiv,
     Id,
     Lang,
+    Max,
+    Maxlength,
+    Method,
+    Min,
+    Minlength,
+    Multiple,
     Name,
+    Nonce,
     Onclick,
+    Pattern,
+    Placeholder,
+    Readonly,
+    Required,
+    Rows,
+    Selected,
     Src,
+    Step,
+    Style,
     Tabindex,
     Target,
     Type,
     Rel,
+    Role,
+    Value,
     Width,
+    // An attribute of an SVG element.
+    Svg(SvgAttr),
+    // An attribute of a MathML element.
+    Math(MathAttr),
 }
 impl Display for Attr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Attr::Action => write!(f, "action"),
             Attr::Alt => write!(f, "alt"),
+            Attr::Aria => write!(f, "aria"),
+            Attr::Autocomplete => write!(f, "autocomplete"),
             Attr::Az => write!(f, "as"),
             Attr::Charset => write!(f, "charset"),
+            Attr::Checked => write!(f, "checked"),
+            Attr::Cols => write!(f, "cols"),
             Attr::Content => write!(f, "content"),
             Attr::Class => write!(f, "class"),
             Attr::Data => write!(f, "data"),
             Attr::Defer => write!(f, "defer"),
+            Attr::Disabled => write!(f, "disabled"),
+            Attr::Enctype => write!(f, "enctype"),
+            Attr::Event => write!(f, "on"),
+            Attr::For => write!(f, "for"),
             Attr::Height => write!(f, "height"),
             Attr::Hidden => write!(f, "hidden"),
             Attr::Href => write!(f, "href"),
             Attr::HttpEquiv => write!(f, "http-equiv"),
             Attr::Id => write!(f, "id"),
             Attr::Lang => write!(f, "lang"),
+            Attr::Max => write!(f, "max"),
+            Attr::Maxlength => write!(f, "maxlength"),
+            Attr::Method => write!(f, "method"),
+            Attr::Min => write!(f, "min"),
+            Attr::Minlength => write!(f, "minlength"),
+            Attr::Multiple => write!(f, "multiple"),
             Attr::Name => write!(f, "name"),
+            Attr::Nonce => write!(f, "nonce"),
             Attr::Onclick => write!(f, "onclick"),
+            Attr::Pattern => write!(f, "pattern"),
+            Attr::Placeholder => write!(f, "placeholder"),
+            Attr::Readonly => write!(f, "readonly"),
+            Attr::Required => write!(f, "required"),
+            Attr::Rows => write!(f, "rows"),
+            Attr::Selected => write!(f, "selected"),
             Attr::Src => write!(f, "src"),
+            Attr::Step => write!(f, "step"),
+            Attr::Style => write!(f, "style"),
             Attr::Tabindex => write!(f, "tabindex"),
             Attr::Target => write!(f, "target"),
             Attr::Type => write!(f, "type"),
             Attr::Rel => write!(f, "rel"),
+            Attr::Role => write!(f, "role"),
+            Attr::Value => write!(f, "value"),
             Attr::Width => write!(f, "width"),
+            Attr::Svg(attr) => write!(f, "{attr}"),
+            Attr::Math(attr) => write!(f, "{attr}"),
         }
     }
 }
+impl FromStr for Attr {
+    type Err = UnknownAttr;
+
+    // The reverse of `Display`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let attr = match s.to_ascii_lowercase().as_str() {
+            "action" => Attr::Action,
+            "alt" => Attr::Alt,
+            "aria" => Attr::Aria,
+            "autocomplete" => Attr::Autocomplete,
+            "as" => Attr::Az,
+            "charset" => Attr::Charset,
+            "checked" => Attr::Checked,
+            "cols" => Attr::Cols,
+            "content" => Attr::Content,
+            "class" => Attr::Class,
+            "data" => Attr::Data,
+            "defer" => Attr::Defer,
+            "disabled" => Attr::Disabled,
+            "enctype" => Attr::Enctype,
+            "on" => Attr::Event,
+            "for" => Attr::For,
+            "height" => Attr::Height,
+            "hidden" => Attr::Hidden,
+            "href" => Attr::Href,
+            "http-equiv" => Attr::HttpEquiv,
+            "id" => Attr::Id,
+            "lang" => Attr::Lang,
+            "max" => Attr::Max,
+            "maxlength" => Attr::Maxlength,
+            "method" => Attr::Method,
+            "min" => Attr::Min,
+            "minlength" => Attr::Minlength,
+            "multiple" => Attr::Multiple,
+            "name" => Attr::Name,
+            "nonce" => Attr::Nonce,
+            "onclick" => Attr::Onclick,
+            "pattern" => Attr::Pattern,
+            "placeholder" => Attr::Placeholder,
+            "readonly" => Attr::Readonly,
+            "required" => Attr::Required,
+            "rows" => Attr::Rows,
+            "selected" => Attr::Selected,
+            "src" => Attr::Src,
+            "step" => Attr::Step,
+            "style" => Attr::Style,
+            "tabindex" => Attr::Tabindex,
+            "target" => Attr::Target,
+            "type" => Attr::Type,
+            "rel" => Attr::Rel,
+            "role" => Attr::Role,
+            "value" => Attr::Value,
+            "width" => Attr::Width,
+            _ => return Err(UnknownAttr(s.to_string())),
+        };
+        Ok(attr)
+    }
+}
+
+// Error returned when a name does not match an `Attr` variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownAttr(pub String);
+
+impl Display for UnknownAttr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown HTML attribute '{}'", self.0)
+    }
+}
+impl std::error::Error for UnknownAttr {}
+
 impl Attr {
+    // The variant for an attribute name, treating every `data-*` name as
+    // `Attr::Data`, every `aria-*` name as `Attr::Aria` and every event
+    // handler other than `onclick` as `Attr::Event`.
+    pub fn from_name(name: &str) -> Option<Attr> {
+        match name.get(..5) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("data-") => Some(Attr::Data),
+            Some(prefix) if prefix.eq_ignore_ascii_case("aria-") => Some(Attr::Aria),
+            _ => name
+                .parse()
+                .ok()
+                .or_else(|| Event::from_attr_name(name).map(|_| Attr::Event)),
+        }
+    }
+    // The variant for an attribute name on `tag`. SVG and MathML elements
+    // look up their own attributes first, as they share names such as
+    // `width` with HTML.
+    pub fn from_name_on(tag: &Tag, name: &str) -> Option<Attr> {
+        match tag {
+            Tag::Svg(_) => match name.parse::<SvgAttr>() {
+                Ok(attr) => Some(Attr::Svg(attr)),
+                Err(_) => Attr::from_name(name),
+            },
+            Tag::Math(_) => match name.parse::<MathAttr>() {
+                Ok(attr) => Some(Attr::Math(attr)),
+                Err(_) => Attr::from_name(name),
+            },
+            _ => Attr::from_name(name),
+        }
+    }
     pub fn all() -> &'static [Attr] {
         &[
+            Attr::Action,
             Attr::Alt,
+            Attr::Aria,
+            Attr::Autocomplete,
             Attr::Az,
+            Attr::Checked,
+            Attr::Cols,
+            Attr::Disabled,
+            Attr::Enctype,
+            Attr::Event,
+            Attr::For,
             Attr::Id,
             Attr::Class,
             Attr::Charset,
@@ -120,13 +287,30 @@ impl Attr {
             Attr::Href,
             Attr::HttpEquiv,
             Attr::Lang,
+            Attr::Max,
+            Attr::Maxlength,
+            Attr::Method,
+            Attr::Min,
+            Attr::Minlength,
+            Attr::Multiple,
             Attr::Name,
+            Attr::Nonce,
             Attr::Onclick,
+            Attr::Pattern,
+            Attr::Placeholder,
+            Attr::Readonly,
             Attr::Rel,
+            Attr::Required,
+            Attr::Role,
+            Attr::Rows,
+            Attr::Selected,
             Attr::Src,
+            Attr::Step,
+            Attr::Style,
             Attr::Tabindex,
             Attr::Target,
             Attr::Type,
+            Attr::Value,
             Attr::Width,
         ]
     }
@@ -138,69 +322,67 @@ impl Attr {
             Attr::Data,
             Attr::Hidden,
             Attr::Lang,
+            Attr::Nonce,
+            Attr::Style,
             Attr::Tabindex,
+            // Accessibility
+            Attr::Aria,
+            Attr::Role,
+            // Event handlers
+            Attr::Event,
+            Attr::Onclick,
         ]
     }
 }
 
-// HTML data-* attributes
-#[derive(Debug, Clone)]
-pub struct Data(pub String, pub String);
-
-impl Display for Data {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, r#"{}-{}="{}""#, Attr::Data, self.0, self.1)
-    }
+// The attributes of an element, in the order they were first set. Each
+// name appears once, ignoring ASCII case as HTML does, and setting it again
+// replaces the value in place. A `None` value is a boolean attribute.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Attrs {
+    entries: Entries,
 }
 
-// Boolean attributes
-#[derive(Debug, Clone)]
-pub struct Defer;
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Entries(Vec<(String, Option<String>)>);
 
-impl Display for Defer {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", Attr::Defer)
+impl Entries {
+    fn position(&self, name: &str) -> Option<usize> {
+        self.0
+            .iter()
+            .position(|(found, _)| found.eq_ignore_ascii_case(name))
+    }
+    fn get(&self, name: &str) -> Option<&Option<String>> {
+        self.position(name).map(|i| &self.0[i].1)
+    }
+    fn insert(&mut self, name: String, value: Option<String>) {
+        match self.position(&name) {
+            Some(i) => self.0[i] = (name, value),
+            None => self.0.push((name, value)),
+        }
+    }
+    fn remove(&mut self, name: &str) -> bool {
+        self.position(name).map(|i| self.0.remove(i)).is_some()
     }
 }
 
-// Vector Attributes
-#[derive(Debug, Clone)]
-pub struct Class(Vec<String>);
-
-impl Display for Class {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Class(values) => {
-                write!(f, r#"{}="{}""#, Attr::Class, values.join(" "))
+// Every attribute, separated by spaces, such as `hidden id="main"`. Unlike
+// an element, this does not leave out attributes a tag does not support.
+impl Render for Attrs {
+    fn render_to(&self, w: &mut impl Write) -> std::fmt::Result {
+        for (i, (name, value)) in self.iter().enumerate() {
+            if i > 0 {
+                w.write_char(' ')?;
             }
+            write!(w, "{}", Entry { name, value })?;
         }
+        Ok(())
     }
 }
-
-#[derive(Debug, Default, Clone)]
-pub struct Attrs {
-    // Global
-    pub alt: Option<Alt>,
-    pub az: Option<Az>,
-    pub id: Option<Id>,
-    pub class: Option<Class>,
-    pub charset: Option<Charset>,
-    pub content: Option<Content>,
-    pub data: Option<Data>,
-    pub defer: Option<Defer>,
-    pub height: Option<Height>,
-    pub hidden: Option<Hidden>,
-    pub href: Option<Href>,
-    pub http_equiv: Option<HttpEquiv>,
-    pub lang: Option<Lang>,
-    pub name: Option<Name>,
-    pub onclick: Option<Onclick>,
-    pub rel: Option<Rel>,
-    pub src: Option<Src>,
-    pub tabindex: Option<Tabindex>,
-    pub target: Option<Target>,
-    pub typ: Option<Type>,
-    pub width: Option<Width>,
+impl Display for Attrs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render_to(f)
+    }
 }
 
 impl Attrs {
@@ -208,259 +390,421 @@ impl Attrs {
         AttrsBuilder::new()
     }
     pub fn get_attrs(&self, tag: &Tag) -> Vec<String> {
-        let tag_attributes = Tag::attributes(tag);
-        let mut attributes = vec![];
-
-        if self.alt.is_some() && validate_attrs(tag, &Attr::Alt, &tag_attributes) {
-            attributes.push(self.alt.as_ref().unwrap().to_string())
-        }
-        if self.az.is_some() && validate_attrs(tag, &Attr::Az, &tag_attributes) {
-            attributes.push(self.az.as_ref().unwrap().to_string())
-        }
-        if self.charset.is_some() && validate_attrs(tag, &Attr::Charset, &tag_attributes) {
-            attributes.push(self.charset.as_ref().unwrap().to_string())
-        }
-        if self.class.is_some() && validate_attrs(tag, &Attr::Class, &tag_attributes) {
-            attributes.push(self.class.as_ref().unwrap().to_string())
-        }
-        if self.content.is_some() && validate_attrs(tag, &Attr::Content, &tag_attributes) {
-            attributes.push(self.content.as_ref().unwrap().to_string())
-        }
-        if self.data.is_some() && validate_attrs(tag, &Attr::Data, &tag_attributes) {
-            attributes.push(self.data.as_ref().unwrap().to_string())
-        }
-        if self.defer.is_some() && validate_attrs(tag, &Attr::Defer, &tag_attributes) {
-            attributes.push(self.defer.as_ref().unwrap().to_string())
-        }
-        if self.height.is_some() && validate_attrs(tag, &Attr::Height, &tag_attributes) {
-            attributes.push(self.height.as_ref().unwrap().to_string())
-        }
-        if self.hidden.is_some() && validate_attrs(tag, &Attr::Hidden, &tag_attributes) {
-            attributes.push(self.hidden.as_ref().unwrap().to_string())
-        }
-        if self.href.is_some() && validate_attrs(tag, &Attr::Href, &tag_attributes) {
-            attributes.push(self.href.as_ref().unwrap().to_string())
-        }
-        if self.http_equiv.is_some() && validate_attrs(tag, &Attr::HttpEquiv, &tag_attributes) {
-            attributes.push(self.http_equiv.as_ref().unwrap().to_string())
-        }
-        if self.id.is_some() && validate_attrs(tag, &Attr::Id, &tag_attributes) {
-            attributes.push(self.id.as_ref().unwrap().to_string())
-        }
-        if self.lang.is_some() && validate_attrs(tag, &Attr::Lang, &tag_attributes) {
-            attributes.push(self.lang.as_ref().unwrap().to_string())
-        }
-        if self.name.is_some() && validate_attrs(tag, &Attr::Name, &tag_attributes) {
-            attributes.push(self.name.as_ref().unwrap().to_string())
-        }
-        if self.onclick.is_some() && validate_attrs(tag, &Attr::Onclick, &tag_attributes) {
-            attributes.push(self.onclick.as_ref().unwrap().to_string())
+        self.supported(tag)
+            .iter()
+            .map(|attr| attr.to_string())
+            .collect()
+    }
+    // The value of an attribute, which is empty for a boolean attribute.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.entries
+            .get(name)
+            .map(|value| value.as_deref().unwrap_or_default())
+    }
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.position(name).is_some()
+    }
+    // Each attribute name and value, in output order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.entries
+            .0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_deref()))
+    }
+    pub fn len(&self) -> usize {
+        self.entries.0.len()
+    }
+    // Set an attribute, written as a boolean attribute when the value is
+    // `None`. Invalid names are ignored with a warning, as with `other`.
+    pub fn set(&mut self, name: impl Into<String>, value: Option<impl Into<String>>) {
+        let name = name.into();
+        match validate_name(&name) {
+            Ok(()) => {
+                self.entries.insert(name, value.map(|value| value.into()));
+            }
+            Err(error) => warn!("{error}"),
         }
-        if self.src.is_some() && validate_attrs(tag, &Attr::Src, &tag_attributes) {
-            attributes.push(self.src.as_ref().unwrap().to_string())
+    }
+    // Remove an attribute, returning whether it was set.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.entries.remove(name)
+    }
+    // The names in the `class` attribute.
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.get("class")
+            .unwrap_or_default()
+            .split_ascii_whitespace()
+    }
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes().any(|name| name == class)
+    }
+    // Add a name to the `class` attribute, unless it is already there.
+    pub fn add_class(&mut self, class: &str) {
+        if self.has_class(class) {
+            return;
         }
-        if self.tabindex.is_some() && validate_attrs(tag, &Attr::Tabindex, &tag_attributes) {
-            attributes.push(self.tabindex.as_ref().unwrap().to_string())
+        let mut classes: Vec<&str> = self.classes().collect();
+        classes.push(class);
+        let value = classes.join(" ");
+        self.set(Attr::Class.to_string(), Some(value));
+    }
+    // Remove a name from the `class` attribute, and the attribute once empty.
+    pub fn remove_class(&mut self, class: &str) {
+        let classes: Vec<&str> = self.classes().filter(|name| *name != class).collect();
+        if classes.is_empty() {
+            self.remove("class");
+        } else {
+            let value = classes.join(" ");
+            self.set(Attr::Class.to_string(), Some(value));
         }
-        if self.target.is_some() && validate_attrs(tag, &Attr::Target, &tag_attributes) {
-            attributes.push(self.target.as_ref().unwrap().to_string())
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.0.is_empty()
+    }
+    // Write the attributes supported by the tag, each preceded by a space.
+    pub fn render_for(&self, tag: &Tag, w: &mut impl Write) -> std::fmt::Result {
+        for attr in self.supported(tag) {
+            write!(w, " {attr}")?;
         }
-        if self.typ.is_some() && validate_attrs(tag, &Attr::Type, &tag_attributes) {
-            attributes.push(self.typ.as_ref().unwrap().to_string())
+        Ok(())
+    }
+    // The attributes that are set and have an `Attr` variant, in output order.
+    pub fn present(&self) -> Vec<Attr> {
+        self.present_with(Attr::from_name)
+    }
+    // The attributes that are set, looked up in the namespace of the tag.
+    pub(crate) fn present_on(&self, tag: &Tag) -> Vec<Attr> {
+        self.present_with(|name| Attr::from_name_on(tag, name))
+    }
+    fn present_with(&self, lookup: impl Fn(&str) -> Option<Attr>) -> Vec<Attr> {
+        let mut present: Vec<Attr> = vec![];
+        for attr in self.iter().filter_map(|(name, _)| lookup(name)) {
+            if !present.contains(&attr) {
+                present.push(attr);
+            }
         }
-        if self.rel.is_some() && validate_attrs(tag, &Attr::Rel, &tag_attributes) {
-            attributes.push(self.rel.as_ref().unwrap().to_string())
+        present
+    }
+    // The attributes that are valid for the tag, in output order.
+    pub(crate) fn supported(&self, tag: &Tag) -> Vec<Entry<'_>> {
+        let tag_attributes = Tag::attributes(tag);
+        self.iter()
+            .filter(|(name, _)| match Attr::from_name_on(tag, name) {
+                Some(attr) => validate_attrs(tag, &attr, &tag_attributes),
+                // Attributes without a variant are only limited by custom
+                // elements defined without them.
+                None if tag.supports_other(name) => true,
+                None => {
+                    warn!("HTML tag '{tag}' does not support the '{name}' attribute");
+                    false
+                }
+            })
+            .map(|(name, value)| Entry { name, value })
+            .collect()
+    }
+}
+
+// A single attribute, written as `name="value"` or just `name`.
+pub(crate) struct Entry<'a> {
+    name: &'a str,
+    value: Option<&'a str>,
+}
+
+impl<'a> Entry<'a> {
+    pub(crate) fn name(&self) -> &'a str {
+        self.name
+    }
+
+    #[cfg(feature = "jsonml")]
+    pub(crate) fn value(&self) -> Option<&'a str> {
+        self.value
+    }
+
+    // Boolean attributes written with their name as the value, as XML
+    // has no attributes without a value.
+    pub(crate) fn expanded(self) -> Self {
+        Entry {
+            name: self.name,
+            value: Some(self.value.unwrap_or(self.name)),
         }
-        if self.width.is_some() && validate_attrs(tag, &Attr::Width, &tag_attributes) {
-            attributes.push(self.width.as_ref().unwrap().to_string())
+    }
+}
+
+impl Display for Entry<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)?;
+        match self.value {
+            Some(value) => write!(f, r#"="{}""#, Escaped(value, value_context(self.name))),
+            None => Ok(()),
         }
-        attributes
     }
 }
 
-pub struct AttrsBuilder {
-    pub alt: Option<Alt>,
-    pub az: Option<Az>,
-    pub charset: Option<Charset>,
-    pub class: Option<Class>,
-    pub content: Option<Content>,
-    pub data: Option<Data>,
-    pub defer: Option<Defer>,
-    pub height: Option<Height>,
-    pub hidden: Option<Hidden>,
-    pub href: Option<Href>,
-    pub http_equiv: Option<HttpEquiv>,
-    pub id: Option<Id>,
-    pub lang: Option<Lang>,
-    pub name: Option<Name>,
-    pub onclick: Option<Onclick>,
-    pub rel: Option<Rel>,
-    pub src: Option<Src>,
-    pub target: Option<Target>,
-    pub tabindex: Option<Tabindex>,
-    pub typ: Option<Type>,
-    pub width: Option<Width>,
+// The escaping context for the value of an attribute.
+fn value_context(name: &str) -> Context {
+    match name.to_ascii_lowercase().as_str() {
+        "action" | "cite" | "formaction" | "href" | "poster" | "src" => Context::Url,
+        _ => Context::Attribute,
+    }
 }
-impl Default for AttrsBuilder {
-    fn default() -> Self {
-        Self::new()
+
+// Error returned when a name cannot be used as an attribute name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidAttrName(pub String);
+
+impl Display for InvalidAttrName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid HTML attribute name '{}'", self.0)
+    }
+}
+impl std::error::Error for InvalidAttrName {}
+
+// Attribute names cannot be empty, or contain whitespace, quotes, `>`, `/`
+// or `=`, as these would end the name or the tag.
+pub(crate) fn validate_name(name: &str) -> Result<(), InvalidAttrName> {
+    let invalid = name.is_empty()
+        || name.chars().any(|c| {
+            c.is_whitespace()
+                || c.is_control()
+                || matches!(c, '"' | '\'' | '<' | '>' | '/' | '=' | '&')
+        });
+    match invalid {
+        true => Err(InvalidAttrName(name.to_string())),
+        false => Ok(()),
     }
 }
+
+#[derive(Debug, Default, Clone)]
+pub struct AttrsBuilder {
+    entries: Entries,
+}
 impl AttrsBuilder {
     pub fn new() -> Self {
         AttrsBuilder {
-            alt: None,
-            az: None,
-            charset: None,
-            class: None,
-            content: None,
-            data: None,
-            defer: None,
-            height: None,
-            hidden: None,
-            href: None,
-            http_equiv: None,
-            id: None,
-            lang: None,
-            name: None,
-            onclick: None,
-            rel: None,
-            src: None,
-            tabindex: None,
-            target: None,
-            typ: None,
-            width: None,
+            entries: Entries::default(),
         }
     }
-    pub fn alt(mut self, alt: impl Into<String>) -> Self {
-        self.alt = Some(Alt(alt.into()));
+
+    fn set(mut self, attr: Attr, value: impl Into<String>) -> Self {
+        self.entries.insert(attr.to_string(), Some(value.into()));
         self
     }
 
-    pub fn az(mut self, az: impl Into<String>) -> Self {
-        self.az = Some(Az(az.into()));
-        self
+    pub fn alt(self, alt: impl Into<String>) -> Self {
+        self.set(Attr::Alt, alt)
     }
 
-    pub fn charset(mut self, charset: impl Into<String>) -> Self {
-        self.charset = Some(Charset(charset.into()));
-        self
+    pub fn az(self, az: impl Into<String>) -> Self {
+        self.set(Attr::Az, az)
     }
 
-    pub fn content(mut self, content: impl Into<String>) -> Self {
-        self.content = Some(Content(content.into()));
-        self
+    pub fn charset(self, charset: impl Into<String>) -> Self {
+        self.set(Attr::Charset, charset)
     }
 
-    pub fn class(mut self, class: Vec<impl Into<String>>) -> Self {
+    pub fn content(self, content: impl Into<String>) -> Self {
+        self.set(Attr::Content, content)
+    }
+
+    pub fn class(self, class: Vec<impl Into<String>>) -> Self {
         let string_vec: Vec<String> = class.into_iter().map(|s| s.into()).collect();
-        self.class = Some(Class(string_vec));
-        self
+        self.set(Attr::Class, string_vec.join(" "))
     }
 
-    pub fn data(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
-        self.data = Some(Data(key.into(), value.into()));
-        self
+    // A `data-*` attribute. Each key is kept, setting a key again replaces it.
+    pub fn data(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.other(format!("{}-{}", Attr::Data, key.into()), Some(value))
     }
 
     pub fn defer(mut self) -> Self {
-        self.defer = Some(Defer);
+        self.entries.insert(Attr::Defer.to_string(), None);
         self
     }
 
-    pub fn height(mut self, height: impl Into<String>) -> Self {
-        self.height = Some(Height(height.into()));
-        self
+    pub fn height(self, height: impl Into<String>) -> Self {
+        self.set(Attr::Height, height)
     }
 
-    pub fn hidden(mut self, hidden: HiddenValue) -> Self {
-        self.hidden = Some(Hidden(hidden.into()));
-        self
+    pub fn hidden(self, hidden: HiddenValue) -> Self {
+        self.set(Attr::Hidden, hidden)
     }
 
-    pub fn href(mut self, href: impl Into<String>) -> Self {
-        self.href = Some(Href(href.into()));
-        self
+    pub fn href(self, href: impl Into<String>) -> Self {
+        self.set(Attr::Href, href)
     }
 
-    pub fn http_equiv(mut self, http_equiv: impl Into<String>) -> Self {
-        self.http_equiv = Some(HttpEquiv(http_equiv.into()));
-        self
+    pub fn http_equiv(self, http_equiv: impl Into<String>) -> Self {
+        self.set(Attr::HttpEquiv, http_equiv)
     }
 
-    pub fn id(mut self, id: impl Into<String>) -> Self {
-        self.id = Some(Id(id.into()));
-        self
+    pub fn id(self, id: impl Into<String>) -> Self {
+        self.set(Attr::Id, id)
     }
 
-    pub fn lang(mut self, lang: impl Into<String>) -> Self {
-        self.lang = Some(Lang(lang.into()));
-        self
+    pub fn lang(self, lang: impl Into<String>) -> Self {
+        self.set(Attr::Lang, lang)
     }
 
-    pub fn name(mut self, name: impl Into<String>) -> Self {
-        self.name = Some(Name(name.into()));
-        self
+    pub fn name(self, name: impl Into<String>) -> Self {
+        self.set(Attr::Name, name)
     }
 
-    pub fn onclick(mut self, onclick: impl Into<String>) -> Self {
-        self.onclick = Some(Onclick(onclick.into()));
-        self
+    // A Content-Security-Policy nonce, for scripts and styles.
+    pub fn nonce(self, nonce: impl Into<String>) -> Self {
+        self.set(Attr::Nonce, nonce)
     }
 
-    pub fn rel(mut self, rel: impl Into<String>) -> Self {
-        self.rel = Some(Rel(rel.into()));
-        self
+    pub fn onclick(self, onclick: impl Into<String>) -> Self {
+        self.set(Attr::Onclick, onclick)
     }
 
-    pub fn src(mut self, src: impl Into<String>) -> Self {
-        self.src = Some(Src(src.into()));
-        self
+    pub fn rel(self, rel: impl Into<String>) -> Self {
+        self.set(Attr::Rel, rel)
     }
 
-    pub fn tabindex(mut self, tabindex: i16) -> Self {
-        self.tabindex = Some(Tabindex(tabindex.to_string()));
-        self
+    pub fn src(self, src: impl Into<String>) -> Self {
+        self.set(Attr::Src, src)
     }
 
-    pub fn target(mut self, target: LinkTarget) -> Self {
-        self.target = Some(Target(target.into()));
-        self
+    pub fn tabindex(self, tabindex: i16) -> Self {
+        self.set(Attr::Tabindex, tabindex.to_string())
     }
 
-    pub fn typ(mut self, typ: impl Into<String>) -> Self {
-        self.typ = Some(Type(typ.into()));
-        self
+    pub fn target(self, target: LinkTarget) -> Self {
+        self.set(Attr::Target, target)
+    }
+
+    pub fn typ(self, typ: impl Into<String>) -> Self {
+        self.set(Attr::Type, typ)
+    }
+
+    pub fn width(self, width: impl Into<String>) -> Self {
+        self.set(Attr::Width, width)
+    }
+
+    // Form attributes
+    pub fn action(self, action: impl Into<String>) -> Self {
+        self.set(Attr::Action, action)
+    }
+
+    pub fn autocomplete(self, autocomplete: impl Into<String>) -> Self {
+        self.set(Attr::Autocomplete, autocomplete)
+    }
+
+    pub fn checked(self) -> Self {
+        self.boolean(Attr::Checked.to_string())
+    }
+
+    pub fn cols(self, cols: u32) -> Self {
+        self.set(Attr::Cols, cols.to_string())
+    }
+
+    pub fn disabled(self) -> Self {
+        self.boolean(Attr::Disabled.to_string())
+    }
+
+    pub fn enctype(self, enctype: impl Into<String>) -> Self {
+        self.set(Attr::Enctype, enctype)
+    }
+
+    // The `for` attribute, naming the id of the control a label is for.
+    pub fn html_for(self, id: impl Into<String>) -> Self {
+        self.set(Attr::For, id)
+    }
+
+    pub fn max(self, max: impl Into<String>) -> Self {
+        self.set(Attr::Max, max)
+    }
+
+    pub fn maxlength(self, maxlength: usize) -> Self {
+        self.set(Attr::Maxlength, maxlength.to_string())
+    }
+
+    pub fn method(self, method: impl Into<String>) -> Self {
+        self.set(Attr::Method, method)
+    }
+
+    pub fn min(self, min: impl Into<String>) -> Self {
+        self.set(Attr::Min, min)
+    }
+
+    pub fn minlength(self, minlength: usize) -> Self {
+        self.set(Attr::Minlength, minlength.to_string())
     }
 
-    pub fn width(mut self, width: impl Into<String>) -> Self {
-        self.width = Some(Width(width.into()));
+    pub fn multiple(self) -> Self {
+        self.boolean(Attr::Multiple.to_string())
+    }
+
+    pub fn pattern(self, pattern: impl Into<String>) -> Self {
+        self.set(Attr::Pattern, pattern)
+    }
+
+    pub fn placeholder(self, placeholder: impl Into<String>) -> Self {
+        self.set(Attr::Placeholder, placeholder)
+    }
+
+    pub fn readonly(self) -> Self {
+        self.boolean(Attr::Readonly.to_string())
+    }
+
+    pub fn required(self) -> Self {
+        self.boolean(Attr::Required.to_string())
+    }
+
+    pub fn rows(self, rows: u32) -> Self {
+        self.set(Attr::Rows, rows.to_string())
+    }
+
+    pub fn selected(self) -> Self {
+        self.boolean(Attr::Selected.to_string())
+    }
+
+    pub fn step(self, step: impl Into<String>) -> Self {
+        self.set(Attr::Step, step)
+    }
+
+    pub fn value(self, value: impl Into<String>) -> Self {
+        self.set(Attr::Value, value)
+    }
+
+    // Any attribute, such as `style`, `role` or `placeholder`. An invalid
+    // name is logged and ignored.
+    pub fn attr(self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.other(name, Some(value))
+    }
+
+    // An attribute without a value, such as `disabled` or `required`.
+    pub fn boolean(self, name: impl Into<String>) -> Self {
+        self.other(name, None::<String>)
+    }
+
+    // Like `attr`, returning an error for an invalid name.
+    pub fn try_attr(
+        self,
+        name: impl Into<String>,
+        value: Option<impl Into<String>>,
+    ) -> Result<Self, InvalidAttrName> {
+        let name = name.into();
+        validate_name(&name)?;
+        Ok(self.other(name, value))
+    }
+
+    // Any attribute, written as a boolean attribute when the value is `None`.
+    pub fn other(mut self, name: impl Into<String>, value: Option<impl Into<String>>) -> Self {
+        let name = name.into();
+        match validate_name(&name) {
+            Ok(()) => {
+                self.entries.insert(name, value.map(|value| value.into()));
+            }
+            Err(error) => warn!("{error}"),
+        }
         self
     }
 
     pub fn build(self) -> Attrs {
         Attrs {
-            alt: self.alt,
-            az: self.az,
-            charset: self.charset,
-            class: self.class,
-            content: self.content,
-            data: self.data,
-            defer: self.defer,
-            height: self.height,
-            hidden: self.hidden,
-            href: self.href,
-            http_equiv: self.http_equiv,
-            id: self.id,
-            lang: self.lang,
-            name: self.name,
-            onclick: self.onclick,
-            rel: self.rel,
-            src: self.src,
-            tabindex: self.tabindex,
-            target: self.target,
-            typ: self.typ,
-            width: self.width,
+            entries: self.entries,
         }
     }
 }
@@ -496,14 +840,43 @@ mod tests {
 
     #[test]
     fn test_data_attribute_display() {
-        let data = Data("user".to_string(), "123".to_string());
-        assert_eq!(data.to_string(), r#"data-user="123""#);
+        let attrs = Attrs::new().data("user", "123").build();
+        assert_eq!(attrs.get_attrs(&Tag::Div), vec![r#"data-user="123""#]);
     }
 
     #[test]
     fn test_class_attribute_display() {
-        let class = Class(vec!["btn".to_string(), "btn-primary".to_string()]);
-        assert_eq!(class.to_string(), r#"class="btn btn-primary""#);
+        let attrs = Attrs::new().class(vec!["btn", "btn-primary"]).build();
+        assert_eq!(
+            attrs.get_attrs(&Tag::Div),
+            vec![r#"class="btn btn-primary""#]
+        );
+    }
+
+    #[test]
+    fn test_attr_from_str() {
+        assert_eq!("http-equiv".parse::<Attr>(), Ok(Attr::HttpEquiv));
+        assert_eq!("as".parse::<Attr>(), Ok(Attr::Az));
+        assert_eq!("HREF".parse::<Attr>(), Ok(Attr::Href));
+        assert_eq!(
+            "bogus".parse::<Attr>(),
+            Err(UnknownAttr("bogus".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_render_attrs() {
+        let attrs = Attrs::new()
+            .id("main")
+            .href("/a?b=1&c=2")
+            .boolean("hidden")
+            .build();
+
+        assert_eq!(
+            attrs.to_string(),
+            r#"id="main" href="/a?b=1&amp;c=2" hidden"#
+        );
+        assert_eq!(Attrs::default().to_string(), "");
     }
 
     #[test]
@@ -515,10 +888,10 @@ mod tests {
             .hidden(HiddenValue::Hidden)
             .build();
 
-        assert!(attrs.id.is_some());
-        assert!(attrs.class.is_some());
-        assert!(attrs.data.is_some());
-        assert!(attrs.hidden.is_some());
+        assert_eq!(attrs.get("id"), Some("test-id"));
+        assert_eq!(attrs.get("class"), Some("btn primary"));
+        assert_eq!(attrs.get("data-user"), Some("123"));
+        assert_eq!(attrs.get("hidden"), Some("hidden"));
     }
 
     #[test]
@@ -541,12 +914,11 @@ mod tests {
             &Attr::Href,
             &Tag::attributes(&Tag::A)
         ));
-        // TODO: Uncomment when validate_attrs is updated
-        // assert!(!validate_attrs(
-        //     &Tag::Div,
-        //     &Attr::Href,
-        //     &Tag::attributes(&Tag::Div)
-        // ));
+        assert!(!validate_attrs(
+            &Tag::Div,
+            &Attr::Href,
+            &Tag::attributes(&Tag::Div)
+        ));
     }
 
     #[test]
@@ -570,6 +942,13 @@ mod tests {
         assert!(a_attrs.contains(&r#"href="https://example.com""#.to_string()));
     }
 
+    #[test]
+    fn test_present() {
+        let attrs = Attrs::new().id("a").href("/").defer().build();
+
+        assert_eq!(attrs.present(), vec![Attr::Id, Attr::Href, Attr::Defer]);
+    }
+
     #[test]
     fn test_attrs_builder_chaining() {
         let attrs = Attrs::new()
@@ -586,4 +965,75 @@ mod tests {
         assert!(anchor_attrs.contains(&r#"onclick="alert('clicked')""#.to_string()));
         assert!(anchor_attrs.contains(&r#"target="_blank""#.to_string()));
     }
+
+    #[test]
+    fn test_multiple_data_attributes() {
+        let attrs = Attrs::new()
+            .data("user", "1")
+            .data("role", "admin")
+            .data("user", "2")
+            .build();
+
+        assert_eq!(attrs.len(), 2);
+        assert_eq!(
+            attrs.get_attrs(&Tag::Div),
+            vec![r#"data-user="2""#, r#"data-role="admin""#]
+        );
+        assert_eq!(attrs.present(), vec![Attr::Data]);
+    }
+
+    #[test]
+    fn test_arbitrary_and_boolean_attributes() {
+        let attrs = Attrs::new()
+            .attr("placeholder", "Search")
+            .attr("style", "color: red")
+            .boolean("required")
+            .attr("formaction", "javascript:alert(1)")
+            .attr("bad name", "x")
+            .id("q")
+            .build();
+
+        assert!(!attrs.contains("bad name"));
+        assert_eq!(attrs.get("required"), Some(""));
+        assert_eq!(
+            attrs.present(),
+            vec![Attr::Placeholder, Attr::Style, Attr::Required, Attr::Id]
+        );
+        assert_eq!(
+            attrs.get_attrs(&Tag::Input),
+            vec![
+                r#"placeholder="Search""#,
+                r#"style="color: red""#,
+                "required",
+                r#"formaction="about:invalid#blocked""#,
+                r#"id="q""#,
+            ]
+        );
+        assert_eq!(
+            Attrs::new().try_attr("a=b", Some("c")).unwrap_err(),
+            InvalidAttrName("a=b".to_string())
+        );
+    }
+
+    #[test]
+    fn test_setting_an_attribute_again_replaces_it() {
+        let attrs = Attrs::new()
+            .id("a")
+            .attr("id", "b")
+            .defer()
+            .attr("defer", "defer")
+            .build();
+
+        assert_eq!(
+            attrs.iter().collect::<Vec<_>>(),
+            vec![("id", Some("b")), ("defer", Some("defer"))]
+        );
+
+        // Names differing only in ASCII case are the same attribute, which
+        // keeps the place it was first set at.
+        let attrs = Attrs::new().attr("ID", "a").href("/").id("b").build();
+        assert_eq!(attrs.to_string(), r#"id="b" href="/""#);
+        assert_eq!(attrs.len(), 2);
+        assert!(attrs.contains("Id"));
+    }
 }
@@ -1,16 +1,26 @@
 use std::fmt::Display;
 
 use super::html::Attr;
+use crate::escape::Context;
 
 #[macro_export]
 macro_rules! html_attribute {
     ($name:ident => $attr:expr) => {
+        html_attribute!($name => $attr, $crate::escape::Context::Attribute);
+    };
+    ($name:ident => $attr:expr, $context:expr) => {
         #[derive(Debug, Clone)]
         pub struct $name(pub String);
 
+        impl $crate::render::Render for $name {
+            fn render_to(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+                write!(w, r#"{}="{}""#, $attr, $crate::escape::Escaped(&self.0, $context))
+            }
+        }
+
         impl Display for $name {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, r#"{}="{}""#, $attr, self.0)
+                $crate::render::Render::render_to(self, f)
             }
         }
     };
@@ -23,14 +33,14 @@ html_attribute!(Charset => Attr::Charset);
 html_attribute!(Content => Attr::Content);
 html_attribute!(Height => Attr::Height);
 html_attribute!(Hidden => Attr::Hidden);
-html_attribute!(Href => Attr::Href);
+html_attribute!(Href => Attr::Href, Context::Url);
 html_attribute!(HttpEquiv => Attr::HttpEquiv);
 html_attribute!(Id => Attr::Id);
 html_attribute!(Lang => Attr::Lang);
 html_attribute!(Name => Attr::Name);
 html_attribute!(Onclick => Attr::Onclick);
 html_attribute!(Rel => Attr::Rel);
-html_attribute!(Src => Attr::Src);
+html_attribute!(Src => Attr::Src, Context::Url);
 html_attribute!(Tabindex => Attr::Tabindex);
 html_attribute!(Target => Attr::Target);
 html_attribute!(Type => Attr::Type);
@@ -0,0 +1,257 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::{Attr, AttrsBuilder};
+
+// MathML attributes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MathAttr {
+    Accent,
+    Accentunder,
+    Actiontype,
+    Alttext,
+    Columnspan,
+    Depth,
+    Dir,
+    Display,
+    Displaystyle,
+    Encoding,
+    Fence,
+    Form,
+    Height,
+    Largeop,
+    Linethickness,
+    Lspace,
+    Mathbackground,
+    Mathcolor,
+    Mathsize,
+    Mathvariant,
+    Maxsize,
+    Minsize,
+    Movablelimits,
+    Notation,
+    Rowspan,
+    Rspace,
+    Scriptlevel,
+    Selection,
+    Separator,
+    Stretchy,
+    Symmetric,
+    Voffset,
+    Width,
+}
+impl Display for MathAttr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            MathAttr::Accent => "accent",
+            MathAttr::Accentunder => "accentunder",
+            MathAttr::Actiontype => "actiontype",
+            MathAttr::Alttext => "alttext",
+            MathAttr::Columnspan => "columnspan",
+            MathAttr::Depth => "depth",
+            MathAttr::Dir => "dir",
+            MathAttr::Display => "display",
+            MathAttr::Displaystyle => "displaystyle",
+            MathAttr::Encoding => "encoding",
+            MathAttr::Fence => "fence",
+            MathAttr::Form => "form",
+            MathAttr::Height => "height",
+            MathAttr::Largeop => "largeop",
+            MathAttr::Linethickness => "linethickness",
+            MathAttr::Lspace => "lspace",
+            MathAttr::Mathbackground => "mathbackground",
+            MathAttr::Mathcolor => "mathcolor",
+            MathAttr::Mathsize => "mathsize",
+            MathAttr::Mathvariant => "mathvariant",
+            MathAttr::Maxsize => "maxsize",
+            MathAttr::Minsize => "minsize",
+            MathAttr::Movablelimits => "movablelimits",
+            MathAttr::Notation => "notation",
+            MathAttr::Rowspan => "rowspan",
+            MathAttr::Rspace => "rspace",
+            MathAttr::Scriptlevel => "scriptlevel",
+            MathAttr::Selection => "selection",
+            MathAttr::Separator => "separator",
+            MathAttr::Stretchy => "stretchy",
+            MathAttr::Symmetric => "symmetric",
+            MathAttr::Voffset => "voffset",
+            MathAttr::Width => "width",
+        };
+        f.write_str(name)
+    }
+}
+impl FromStr for MathAttr {
+    type Err = UnknownMathAttr;
+
+    // The reverse of `Display`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let attr = match s.to_ascii_lowercase().as_str() {
+            "accent" => MathAttr::Accent,
+            "accentunder" => MathAttr::Accentunder,
+            "actiontype" => MathAttr::Actiontype,
+            "alttext" => MathAttr::Alttext,
+            "columnspan" => MathAttr::Columnspan,
+            "depth" => MathAttr::Depth,
+            "dir" => MathAttr::Dir,
+            "display" => MathAttr::Display,
+            "displaystyle" => MathAttr::Displaystyle,
+            "encoding" => MathAttr::Encoding,
+            "fence" => MathAttr::Fence,
+            "form" => MathAttr::Form,
+            "height" => MathAttr::Height,
+            "largeop" => MathAttr::Largeop,
+            "linethickness" => MathAttr::Linethickness,
+            "lspace" => MathAttr::Lspace,
+            "mathbackground" => MathAttr::Mathbackground,
+            "mathcolor" => MathAttr::Mathcolor,
+            "mathsize" => MathAttr::Mathsize,
+            "mathvariant" => MathAttr::Mathvariant,
+            "maxsize" => MathAttr::Maxsize,
+            "minsize" => MathAttr::Minsize,
+            "movablelimits" => MathAttr::Movablelimits,
+            "notation" => MathAttr::Notation,
+            "rowspan" => MathAttr::Rowspan,
+            "rspace" => MathAttr::Rspace,
+            "scriptlevel" => MathAttr::Scriptlevel,
+            "selection" => MathAttr::Selection,
+            "separator" => MathAttr::Separator,
+            "stretchy" => MathAttr::Stretchy,
+            "symmetric" => MathAttr::Symmetric,
+            "voffset" => MathAttr::Voffset,
+            "width" => MathAttr::Width,
+            _ => return Err(UnknownMathAttr(s.to_string())),
+        };
+        Ok(attr)
+    }
+}
+
+// Error returned when a name does not match a `MathAttr` variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownMathAttr(pub String);
+
+impl Display for UnknownMathAttr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown MathML attribute '{}'", self.0)
+    }
+}
+impl std::error::Error for UnknownMathAttr {}
+
+impl MathAttr {
+    // Attributes supported by every MathML element.
+    pub fn global() -> &'static [MathAttr] {
+        &[
+            MathAttr::Dir,
+            MathAttr::Displaystyle,
+            MathAttr::Mathbackground,
+            MathAttr::Mathcolor,
+            MathAttr::Mathsize,
+            MathAttr::Mathvariant,
+            MathAttr::Scriptlevel,
+        ]
+    }
+}
+
+impl From<MathAttr> for Attr {
+    fn from(attr: MathAttr) -> Self {
+        Attr::Math(attr)
+    }
+}
+
+// Whether a formula is part of the surrounding text or a block of its own.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum MathDisplay {
+    Block,
+    #[default]
+    Inline,
+}
+impl Display for MathDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MathDisplay::Block => write!(f, "block"),
+            MathDisplay::Inline => write!(f, "inline"),
+        }
+    }
+}
+
+impl AttrsBuilder {
+    // Any MathML attribute, for those without a typed method.
+    pub fn math(self, attr: MathAttr, value: impl Display) -> Self {
+        self.attr(attr.to_string(), value.to_string())
+    }
+
+    pub fn display(self, display: MathDisplay) -> Self {
+        self.math(MathAttr::Display, display)
+    }
+
+    // A text alternative for the formula, for `math` elements.
+    pub fn alttext(self, alttext: impl Display) -> Self {
+        self.math(MathAttr::Alttext, alttext)
+    }
+
+    pub fn displaystyle(self, displaystyle: bool) -> Self {
+        self.math(MathAttr::Displaystyle, displaystyle)
+    }
+
+    pub fn scriptlevel(self, scriptlevel: impl Display) -> Self {
+        self.math(MathAttr::Scriptlevel, scriptlevel)
+    }
+
+    // The style of an identifier, such as `normal` or `bold`.
+    pub fn mathvariant(self, mathvariant: impl Display) -> Self {
+        self.math(MathAttr::Mathvariant, mathvariant)
+    }
+
+    pub fn mathcolor(self, mathcolor: impl Display) -> Self {
+        self.math(MathAttr::Mathcolor, mathcolor)
+    }
+
+    pub fn mathbackground(self, mathbackground: impl Display) -> Self {
+        self.math(MathAttr::Mathbackground, mathbackground)
+    }
+
+    pub fn mathsize(self, mathsize: impl Display) -> Self {
+        self.math(MathAttr::Mathsize, mathsize)
+    }
+
+    // The thickness of the fraction bar, for `mfrac` elements.
+    pub fn linethickness(self, linethickness: impl Display) -> Self {
+        self.math(MathAttr::Linethickness, linethickness)
+    }
+
+    // Operator attributes, for `mo` elements.
+    pub fn fence(self, fence: bool) -> Self {
+        self.math(MathAttr::Fence, fence)
+    }
+
+    pub fn stretchy(self, stretchy: bool) -> Self {
+        self.math(MathAttr::Stretchy, stretchy)
+    }
+
+    pub fn lspace(self, lspace: impl Display) -> Self {
+        self.math(MathAttr::Lspace, lspace)
+    }
+
+    pub fn rspace(self, rspace: impl Display) -> Self {
+        self.math(MathAttr::Rspace, rspace)
+    }
+
+    pub fn accent(self, accent: bool) -> Self {
+        self.math(MathAttr::Accent, accent)
+    }
+
+    pub fn columnspan(self, columnspan: u32) -> Self {
+        self.math(MathAttr::Columnspan, columnspan)
+    }
+
+    pub fn rowspan(self, rowspan: u32) -> Self {
+        self.math(MathAttr::Rowspan, rowspan)
+    }
+
+    pub fn notation(self, notation: impl Display) -> Self {
+        self.math(MathAttr::Notation, notation)
+    }
+
+    pub fn encoding(self, encoding: impl Display) -> Self {
+        self.math(MathAttr::Encoding, encoding)
+    }
+}
@@ -1,5 +1,14 @@
+mod aria;
+mod event;
 mod html;
 mod makro;
+mod mathml;
+mod svg;
 
-pub use html::{Attr, Attrs, HiddenValue, LinkTarget};
+pub use aria::{AriaCurrent, AriaLive, HasPopup, Role, Tristate, UnknownRole};
+pub use event::{Event, UnknownEvent};
+pub(crate) use html::validate_name;
+pub use html::{Attr, Attrs, AttrsBuilder, HiddenValue, InvalidAttrName, LinkTarget, UnknownAttr};
 pub use makro::*;
+pub use mathml::{MathAttr, MathDisplay, UnknownMathAttr};
+pub use svg::{SvgAttr, UnknownSvgAttr};
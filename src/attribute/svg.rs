@@ -0,0 +1,540 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::{Attr, AttrsBuilder};
+
+// SVG attributes. Names keep their SVG casing, such as `viewBox`, or use
+// hyphens for presentation attributes, such as `stroke-width`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SvgAttr {
+    AttributeName,
+    Begin,
+    ClipPath,
+    ClipPathUnits,
+    ClipRule,
+    Color,
+    Cx,
+    Cy,
+    D,
+    Display,
+    DominantBaseline,
+    Dur,
+    Dx,
+    Dy,
+    Fill,
+    FillOpacity,
+    FillRule,
+    Filter,
+    FilterUnits,
+    FloodColor,
+    FloodOpacity,
+    FontFamily,
+    FontSize,
+    FontWeight,
+    From,
+    Fx,
+    Fy,
+    GradientTransform,
+    GradientUnits,
+    Height,
+    Href,
+    In,
+    In2,
+    KeyTimes,
+    LengthAdjust,
+    MarkerEnd,
+    MarkerHeight,
+    MarkerMid,
+    MarkerStart,
+    MarkerUnits,
+    MarkerWidth,
+    Mask,
+    MaskUnits,
+    Mode,
+    Offset,
+    Opacity,
+    Operator,
+    Orient,
+    PathLength,
+    PatternContentUnits,
+    PatternTransform,
+    PatternUnits,
+    Points,
+    PreserveAspectRatio,
+    R,
+    RefX,
+    RefY,
+    RepeatCount,
+    Result,
+    Rx,
+    Ry,
+    SpreadMethod,
+    StartOffset,
+    StdDeviation,
+    StopColor,
+    StopOpacity,
+    Stroke,
+    StrokeDasharray,
+    StrokeDashoffset,
+    StrokeLinecap,
+    StrokeLinejoin,
+    StrokeMiterlimit,
+    StrokeOpacity,
+    StrokeWidth,
+    Target,
+    TextAnchor,
+    TextLength,
+    To,
+    Transform,
+    Type,
+    Values,
+    VectorEffect,
+    ViewBox,
+    Visibility,
+    Width,
+    X,
+    X1,
+    X2,
+    Xmlns,
+    Y,
+    Y1,
+    Y2,
+}
+impl Display for SvgAttr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            SvgAttr::AttributeName => "attributeName",
+            SvgAttr::Begin => "begin",
+            SvgAttr::ClipPath => "clip-path",
+            SvgAttr::ClipPathUnits => "clipPathUnits",
+            SvgAttr::ClipRule => "clip-rule",
+            SvgAttr::Color => "color",
+            SvgAttr::Cx => "cx",
+            SvgAttr::Cy => "cy",
+            SvgAttr::D => "d",
+            SvgAttr::Display => "display",
+            SvgAttr::DominantBaseline => "dominant-baseline",
+            SvgAttr::Dur => "dur",
+            SvgAttr::Dx => "dx",
+            SvgAttr::Dy => "dy",
+            SvgAttr::Fill => "fill",
+            SvgAttr::FillOpacity => "fill-opacity",
+            SvgAttr::FillRule => "fill-rule",
+            SvgAttr::Filter => "filter",
+            SvgAttr::FilterUnits => "filterUnits",
+            SvgAttr::FloodColor => "flood-color",
+            SvgAttr::FloodOpacity => "flood-opacity",
+            SvgAttr::FontFamily => "font-family",
+            SvgAttr::FontSize => "font-size",
+            SvgAttr::FontWeight => "font-weight",
+            SvgAttr::From => "from",
+            SvgAttr::Fx => "fx",
+            SvgAttr::Fy => "fy",
+            SvgAttr::GradientTransform => "gradientTransform",
+            SvgAttr::GradientUnits => "gradientUnits",
+            SvgAttr::Height => "height",
+            SvgAttr::Href => "href",
+            SvgAttr::In => "in",
+            SvgAttr::In2 => "in2",
+            SvgAttr::KeyTimes => "keyTimes",
+            SvgAttr::LengthAdjust => "lengthAdjust",
+            SvgAttr::MarkerEnd => "marker-end",
+            SvgAttr::MarkerHeight => "markerHeight",
+            SvgAttr::MarkerMid => "marker-mid",
+            SvgAttr::MarkerStart => "marker-start",
+            SvgAttr::MarkerUnits => "markerUnits",
+            SvgAttr::MarkerWidth => "markerWidth",
+            SvgAttr::Mask => "mask",
+            SvgAttr::MaskUnits => "maskUnits",
+            SvgAttr::Mode => "mode",
+            SvgAttr::Offset => "offset",
+            SvgAttr::Opacity => "opacity",
+            SvgAttr::Operator => "operator",
+            SvgAttr::Orient => "orient",
+            SvgAttr::PathLength => "pathLength",
+            SvgAttr::PatternContentUnits => "patternContentUnits",
+            SvgAttr::PatternTransform => "patternTransform",
+            SvgAttr::PatternUnits => "patternUnits",
+            SvgAttr::Points => "points",
+            SvgAttr::PreserveAspectRatio => "preserveAspectRatio",
+            SvgAttr::R => "r",
+            SvgAttr::RefX => "refX",
+            SvgAttr::RefY => "refY",
+            SvgAttr::RepeatCount => "repeatCount",
+            SvgAttr::Result => "result",
+            SvgAttr::Rx => "rx",
+            SvgAttr::Ry => "ry",
+            SvgAttr::SpreadMethod => "spreadMethod",
+            SvgAttr::StartOffset => "startOffset",
+            SvgAttr::StdDeviation => "stdDeviation",
+            SvgAttr::StopColor => "stop-color",
+            SvgAttr::StopOpacity => "stop-opacity",
+            SvgAttr::Stroke => "stroke",
+            SvgAttr::StrokeDasharray => "stroke-dasharray",
+            SvgAttr::StrokeDashoffset => "stroke-dashoffset",
+            SvgAttr::StrokeLinecap => "stroke-linecap",
+            SvgAttr::StrokeLinejoin => "stroke-linejoin",
+            SvgAttr::StrokeMiterlimit => "stroke-miterlimit",
+            SvgAttr::StrokeOpacity => "stroke-opacity",
+            SvgAttr::StrokeWidth => "stroke-width",
+            SvgAttr::Target => "target",
+            SvgAttr::TextAnchor => "text-anchor",
+            SvgAttr::TextLength => "textLength",
+            SvgAttr::To => "to",
+            SvgAttr::Transform => "transform",
+            SvgAttr::Type => "type",
+            SvgAttr::Values => "values",
+            SvgAttr::VectorEffect => "vector-effect",
+            SvgAttr::ViewBox => "viewBox",
+            SvgAttr::Visibility => "visibility",
+            SvgAttr::Width => "width",
+            SvgAttr::X => "x",
+            SvgAttr::X1 => "x1",
+            SvgAttr::X2 => "x2",
+            SvgAttr::Xmlns => "xmlns",
+            SvgAttr::Y => "y",
+            SvgAttr::Y1 => "y1",
+            SvgAttr::Y2 => "y2",
+        };
+        f.write_str(name)
+    }
+}
+impl FromStr for SvgAttr {
+    type Err = UnknownSvgAttr;
+
+    // The reverse of `Display`, ignoring case as the HTML parser does.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let attr = match s.to_ascii_lowercase().as_str() {
+            "attributename" => SvgAttr::AttributeName,
+            "begin" => SvgAttr::Begin,
+            "clip-path" => SvgAttr::ClipPath,
+            "clippathunits" => SvgAttr::ClipPathUnits,
+            "clip-rule" => SvgAttr::ClipRule,
+            "color" => SvgAttr::Color,
+            "cx" => SvgAttr::Cx,
+            "cy" => SvgAttr::Cy,
+            "d" => SvgAttr::D,
+            "display" => SvgAttr::Display,
+            "dominant-baseline" => SvgAttr::DominantBaseline,
+            "dur" => SvgAttr::Dur,
+            "dx" => SvgAttr::Dx,
+            "dy" => SvgAttr::Dy,
+            "fill" => SvgAttr::Fill,
+            "fill-opacity" => SvgAttr::FillOpacity,
+            "fill-rule" => SvgAttr::FillRule,
+            "filter" => SvgAttr::Filter,
+            "filterunits" => SvgAttr::FilterUnits,
+            "flood-color" => SvgAttr::FloodColor,
+            "flood-opacity" => SvgAttr::FloodOpacity,
+            "font-family" => SvgAttr::FontFamily,
+            "font-size" => SvgAttr::FontSize,
+            "font-weight" => SvgAttr::FontWeight,
+            "from" => SvgAttr::From,
+            "fx" => SvgAttr::Fx,
+            "fy" => SvgAttr::Fy,
+            "gradienttransform" => SvgAttr::GradientTransform,
+            "gradientunits" => SvgAttr::GradientUnits,
+            "height" => SvgAttr::Height,
+            "href" => SvgAttr::Href,
+            "in" => SvgAttr::In,
+            "in2" => SvgAttr::In2,
+            "keytimes" => SvgAttr::KeyTimes,
+            "lengthadjust" => SvgAttr::LengthAdjust,
+            "marker-end" => SvgAttr::MarkerEnd,
+            "markerheight" => SvgAttr::MarkerHeight,
+            "marker-mid" => SvgAttr::MarkerMid,
+            "marker-start" => SvgAttr::MarkerStart,
+            "markerunits" => SvgAttr::MarkerUnits,
+            "markerwidth" => SvgAttr::MarkerWidth,
+            "mask" => SvgAttr::Mask,
+            "maskunits" => SvgAttr::MaskUnits,
+            "mode" => SvgAttr::Mode,
+            "offset" => SvgAttr::Offset,
+            "opacity" => SvgAttr::Opacity,
+            "operator" => SvgAttr::Operator,
+            "orient" => SvgAttr::Orient,
+            "pathlength" => SvgAttr::PathLength,
+            "patterncontentunits" => SvgAttr::PatternContentUnits,
+            "patterntransform" => SvgAttr::PatternTransform,
+            "patternunits" => SvgAttr::PatternUnits,
+            "points" => SvgAttr::Points,
+            "preserveaspectratio" => SvgAttr::PreserveAspectRatio,
+            "r" => SvgAttr::R,
+            "refx" => SvgAttr::RefX,
+            "refy" => SvgAttr::RefY,
+            "repeatcount" => SvgAttr::RepeatCount,
+            "result" => SvgAttr::Result,
+            "rx" => SvgAttr::Rx,
+            "ry" => SvgAttr::Ry,
+            "spreadmethod" => SvgAttr::SpreadMethod,
+            "startoffset" => SvgAttr::StartOffset,
+            "stddeviation" => SvgAttr::StdDeviation,
+            "stop-color" => SvgAttr::StopColor,
+            "stop-opacity" => SvgAttr::StopOpacity,
+            "stroke" => SvgAttr::Stroke,
+            "stroke-dasharray" => SvgAttr::StrokeDasharray,
+            "stroke-dashoffset" => SvgAttr::StrokeDashoffset,
+            "stroke-linecap" => SvgAttr::StrokeLinecap,
+            "stroke-linejoin" => SvgAttr::StrokeLinejoin,
+            "stroke-miterlimit" => SvgAttr::StrokeMiterlimit,
+            "stroke-opacity" => SvgAttr::StrokeOpacity,
+            "stroke-width" => SvgAttr::StrokeWidth,
+            "target" => SvgAttr::Target,
+            "text-anchor" => SvgAttr::TextAnchor,
+            "textlength" => SvgAttr::TextLength,
+            "to" => SvgAttr::To,
+            "transform" => SvgAttr::Transform,
+            "type" => SvgAttr::Type,
+            "values" => SvgAttr::Values,
+            "vector-effect" => SvgAttr::VectorEffect,
+            "viewbox" => SvgAttr::ViewBox,
+            "visibility" => SvgAttr::Visibility,
+            "width" => SvgAttr::Width,
+            "x" => SvgAttr::X,
+            "x1" => SvgAttr::X1,
+            "x2" => SvgAttr::X2,
+            "xmlns" => SvgAttr::Xmlns,
+            "y" => SvgAttr::Y,
+            "y1" => SvgAttr::Y1,
+            "y2" => SvgAttr::Y2,
+            _ => return Err(UnknownSvgAttr(s.to_string())),
+        };
+        Ok(attr)
+    }
+}
+
+// Error returned when a name does not match an `SvgAttr` variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownSvgAttr(pub String);
+
+impl Display for UnknownSvgAttr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown SVG attribute '{}'", self.0)
+    }
+}
+impl std::error::Error for UnknownSvgAttr {}
+
+impl SvgAttr {
+    // Attributes that style an element, supported by every SVG element.
+    pub fn presentation() -> &'static [SvgAttr] {
+        &[
+            SvgAttr::ClipPath,
+            SvgAttr::ClipRule,
+            SvgAttr::Color,
+            SvgAttr::Display,
+            SvgAttr::DominantBaseline,
+            SvgAttr::Fill,
+            SvgAttr::FillOpacity,
+            SvgAttr::FillRule,
+            SvgAttr::Filter,
+            SvgAttr::FontFamily,
+            SvgAttr::FontSize,
+            SvgAttr::FontWeight,
+            SvgAttr::MarkerEnd,
+            SvgAttr::MarkerMid,
+            SvgAttr::MarkerStart,
+            SvgAttr::Mask,
+            SvgAttr::Opacity,
+            SvgAttr::Stroke,
+            SvgAttr::StrokeDasharray,
+            SvgAttr::StrokeDashoffset,
+            SvgAttr::StrokeLinecap,
+            SvgAttr::StrokeLinejoin,
+            SvgAttr::StrokeMiterlimit,
+            SvgAttr::StrokeOpacity,
+            SvgAttr::StrokeWidth,
+            SvgAttr::TextAnchor,
+            SvgAttr::Transform,
+            SvgAttr::VectorEffect,
+            SvgAttr::Visibility,
+        ]
+    }
+}
+
+impl From<SvgAttr> for Attr {
+    fn from(attr: SvgAttr) -> Self {
+        Attr::Svg(attr)
+    }
+}
+
+// SVG attributes take any value that can be displayed, so numbers can be
+// passed as they are.
+impl AttrsBuilder {
+    // Any SVG attribute, for those without a typed method.
+    pub fn svg(self, attr: SvgAttr, value: impl Display) -> Self {
+        self.attr(attr.to_string(), value.to_string())
+    }
+
+    pub fn view_box(self, view_box: impl Display) -> Self {
+        self.svg(SvgAttr::ViewBox, view_box)
+    }
+
+    pub fn preserve_aspect_ratio(self, preserve_aspect_ratio: impl Display) -> Self {
+        self.svg(SvgAttr::PreserveAspectRatio, preserve_aspect_ratio)
+    }
+
+    pub fn xmlns(self, xmlns: impl Display) -> Self {
+        self.svg(SvgAttr::Xmlns, xmlns)
+    }
+
+    pub fn d(self, d: impl Display) -> Self {
+        self.svg(SvgAttr::D, d)
+    }
+
+    pub fn x(self, x: impl Display) -> Self {
+        self.svg(SvgAttr::X, x)
+    }
+
+    pub fn y(self, y: impl Display) -> Self {
+        self.svg(SvgAttr::Y, y)
+    }
+
+    pub fn dx(self, dx: impl Display) -> Self {
+        self.svg(SvgAttr::Dx, dx)
+    }
+
+    pub fn dy(self, dy: impl Display) -> Self {
+        self.svg(SvgAttr::Dy, dy)
+    }
+
+    pub fn cx(self, cx: impl Display) -> Self {
+        self.svg(SvgAttr::Cx, cx)
+    }
+
+    pub fn cy(self, cy: impl Display) -> Self {
+        self.svg(SvgAttr::Cy, cy)
+    }
+
+    pub fn r(self, r: impl Display) -> Self {
+        self.svg(SvgAttr::R, r)
+    }
+
+    pub fn rx(self, rx: impl Display) -> Self {
+        self.svg(SvgAttr::Rx, rx)
+    }
+
+    pub fn ry(self, ry: impl Display) -> Self {
+        self.svg(SvgAttr::Ry, ry)
+    }
+
+    pub fn x1(self, x1: impl Display) -> Self {
+        self.svg(SvgAttr::X1, x1)
+    }
+
+    pub fn y1(self, y1: impl Display) -> Self {
+        self.svg(SvgAttr::Y1, y1)
+    }
+
+    pub fn x2(self, x2: impl Display) -> Self {
+        self.svg(SvgAttr::X2, x2)
+    }
+
+    pub fn y2(self, y2: impl Display) -> Self {
+        self.svg(SvgAttr::Y2, y2)
+    }
+
+    pub fn points(self, points: impl Display) -> Self {
+        self.svg(SvgAttr::Points, points)
+    }
+
+    pub fn fill(self, fill: impl Display) -> Self {
+        self.svg(SvgAttr::Fill, fill)
+    }
+
+    pub fn fill_opacity(self, fill_opacity: impl Display) -> Self {
+        self.svg(SvgAttr::FillOpacity, fill_opacity)
+    }
+
+    pub fn stroke(self, stroke: impl Display) -> Self {
+        self.svg(SvgAttr::Stroke, stroke)
+    }
+
+    pub fn stroke_width(self, stroke_width: impl Display) -> Self {
+        self.svg(SvgAttr::StrokeWidth, stroke_width)
+    }
+
+    pub fn stroke_linecap(self, stroke_linecap: impl Display) -> Self {
+        self.svg(SvgAttr::StrokeLinecap, stroke_linecap)
+    }
+
+    pub fn stroke_linejoin(self, stroke_linejoin: impl Display) -> Self {
+        self.svg(SvgAttr::StrokeLinejoin, stroke_linejoin)
+    }
+
+    pub fn stroke_dasharray(self, stroke_dasharray: impl Display) -> Self {
+        self.svg(SvgAttr::StrokeDasharray, stroke_dasharray)
+    }
+
+    pub fn opacity(self, opacity: impl Display) -> Self {
+        self.svg(SvgAttr::Opacity, opacity)
+    }
+
+    pub fn transform(self, transform: impl Display) -> Self {
+        self.svg(SvgAttr::Transform, transform)
+    }
+
+    pub fn text_anchor(self, text_anchor: impl Display) -> Self {
+        self.svg(SvgAttr::TextAnchor, text_anchor)
+    }
+
+    pub fn font_size(self, font_size: impl Display) -> Self {
+        self.svg(SvgAttr::FontSize, font_size)
+    }
+
+    pub fn font_family(self, font_family: impl Display) -> Self {
+        self.svg(SvgAttr::FontFamily, font_family)
+    }
+
+    pub fn offset(self, offset: impl Display) -> Self {
+        self.svg(SvgAttr::Offset, offset)
+    }
+
+    pub fn stop_color(self, stop_color: impl Display) -> Self {
+        self.svg(SvgAttr::StopColor, stop_color)
+    }
+
+    pub fn gradient_units(self, gradient_units: impl Display) -> Self {
+        self.svg(SvgAttr::GradientUnits, gradient_units)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_svg_attribute_names() {
+        assert_eq!(SvgAttr::ViewBox.to_string(), "viewBox");
+        assert_eq!(SvgAttr::StrokeWidth.to_string(), "stroke-width");
+        assert_eq!("viewbox".parse::<SvgAttr>(), Ok(SvgAttr::ViewBox));
+        assert_eq!("stroke-width".parse::<SvgAttr>(), Ok(SvgAttr::StrokeWidth));
+        assert_eq!(Attr::from(SvgAttr::ViewBox).to_string(), "viewBox");
+    }
+
+    #[test]
+    fn test_attributes_are_looked_up_per_namespace() {
+        let rect = Tag::Svg(SvgTag::Rect);
+        assert_eq!(
+            Attr::from_name_on(&rect, "width"),
+            Some(Attr::Svg(SvgAttr::Width))
+        );
+        assert_eq!(Attr::from_name_on(&rect, "class"), Some(Attr::Class));
+        assert_eq!(Attr::from_name_on(&Tag::Div, "width"), Some(Attr::Width));
+        assert_eq!(Attr::from_name_on(&Tag::Div, "viewBox"), None);
+
+        // Presentation attributes apply to every SVG element, others only
+        // to the elements that use them.
+        let attrs = Attrs::new().fill("red").stroke_width(2).build();
+        assert_eq!(
+            attrs.get_attrs(&rect),
+            vec![r#"fill="red""#, r#"stroke-width="2""#]
+        );
+        assert_eq!(
+            attrs.get_attrs(&Tag::Svg(SvgTag::Stop)),
+            vec![r#"fill="red""#, r#"stroke-width="2""#]
+        );
+        let stop = Attrs::new().offset("50%").stop_color("#fff").build();
+        assert_eq!(stop.get_attrs(&rect), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,558 @@
+use std::fmt::{Display, Write};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+
+use super::Document;
+use crate::attribute::{Attr, Attrs, Event};
+use crate::element::{Element, ElementBuilder, Visit};
+use crate::escape::{escape, Context};
+use crate::render::{Config, Render, RenderError};
+use crate::tag::Tag;
+
+// The attribute that links an element to its handlers in the script
+// added by `bind_handlers`.
+const HOOK: &str = "data-handlers";
+
+// Binds the handlers of this script to the elements with their hooks, so
+// scripts from earlier calls leave them alone. A handler returning false
+// prevents the default action, as it does inline.
+const BINDER: &str = r#"Object.keys(handlers).forEach(function (hook) {
+document.querySelectorAll('[data-handlers~="' + hook + '"]').forEach(function (element) {
+handlers[hook].forEach(function (handler) {
+var target = handler[1] ? window : element;
+target.addEventListener(handler[0], function (event) {
+if (handler[2].call(target, event) === false) event.preventDefault();
+});
+});
+});
+});"#;
+
+impl Document {
+    // Move every inline event handler into a `<script>` with the nonce, at
+    // the end of the body, so a Content-Security-Policy without
+    // 'unsafe-inline' still runs them. Each element with handlers gets a
+    // `data-handlers` hook that the script binds them to. Hooks continue
+    // from those of earlier calls, and are added to an element's existing
+    // hooks. Returns how many handlers were moved, adding no script when
+    // there are none.
+    //
+    // Only call it on trees you built. Any `on*` attribute in parsed or
+    // deserialized markup gets the nonce too, which lets that markup run
+    // script.
+    pub fn bind_handlers(&mut self, nonce: &str) -> usize {
+        let mut handlers = String::new();
+        let mut hook = self.next_hook();
+        let mut moved = 0;
+        self.walk_mut(|element| {
+            let taken = take_handlers(element);
+            if taken.is_empty() {
+                return Visit::Continue;
+            }
+            // Handlers on the body for window events, such as `onload`,
+            // are bound to the window, as browsers do.
+            let body = element.tag == Tag::Body;
+            let separator = if moved > 0 { ",\n" } else { "" };
+            let _ = write!(handlers, "{separator}{hook}: [");
+            for (i, (event, script)) in taken.iter().enumerate() {
+                let separator = if i > 0 { ", " } else { "" };
+                let window = body && is_window_event(*event);
+                let _ = write!(
+                    handlers,
+                    "{separator}[\"{event}\", {window}, function (event) {{\n{script}\n}}]"
+                );
+            }
+            handlers.push(']');
+            let hooks = match element.attr(HOOK) {
+                Some(existing) => format!("{existing} {hook}"),
+                None => hook.to_string(),
+            };
+            element.attrs_mut().set(HOOK, Some(hooks));
+            hook += 1;
+            moved += taken.len();
+            Visit::Continue
+        });
+        if moved == 0 {
+            return 0;
+        }
+
+        let script = ElementBuilder::new(Tag::Script)
+            .attrs(Attrs::new().nonce(nonce).build())
+            .content(format!(
+                "(function () {{\nvar handlers = {{{handlers}}};\n{BINDER}\n}})();"
+            ))
+            .build();
+        match body_mut(&mut self.elements) {
+            Some(body) => body.children.get_or_insert_with(Vec::new).push(script),
+            None => self.elements.push(script),
+        }
+        moved
+    }
+
+    // The hook after the highest already in the document.
+    fn next_hook(&self) -> usize {
+        let mut next = 0;
+        self.walk(|element| {
+            let hooks = element.attr(HOOK).unwrap_or_default().split_whitespace();
+            for hook in hooks.filter_map(|hook| hook.parse::<usize>().ok()) {
+                next = next.max(hook + 1);
+            }
+            Visit::Continue
+        });
+        next
+    }
+}
+
+// A Content-Security-Policy for rendering a document with a per-request
+// nonce. Rendering sets the nonce on every script and style, and returns
+// the matching header value with the HTML, so neither is patched by hand.
+// Nonces do not cover `style` attributes, so their hashes are added to
+// `style-src` with 'unsafe-hashes'. Inline event handlers are left as they
+// are, so the policy blocks them, unless `bind_handlers` is set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Csp {
+    nonce: String,
+    hash_scripts: bool,
+    bind_handlers: bool,
+    directives: Vec<(String, Vec<String>)>,
+}
+
+impl Csp {
+    // The nonce should be at least 128 bits from a secure random source,
+    // base64 encoded, and never reused.
+    pub fn new(nonce: impl Into<String>) -> CspBuilder {
+        CspBuilder {
+            csp: Csp {
+                nonce: nonce.into(),
+                hash_scripts: false,
+                bind_handlers: false,
+                directives: vec![],
+            },
+        }
+    }
+
+    pub fn nonce(&self) -> &str {
+        &self.nonce
+    }
+
+    // The header value for the scripts and styles of the rendered document.
+    // A directive given 'unsafe-inline' gets no nonce or hashes, as
+    // browsers ignore 'unsafe-inline' alongside them.
+    fn header(&self, script_hashes: &[String], style_hashes: &[String]) -> String {
+        let mut directives = self.directives.clone();
+        let nonce = format!("'nonce-{}'", self.nonce);
+        let mut script_src = vec![nonce.clone()];
+        script_src.extend(script_hashes.iter().map(|hash| format!("'sha256-{hash}'")));
+        let mut style_src = vec![nonce];
+        if !style_hashes.is_empty() {
+            style_src.push("'unsafe-hashes'".to_string());
+            style_src.extend(style_hashes.iter().map(|hash| format!("'sha256-{hash}'")));
+        }
+        for (name, sources) in [("script-src", script_src), ("style-src", style_src)] {
+            let inline = directives.iter().any(|(found, existing)| {
+                found == name && existing.iter().any(|source| source == "'unsafe-inline'")
+            });
+            if !inline {
+                add_sources(&mut directives, name, sources);
+            }
+        }
+        directives
+            .iter()
+            .map(|(name, sources)| match sources.is_empty() {
+                true => name.clone(),
+                false => format!("{name} {}", sources.join(" ")),
+            })
+            .collect::<Vec<String>>()
+            .join("; ")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CspBuilder {
+    csp: Csp,
+}
+
+impl CspBuilder {
+    // Add the SHA-256 hash of each inline script to `script-src`, for
+    // browsers that cache pages or strip nonces.
+    pub fn hash_scripts(mut self, hash_scripts: bool) -> Self {
+        self.csp.hash_scripts = hash_scripts;
+        self
+    }
+
+    // Move inline event handlers into a script, as `Document::bind_handlers`
+    // does, as the policy does not allow them. Only for trusted trees, as
+    // it lets any `on*` attribute in the document run.
+    pub fn bind_handlers(mut self, bind_handlers: bool) -> Self {
+        self.csp.bind_handlers = bind_handlers;
+        self
+    }
+
+    // A directive and its sources, such as `default-src 'self'`. Sources
+    // for `script-src` and `style-src` come before the nonce and hashes.
+    pub fn directive(mut self, name: impl Into<String>, sources: &[&str]) -> Self {
+        let sources = sources.iter().map(|source| source.to_string()).collect();
+        add_sources(&mut self.csp.directives, &name.into(), sources);
+        self
+    }
+
+    // Fails if the nonce or a directive could break out of the header or
+    // the `nonce` attribute.
+    pub fn build(self) -> Result<Csp, InvalidCsp> {
+        let nonce = &self.csp.nonce;
+        if nonce.is_empty()
+            || !nonce
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '=' | '-' | '_'))
+        {
+            return Err(InvalidCsp::Nonce(nonce.clone()));
+        }
+        for (name, sources) in &self.csp.directives {
+            let invalid = |value: &str| {
+                value.is_empty()
+                    || value.contains(|c: char| c == ';' || c == ',' || !c.is_ascii_graphic())
+            };
+            if invalid(name) || sources.iter().any(|source| invalid(source)) {
+                return Err(InvalidCsp::Directive(name.clone()));
+            }
+        }
+        Ok(self.csp)
+    }
+}
+
+fn add_sources(directives: &mut Vec<(String, Vec<String>)>, name: &str, sources: Vec<String>) {
+    match directives.iter_mut().find(|(found, _)| found == name) {
+        Some((_, existing)) => existing.extend(sources),
+        None => directives.push((name.to_string(), sources)),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvalidCsp {
+    // The nonce is empty or has characters outside base64.
+    Nonce(String),
+    // A directive name or source is empty, or has a separator or space.
+    Directive(String),
+}
+impl Display for InvalidCsp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidCsp::Nonce(nonce) => write!(f, "invalid CSP nonce '{nonce}'"),
+            InvalidCsp::Directive(name) => write!(f, "invalid CSP directive '{name}'"),
+        }
+    }
+}
+impl std::error::Error for InvalidCsp {}
+
+// A rendered document and the `Content-Security-Policy` header value to
+// send with it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CspOutput {
+    pub html: String,
+    pub header: String,
+}
+
+impl Document {
+    // Render with the nonce set on every script and style.
+    pub fn render_csp(&self, csp: &Csp) -> CspOutput {
+        let config = Config::new().xml(self.doctype.is_xhtml()).build();
+        self.render_csp_with(csp, &config)
+            .expect("writing to a String cannot fail")
+    }
+
+    pub fn render_csp_with(&self, csp: &Csp, config: &Config) -> Result<CspOutput, RenderError> {
+        let mut document = self.clone();
+        if csp.bind_handlers {
+            document.bind_handlers(&csp.nonce);
+        }
+        let mut script_hashes = vec![];
+        let mut style_hashes = vec![];
+        document.walk_mut(|element| {
+            if matches!(element.tag, Tag::Script | Tag::Style) {
+                element
+                    .attrs_mut()
+                    .set(Attr::Nonce.to_string(), Some(&csp.nonce));
+            }
+            if csp.hash_scripts && element.tag == Tag::Script && element.attr("src").is_none() {
+                if let Some(content) = element.content.as_deref().filter(|c| !c.is_empty()) {
+                    let written = escape(content, Context::RawText);
+                    add_hash(&mut script_hashes, &written);
+                }
+            }
+            // The hash is of the value as the browser reads it, unescaped.
+            if let Some(style) = element.attr("style").filter(|s| !s.is_empty()) {
+                add_hash(&mut style_hashes, style);
+            }
+            Visit::Continue
+        });
+        let mut html = String::new();
+        document.render_with(config, &mut html)?;
+        Ok(CspOutput {
+            html,
+            header: csp.header(&script_hashes, &style_hashes),
+        })
+    }
+}
+
+// Add the base64 SHA-256 hash of a script or style, once.
+fn add_hash(hashes: &mut Vec<String>, content: &str) {
+    let hash = BASE64.encode(Sha256::digest(content.as_bytes()));
+    if !hashes.contains(&hash) {
+        hashes.push(hash);
+    }
+}
+
+// Remove the event handler attributes of an element, in name order.
+fn take_handlers(element: &mut Element) -> Vec<(Event, String)> {
+    let Some(attrs) = &mut element.attrs else {
+        return vec![];
+    };
+    let handlers: Vec<(String, Event, String)> = attrs
+        .iter()
+        .filter_map(|(name, script)| {
+            let event = Event::from_attr_name(name)?;
+            Some((
+                name.to_string(),
+                event,
+                script.unwrap_or_default().to_string(),
+            ))
+        })
+        .collect();
+    handlers
+        .into_iter()
+        .map(|(name, event, script)| {
+            attrs.remove(&name);
+            (event, script)
+        })
+        .collect()
+}
+
+// Events that a handler on the body listens for on the window.
+fn is_window_event(event: Event) -> bool {
+    matches!(
+        event,
+        Event::AfterPrint
+            | Event::BeforePrint
+            | Event::BeforeUnload
+            | Event::Blur
+            | Event::Error
+            | Event::Focus
+            | Event::HashChange
+            | Event::Load
+            | Event::Message
+            | Event::Offline
+            | Event::Online
+            | Event::PageHide
+            | Event::PageShow
+            | Event::PopState
+            | Event::Resize
+            | Event::Scroll
+            | Event::Storage
+            | Event::Unload
+    )
+}
+
+fn body_mut(nodes: &mut [Element]) -> Option<&mut Element> {
+    for node in nodes {
+        if node.tag == Tag::Body {
+            return Some(node);
+        }
+        if let Some(body) = node.children.as_deref_mut().and_then(body_mut) {
+            return Some(body);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bind_handlers() {
+        let mut page = parse(concat!(
+            r#"<!DOCTYPE html><html><body onload="init()">"#,
+            r#"<button onclick="count++" onmouseover="hover(this)">Add</button>"#,
+            r#"<input onkeydown="if (event.key === '<') return false; // </script>">"#,
+            "</body></html>"
+        ))
+        .unwrap();
+
+        assert_eq!(page.bind_handlers("r4nd0m"), 4);
+        assert_eq!(page.select("[onclick], [onkeydown]").unwrap().len(), 0);
+        assert_eq!(page.select("[data-handlers]").unwrap().len(), 3);
+
+        let html = page.to_string();
+        assert!(html.starts_with(concat!(
+            r#"<!DOCTYPE html><html><body data-handlers="0">"#,
+            r#"<button data-handlers="1">Add</button><input data-handlers="2">"#,
+            r#"<script nonce="r4nd0m">(function () {"#
+        )));
+        assert!(html.contains(concat!(
+            "var handlers = {0: [[\"load\", true, function (event) {\ninit()\n}]],\n",
+            "1: [[\"click\", false, function (event) {\ncount++\n}], ",
+            "[\"mouseover\", false, function (event) {\nhover(this)\n}]],\n",
+            "2: [[\"keydown\", false, function (event) {\n",
+            "if (event.key === '<') return false; // <\\/script>\n}]]};"
+        )));
+        assert!(html.ends_with("})();</script></body></html>"));
+
+        // Once moved, there is nothing left to bind.
+        assert_eq!(page.bind_handlers("r4nd0m"), 0);
+        assert_eq!(page.select("script").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_bind_handlers_again() {
+        let mut page = parse(concat!(
+            "<!DOCTYPE html><html><body>",
+            r#"<button onclick="a()">A</button><p>B</p>"#,
+            "</body></html>"
+        ))
+        .unwrap();
+        assert_eq!(page.bind_handlers("r4nd0m"), 1);
+
+        // Handlers added later get hooks of their own, added to any the
+        // element has, and the new script binds only those.
+        page.walk_mut(|element| {
+            if matches!(element.tag, Tag::Button | Tag::P) {
+                element.attrs_mut().set("onclick", Some("b()"));
+            }
+            Visit::Continue
+        });
+        assert_eq!(page.bind_handlers("r4nd0m"), 2);
+
+        let html = page.to_string();
+        assert!(html.contains(r#"<button data-handlers="0 1">A</button><p data-handlers="2">"#));
+        let scripts = page.select("script").unwrap();
+        assert_eq!(scripts.len(), 2);
+        let second = scripts[1].content.as_deref().unwrap();
+        assert!(second
+            .contains("var handlers = {1: [[\"click\", false, function (event) {\nb()\n}]],\n2: "));
+        assert!(!second.contains("a()"));
+        assert!(second.contains(r#"'[data-handlers~="' + hook + '"]'"#));
+    }
+
+    #[test]
+    fn test_render_csp() {
+        let page = Document::new()
+            .head(
+                HeadBuilder::new()
+                    .title("Pay")
+                    .element(
+                        ElementBuilder::new(Tag::Script)
+                            .attrs(Attrs::new().src("/app.js").nonce("stale").build())
+                            .build(),
+                    )
+                    .element(
+                        ElementBuilder::new(Tag::Style)
+                            .content("p { color: red }")
+                            .build(),
+                    ),
+            )
+            .body(vec![
+                ElementBuilder::new(Tag::Button)
+                    .attrs(Attrs::new().onclick("pay()").build())
+                    .content("Pay")
+                    .build(),
+                ElementBuilder::new(Tag::Script)
+                    .content("alert('</script>')")
+                    .build(),
+            ])
+            .build();
+        let csp = Csp::new("bm9uY2U=")
+            .directive("default-src", &["'self'"])
+            .directive("script-src", &["'strict-dynamic'"])
+            .hash_scripts(true)
+            .bind_handlers(true)
+            .build()
+            .unwrap();
+
+        let CspOutput { html, header } = page.render_csp(&csp);
+        assert_eq!(html.matches(r#"nonce="bm9uY2U=""#).count(), 4);
+        assert!(!html.contains("stale") && !html.contains("onclick"));
+        assert!(html.contains(r#"<script nonce="bm9uY2U=">alert('<\/script>')</script>"#));
+
+        let hashes: Vec<&str> = header
+            .split(' ')
+            .filter(|source| source.starts_with("'sha256-"))
+            .collect();
+        assert_eq!(hashes.len(), 2);
+        // The hash is of the script as written, `<\/script>` and all.
+        assert_eq!(
+            hashes[0],
+            format!(
+                "'sha256-{}'",
+                BASE64.encode(Sha256::digest(r"alert('<\/script>')"))
+            )
+        );
+        assert!(header.starts_with(
+            "default-src 'self'; script-src 'strict-dynamic' 'nonce-bm9uY2U=' 'sha256-"
+        ));
+        assert!(header.ends_with("; style-src 'nonce-bm9uY2U='"));
+    }
+
+    #[test]
+    fn test_csp_style_attributes() {
+        let style = Css::new().font_family(r#""Open Sans""#).build();
+        let p = || {
+            ElementBuilder::new(Tag::P)
+                .attrs(Attrs::new().style(style.clone()).build())
+                .build()
+        };
+        let page = Document::new().body(vec![p(), p()]).build();
+        let csp = Csp::new("bm9uY2U=").build().unwrap();
+
+        // One hash per distinct value, of the value before escaping.
+        let hash = BASE64.encode(Sha256::digest(r#"font-family: "Open Sans""#));
+        assert_eq!(
+            page.render_csp(&csp).header,
+            format!("script-src 'nonce-bm9uY2U='; style-src 'nonce-bm9uY2U=' 'unsafe-hashes' 'sha256-{hash}'")
+        );
+
+        // Given 'unsafe-inline', the directive is left as it is.
+        let csp = Csp::new("bm9uY2U=")
+            .directive("style-src", &["'unsafe-inline'"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            page.render_csp(&csp).header,
+            "style-src 'unsafe-inline'; script-src 'nonce-bm9uY2U='"
+        );
+    }
+
+    #[test]
+    fn test_csp_leaves_handlers_by_default() {
+        let page = Document::new()
+            .body(parse_fragment(r#"<img src="x" alt="" onerror="alert(1)">"#).unwrap())
+            .build();
+
+        // Injected handlers stay inline, where the policy blocks them.
+        let CspOutput { html, header } = page.render_csp(&Csp::new("abc").build().unwrap());
+        assert!(html.contains(r#"onerror="alert(1)""#));
+        assert!(!html.contains("<script") && !html.contains("data-handlers"));
+        assert_eq!(header, "script-src 'nonce-abc'; style-src 'nonce-abc'");
+
+        let csp = Csp::new("abc").bind_handlers(true).build().unwrap();
+        let html = page.render_csp(&csp).html;
+        assert!(!html.contains("onerror") && html.contains(r#"data-handlers="0""#));
+    }
+
+    #[test]
+    fn test_invalid_csp() {
+        assert_eq!(
+            Csp::new("a\"b").build().unwrap_err(),
+            InvalidCsp::Nonce("a\"b".to_string())
+        );
+        assert_eq!(
+            Csp::new("abc")
+                .directive("img-src", &["'self'; script-src *"])
+                .build()
+                .unwrap_err()
+                .to_string(),
+            "invalid CSP directive 'img-src'"
+        );
+    }
+}
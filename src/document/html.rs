@@ -1,19 +1,83 @@
-use std::fmt::Display;
+use std::fmt::{Display, Write};
 
-use crate::element::Element;
-use crate::tag::Tag;
+use crate::attribute::{Attrs, AttrsBuilder};
+use crate::element::{Element, ElementBuilder};
+use crate::render::{Config, Printer, Render, RenderError};
+use crate::tag::{CustomElements, Tag};
+use crate::validate::{lint_document, validate_document, A11yIssue, ValidationError};
 
 #[derive(Debug, Default, Clone, PartialEq)]
 pub enum Doctype {
     #[default]
     Html,
+    Xhtml1Strict,
+    Xhtml1Transitional,
+    Xhtml11,
+    Html401Strict,
+    Html401Transitional,
 }
 impl Display for Doctype {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("html")?;
+        if let (Some(public), Some(system)) = (self.public_id(), self.system_id()) {
+            write!(f, r#" PUBLIC "{public}" "{system}""#)?;
+        }
+        Ok(())
+    }
+}
+
+impl Doctype {
+    pub const ALL: [Doctype; 6] = [
+        Doctype::Html,
+        Doctype::Xhtml1Strict,
+        Doctype::Xhtml1Transitional,
+        Doctype::Xhtml11,
+        Doctype::Html401Strict,
+        Doctype::Html401Transitional,
+    ];
+
+    // The formal public identifier. HTML5 does not have one.
+    pub fn public_id(&self) -> Option<&'static str> {
+        match self {
+            Doctype::Html => None,
+            Doctype::Xhtml1Strict => Some("-//W3C//DTD XHTML 1.0 Strict//EN"),
+            Doctype::Xhtml1Transitional => Some("-//W3C//DTD XHTML 1.0 Transitional//EN"),
+            Doctype::Xhtml11 => Some("-//W3C//DTD XHTML 1.1//EN"),
+            Doctype::Html401Strict => Some("-//W3C//DTD HTML 4.01//EN"),
+            Doctype::Html401Transitional => Some("-//W3C//DTD HTML 4.01 Transitional//EN"),
+        }
+    }
+
+    // The URL of the document type definition.
+    pub fn system_id(&self) -> Option<&'static str> {
         match self {
-            Doctype::Html => write!(f, "html"),
+            Doctype::Html => None,
+            Doctype::Xhtml1Strict => Some("http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd"),
+            Doctype::Xhtml1Transitional => {
+                Some("http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd")
+            }
+            Doctype::Xhtml11 => Some("http://www.w3.org/TR/xhtml11/DTD/xhtml11.dtd"),
+            Doctype::Html401Strict => Some("http://www.w3.org/TR/html4/strict.dtd"),
+            Doctype::Html401Transitional => Some("http://www.w3.org/TR/html4/loose.dtd"),
         }
     }
+
+    // XHTML documents are XML, and are written with XML serialization by default.
+    pub fn is_xhtml(&self) -> bool {
+        matches!(
+            self,
+            Doctype::Xhtml1Strict | Doctype::Xhtml1Transitional | Doctype::Xhtml11
+        )
+    }
+
+    // Find the doctype with a public identifier, ignoring case.
+    pub fn from_public_id(id: &str) -> Option<Doctype> {
+        Doctype::ALL.into_iter().find(|doctype| {
+            doctype
+                .public_id()
+                .is_some_and(|public| public.eq_ignore_ascii_case(id.trim()))
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -23,8 +87,332 @@ pub struct Document {
 }
 impl Display for Document {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let doctype = Element::open_tag(&Tag::Doctype, Doctype::Html.to_string());
-        let result: String = self.elements.iter().map(|tag| tag.to_string()).collect();
-        write!(f, "{}{}", doctype, result)
+        match f.alternate() {
+            true => {
+                let config = Config::new()
+                    .pretty(true)
+                    .xml(self.doctype.is_xhtml())
+                    .build();
+                self.render_with(&config, f).map_err(|_| std::fmt::Error)
+            }
+            false => self.render_to(f),
+        }
+    }
+}
+
+impl Render for Document {
+    fn render_to(&self, w: &mut impl Write) -> std::fmt::Result {
+        let config = Config::new().xml(self.doctype.is_xhtml()).build();
+        Printer::new(&config, w).document(self)
+    }
+
+    fn render_with(&self, config: &Config, w: &mut impl Write) -> Result<(), RenderError> {
+        if config.strict {
+            self.validate().map_err(RenderError::Invalid)?;
+        }
+        Ok(Printer::new(config, w).document(self)?)
+    }
+}
+
+impl Document {
+    pub fn new() -> DocumentBuilder {
+        DocumentBuilder::new()
+    }
+    // Check every element in the document, returning every problem found.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        self.validate_with(&CustomElements::default())
+    }
+    // Like `validate`, checking custom elements without a definition
+    // against the one they were registered with.
+    pub fn validate_with(
+        &self,
+        custom_elements: &CustomElements,
+    ) -> Result<(), Vec<ValidationError>> {
+        let errors = validate_document(self, custom_elements);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+    // Check every element in the document for accessibility problems.
+    pub fn lint_a11y(&self) -> Result<(), Vec<A11yIssue>> {
+        let issues = lint_document(self);
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+// Builds a document with an `html` root holding a `head` and a `body`.
+#[derive(Debug, Default, Clone)]
+pub struct DocumentBuilder {
+    doctype: Doctype,
+    lang: Option<String>,
+    head: HeadBuilder,
+    body: Vec<Element>,
+}
+
+impl DocumentBuilder {
+    pub fn new() -> Self {
+        DocumentBuilder::default()
+    }
+
+    pub fn doctype(mut self, doctype: Doctype) -> Self {
+        self.doctype = doctype;
+        self
+    }
+
+    // The language of the page, set on the `html` element.
+    pub fn lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    pub fn head(mut self, head: HeadBuilder) -> Self {
+        self.head = head;
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.head = self.head.title(title);
+        self
+    }
+
+    pub fn meta_charset(mut self, charset: impl Into<String>) -> Self {
+        self.head = self.head.meta_charset(charset);
+        self
+    }
+
+    pub fn viewport(mut self, viewport: impl Into<String>) -> Self {
+        self.head = self.head.viewport(viewport);
+        self
+    }
+
+    pub fn stylesheet(mut self, href: impl Into<String>) -> Self {
+        self.head = self.head.stylesheet(href);
+        self
+    }
+
+    pub fn script(mut self, src: impl Into<String>) -> Self {
+        self.head = self.head.script(src);
+        self
+    }
+
+    pub fn canonical(mut self, href: impl Into<String>) -> Self {
+        self.head = self.head.canonical(href);
+        self
+    }
+
+    // The elements within the `body`.
+    pub fn body(mut self, body: Vec<Element>) -> Self {
+        self.body = body;
+        self
+    }
+
+    pub fn build(self) -> Document {
+        let mut html = ElementBuilder::new(Tag::Html);
+        if let Some(lang) = self.lang {
+            html = html.attrs(Attrs::new().lang(lang).build());
+        }
+        let body = ElementBuilder::new(Tag::Body).children(self.body).build();
+        Document {
+            doctype: self.doctype,
+            elements: vec![html.children(vec![self.head.build(), body]).build()],
+        }
+    }
+}
+
+// Builds a `head` element. Each entry is written once, and entries are
+// written in a fixed order whatever order they were added in: the charset
+// first, as browsers only look for it at the start of the page, then the
+// viewport, title, other meta tags, links and scripts.
+#[derive(Debug, Default, Clone)]
+pub struct HeadBuilder {
+    charset: Option<String>,
+    viewport: Option<String>,
+    title: Option<String>,
+    // Meta tags by name, in the order they were added.
+    meta: Vec<(String, String)>,
+    canonical: Option<String>,
+    icons: Vec<String>,
+    stylesheets: Vec<String>,
+    scripts: Vec<String>,
+    other: Vec<Element>,
+}
+
+impl HeadBuilder {
+    pub fn new() -> Self {
+        HeadBuilder::default()
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn meta_charset(mut self, charset: impl Into<String>) -> Self {
+        self.charset = Some(charset.into());
+        self
+    }
+
+    // The viewport meta tag, such as `width=device-width, initial-scale=1`.
+    pub fn viewport(mut self, viewport: impl Into<String>) -> Self {
+        self.viewport = Some(viewport.into());
+        self
+    }
+
+    // A named meta tag. Setting a name again replaces its content.
+    pub fn meta(mut self, name: impl Into<String>, content: impl Into<String>) -> Self {
+        let (name, content) = (name.into(), content.into());
+        match self.meta.iter_mut().find(|(existing, _)| *existing == name) {
+            Some(meta) => meta.1 = content,
+            None => self.meta.push((name, content)),
+        }
+        self
+    }
+
+    pub fn description(self, description: impl Into<String>) -> Self {
+        self.meta("description", description)
+    }
+
+    pub fn canonical(mut self, href: impl Into<String>) -> Self {
+        self.canonical = Some(href.into());
+        self
+    }
+
+    pub fn icon(mut self, href: impl Into<String>) -> Self {
+        push_unique(&mut self.icons, href.into());
+        self
+    }
+
+    pub fn stylesheet(mut self, href: impl Into<String>) -> Self {
+        push_unique(&mut self.stylesheets, href.into());
+        self
+    }
+
+    // A script that runs once the page has been parsed.
+    pub fn script(mut self, src: impl Into<String>) -> Self {
+        push_unique(&mut self.scripts, src.into());
+        self
+    }
+
+    // Any other element, written after the rest.
+    pub fn element(mut self, element: Element) -> Self {
+        self.other.push(element);
+        self
+    }
+
+    pub fn build(self) -> Element {
+        let meta =
+            |attrs: AttrsBuilder| ElementBuilder::new(Tag::Meta).attrs(attrs.build()).build();
+        let link = |rel: &str, href: String| {
+            ElementBuilder::new(Tag::Link)
+                .attrs(Attrs::new().rel(rel).href(href).build())
+                .build()
+        };
+
+        let mut children = vec![];
+        if let Some(charset) = self.charset {
+            children.push(meta(Attrs::new().charset(charset)));
+        }
+        if let Some(viewport) = self.viewport {
+            children.push(meta(Attrs::new().name("viewport").content(viewport)));
+        }
+        if let Some(title) = self.title {
+            children.push(ElementBuilder::new(Tag::Title).content(title).build());
+        }
+        for (name, content) in self.meta {
+            children.push(meta(Attrs::new().name(name).content(content)));
+        }
+        if let Some(canonical) = self.canonical {
+            children.push(link("canonical", canonical));
+        }
+        for icon in self.icons {
+            children.push(link("icon", icon));
+        }
+        for stylesheet in self.stylesheets {
+            children.push(link("stylesheet", stylesheet));
+        }
+        for script in self.scripts {
+            children.push(
+                ElementBuilder::new(Tag::Script)
+                    .attrs(Attrs::new().src(script).defer().build())
+                    .build(),
+            );
+        }
+        children.extend(self.other);
+        ElementBuilder::new(Tag::Head).children(children).build()
+    }
+}
+
+fn push_unique(values: &mut Vec<String>, value: String) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_builder() {
+        let document = Document::new()
+            .lang("en")
+            .script("/app.js")
+            .stylesheet("/site.css")
+            .title("Home")
+            .canonical("https://example.com/")
+            .viewport("width=device-width, initial-scale=1")
+            .meta_charset("utf-8")
+            .body(vec![ElementBuilder::new(Tag::H1).content("Home").build()])
+            .build();
+
+        assert_eq!(
+            document.to_string(),
+            concat!(
+                "<!DOCTYPE html>",
+                r#"<html lang="en"><head>"#,
+                r#"<meta charset="utf-8">"#,
+                r#"<meta name="viewport" content="width=device-width, initial-scale=1">"#,
+                "<title>Home</title>",
+                r#"<link rel="canonical" href="https://example.com/">"#,
+                r#"<link rel="stylesheet" href="/site.css">"#,
+                r#"<script src="/app.js" defer></script>"#,
+                "</head><body><h1>Home</h1></body></html>"
+            )
+        );
+        assert_eq!(document.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_head_builder_removes_duplicates() {
+        let head = HeadBuilder::new()
+            .stylesheet("/a.css")
+            .stylesheet("/b.css")
+            .stylesheet("/a.css")
+            .script("/app.js")
+            .script("/app.js")
+            .description("First")
+            .meta("author", "Ferris")
+            .description("Second")
+            .title("Old")
+            .title("New")
+            .build();
+
+        assert_eq!(
+            head.to_string(),
+            concat!(
+                "<head><title>New</title>",
+                r#"<meta name="description" content="Second">"#,
+                r#"<meta name="author" content="Ferris">"#,
+                r#"<link rel="stylesheet" href="/a.css">"#,
+                r#"<link rel="stylesheet" href="/b.css">"#,
+                r#"<script src="/app.js" defer></script></head>"#
+            )
+        );
     }
 }
@@ -1,3 +1,7 @@
+#[cfg(feature = "csp")]
+mod csp;
 mod html;
 
-pub use html::{Doctype, Document};
+#[cfg(feature = "csp")]
+pub use csp::{Csp, CspBuilder, CspOutput, InvalidCsp};
+pub use html::{Doctype, Document, DocumentBuilder, HeadBuilder};
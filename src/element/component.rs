@@ -0,0 +1,191 @@
+use super::{Element, IntoChildren};
+
+// A reusable piece of markup, such as a navbar, a card or a table row.
+//
+// The props are the fields of the implementing struct. Give optional props
+// a `Default` so callers only set what they need with `..Default::default()`.
+// A component can be used anywhere an element child is accepted, and is
+// rendered when it is added to its parent.
+pub trait Component {
+    fn render(&self) -> Element;
+}
+
+impl<C: Component + ?Sized> Component for &C {
+    fn render(&self) -> Element {
+        (**self).render()
+    }
+}
+impl<C: Component + ?Sized> Component for Box<C> {
+    fn render(&self) -> Element {
+        (**self).render()
+    }
+}
+
+impl<C: Component> IntoChildren for C {
+    fn push_into(self, children: &mut Vec<Element>) {
+        children.push(self.render());
+    }
+}
+impl<C: Component> From<C> for Element {
+    fn from(component: C) -> Self {
+        component.render()
+    }
+}
+
+// Markup passed into a component, which the component places where it
+// chooses. Use a field for each slot, such as the header and body of a card.
+#[derive(Debug, Clone, Default)]
+pub struct Children(Vec<Element>);
+
+impl Children {
+    pub fn new(children: impl IntoChildren) -> Self {
+        let mut elements = vec![];
+        children.push_into(&mut elements);
+        Children(elements)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &Element> {
+        self.0.iter()
+    }
+}
+
+impl IntoChildren for Children {
+    fn push_into(self, children: &mut Vec<Element>) {
+        children.extend(self.0);
+    }
+}
+// Components render from a reference, so their children are copied in.
+impl IntoChildren for &Children {
+    fn push_into(self, children: &mut Vec<Element>) {
+        children.extend(self.0.iter().cloned());
+    }
+}
+impl From<Vec<Element>> for Children {
+    fn from(children: Vec<Element>) -> Self {
+        Children(children)
+    }
+}
+
+// Tuples hold children of different types, such as an element followed by
+// components.
+macro_rules! into_children_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: IntoChildren),+> IntoChildren for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn push_into(self, children: &mut Vec<Element>) {
+                let ($($name,)+) = self;
+                $($name.push_into(children);)+
+            }
+        }
+    };
+}
+into_children_tuple!(A, B);
+into_children_tuple!(A, B, C);
+into_children_tuple!(A, B, C, D);
+into_children_tuple!(A, B, C, D, E);
+into_children_tuple!(A, B, C, D, E, F);
+into_children_tuple!(A, B, C, D, E, F, G);
+into_children_tuple!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Default)]
+    struct Card {
+        title: String,
+        highlighted: bool,
+        body: Children,
+        footer: Children,
+    }
+    impl Component for Card {
+        fn render(&self) -> Element {
+            let class = match self.highlighted {
+                true => vec!["card", "highlighted"],
+                false => vec!["card"],
+            };
+            let title = ElementBuilder::new(Tag::H2)
+                .content(self.title.as_str())
+                .build();
+            let footer = (!self.footer.is_empty()).then(|| {
+                ElementBuilder::new(Tag::Footer)
+                    .children(&self.footer)
+                    .build()
+            });
+            ElementBuilder::new(Tag::Div)
+                .attrs(Attrs::new().class(class).build())
+                .children((title, &self.body, footer))
+                .build()
+        }
+    }
+
+    struct Badge(&'static str);
+    impl Component for Badge {
+        fn render(&self) -> Element {
+            ElementBuilder::new(Tag::Span).content(self.0).build()
+        }
+    }
+
+    #[test]
+    fn test_component_with_default_props_and_slots() {
+        let card = Card {
+            title: "Plan".to_string(),
+            body: Children::new(ElementBuilder::new(Tag::P).content("Monthly").build()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            card.render().to_string(),
+            r#"<div class="card"><h2>Plan</h2><p>Monthly</p></div>"#
+        );
+
+        let card = Card {
+            title: "Plan".to_string(),
+            highlighted: true,
+            footer: Children::new((Badge("New"), " from $5")),
+            ..Default::default()
+        };
+        assert_eq!(
+            card.render().to_string(),
+            concat!(
+                r#"<div class="card highlighted"><h2>Plan</h2>"#,
+                "<footer><span>New</span> from $5</footer></div>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_children_mix_elements_and_components() {
+        let hr = ElementBuilder::new(Tag::Hr).build();
+        let list = ElementBuilder::new(Tag::Div)
+            .children((Badge("a"), hr, vec![Badge("b"), Badge("c")]))
+            .build();
+        assert_eq!(
+            list.to_string(),
+            "<div><span>a</span><hr><span>b</span><span>c</span></div>"
+        );
+
+        let boxed: Vec<Box<dyn Component>> = vec![
+            Box::new(Badge("x")),
+            Box::new(Card {
+                title: "y".to_string(),
+                ..Default::default()
+            }),
+        ];
+        let div = ElementBuilder::new(Tag::Div).children(boxed).build();
+        assert_eq!(
+            div.to_string(),
+            r#"<div><span>x</span><div class="card"><h2>y</h2></div></div>"#
+        );
+
+        let badge = Badge("z");
+        let element: Element = (&badge).into();
+        assert_eq!(
+            crate::html! { p { {&badge} " " {element} } }.to_string(),
+            "<p><span>z</span> <span>z</span></p>"
+        );
+    }
+}
@@ -1,7 +1,10 @@
-use std::fmt::Display;
+use std::fmt::{Display, Write};
 
 use crate::attribute::Attrs;
-use crate::tag::Tag;
+use crate::escape::{Context, Raw};
+use crate::render::{Config, Printer, Render, RenderError};
+use crate::tag::{CustomElements, Tag};
+use crate::validate::{lint_element, validate_element, A11yIssue, ValidationError};
 
 #[derive(Debug, Clone)]
 pub struct ElementBuilder {
@@ -29,7 +32,8 @@ impl ElementBuilder {
         self
     }
 
-    // Text within a tag
+    // The text of a comment or doctype. Other tags have no text within
+    // their opening tag, so it is left out when rendering.
     pub fn text(mut self, text: impl Into<String>) -> Self {
         self.text = Some(text.into());
         self
@@ -41,9 +45,18 @@ impl ElementBuilder {
         self
     }
 
-    // Nested tag within a tag
-    pub fn children(mut self, children: Vec<Element>) -> Self {
-        self.children = Some(children);
+    // Nested tags within a tag, after those already added. Takes elements,
+    // components, text, or a tuple mixing them.
+    pub fn children(mut self, children: impl IntoChildren) -> Self {
+        children.push_into(self.children.get_or_insert_with(Vec::new));
+        self
+    }
+
+    // Trusted markup appended after the children, written without escaping.
+    pub fn raw(mut self, markup: Raw) -> Self {
+        self.children
+            .get_or_insert_with(Vec::new)
+            .push(markup.into());
         self
     }
 
@@ -69,8 +82,91 @@ pub struct Element {
 }
 impl Display for Element {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let fmt_str = self.make_tag();
-        write!(f, "{fmt_str}")
+        match f.alternate() {
+            true => self
+                .render_with(&Config::pretty(), f)
+                .map_err(|_| std::fmt::Error),
+            false => self.render_to(f),
+        }
+    }
+}
+
+impl Render for Element {
+    fn render_to(&self, w: &mut impl Write) -> std::fmt::Result {
+        Printer::new(&Config::default(), w).element(self, 0)
+    }
+
+    fn render_with(&self, config: &Config, w: &mut impl Write) -> Result<(), RenderError> {
+        if config.strict {
+            self.validate().map_err(RenderError::Invalid)?;
+        }
+        Ok(Printer::new(config, w).element(self, 0)?)
+    }
+}
+
+// Values that can be placed within an element by the `html!` macro.
+// Strings and numbers become escaped text.
+pub trait IntoChildren {
+    fn push_into(self, children: &mut Vec<Element>);
+}
+
+impl IntoChildren for Element {
+    fn push_into(self, children: &mut Vec<Element>) {
+        children.push(self);
+    }
+}
+impl IntoChildren for Raw {
+    fn push_into(self, children: &mut Vec<Element>) {
+        children.push(self.into());
+    }
+}
+impl IntoChildren for String {
+    fn push_into(self, children: &mut Vec<Element>) {
+        children.push(ElementBuilder::new(Tag::Text).content(self).build());
+    }
+}
+impl IntoChildren for &str {
+    fn push_into(self, children: &mut Vec<Element>) {
+        self.to_string().push_into(children);
+    }
+}
+impl IntoChildren for &String {
+    fn push_into(self, children: &mut Vec<Element>) {
+        self.as_str().push_into(children);
+    }
+}
+impl<T: IntoChildren> IntoChildren for Vec<T> {
+    fn push_into(self, children: &mut Vec<Element>) {
+        for child in self {
+            child.push_into(children);
+        }
+    }
+}
+impl<T: IntoChildren> IntoChildren for Option<T> {
+    fn push_into(self, children: &mut Vec<Element>) {
+        if let Some(child) = self {
+            child.push_into(children);
+        }
+    }
+}
+macro_rules! into_children_display {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoChildren for $ty {
+                fn push_into(self, children: &mut Vec<Element>) {
+                    self.to_string().push_into(children);
+                }
+            }
+        )*
+    };
+}
+into_children_display!(
+    bool, char, f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl From<Raw> for Element {
+    fn from(markup: Raw) -> Self {
+        ElementBuilder::new(Tag::Raw).content(markup).build()
     }
 }
 
@@ -92,37 +188,75 @@ impl Element {
     }
     // Create a HTML closing tag.
     pub fn close_tag(tag: &Tag) -> String {
-        match tag {
+        match Element::is_void(tag) {
             // Void tags are self closing.
-            Tag::Doctype | Tag::Meta | Tag::Comment | Tag::Br | Tag::Img => "".to_string(),
+            true => "".to_string(),
             // All other tags have a corresponding closing tag
-            _ => format!("</{}>", tag),
+            false => format!("</{}>", tag),
         }
     }
+    // Void tags have no content and no closing tag.
+    pub fn is_void(tag: &Tag) -> bool {
+        matches!(
+            tag,
+            Tag::Doctype
+                | Tag::Meta
+                | Tag::Comment
+                | Tag::Area
+                | Tag::Base
+                | Tag::Br
+                | Tag::Col
+                | Tag::Embed
+                | Tag::Hr
+                | Tag::Img
+                | Tag::Input
+                | Tag::Link
+                | Tag::Source
+                | Tag::Track
+                | Tag::Wbr
+                // Not elements, but written without a closing tag.
+                | Tag::Raw
+                | Tag::Text
+        )
+    }
     pub fn make_tag(&self) -> String {
-        let content = self
-            .content
-            .as_ref()
-            .map_or(String::new(), |content| content.to_string());
-
-        let children: String = self.children.as_ref().map_or(String::new(), |children| {
-            children.iter().map(|child| child.to_string()).collect()
-        });
-
-        let attributes =
-            if let Some(attrs) = self.attrs.as_ref() { attrs.get_attrs(&self.tag) } else { vec![] };
-
-        let open_tag = if !attributes.is_empty() {
-            Element::open_tag(&self.tag, attributes.join(" "))
-        } else if self.text.is_some() {
-            Element::open_tag(&self.tag, self.text.as_ref().unwrap())
+        let mut html = String::new();
+        // Writing to a String cannot fail.
+        let _ = self.render_to(&mut html);
+        html
+    }
+    // Check the element and its children, returning every problem found.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        self.validate_with(&CustomElements::default())
+    }
+    // Like `validate`, checking custom elements without a definition
+    // against the one they were registered with.
+    pub fn validate_with(
+        &self,
+        custom_elements: &CustomElements,
+    ) -> Result<(), Vec<ValidationError>> {
+        let errors = validate_element(self, custom_elements);
+        if errors.is_empty() {
+            Ok(())
         } else {
-            Element::open_tag(&self.tag, "")
-        };
-
-        let close_tag = Element::close_tag(&self.tag);
-
-        format!(r#"{open_tag}{content}{children}{close_tag}"#)
+            Err(errors)
+        }
+    }
+    // Check the element and its children for accessibility problems.
+    pub fn lint_a11y(&self) -> Result<(), Vec<A11yIssue>> {
+        let issues = lint_element(self);
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+    // The escaping context for content within a tag.
+    pub fn content_context(tag: &Tag) -> Context {
+        match tag {
+            Tag::Script | Tag::Style => Context::RawText,
+            _ => Context::Text,
+        }
     }
 }
 
@@ -162,6 +296,25 @@ mod tests {
         assert_eq!(img.to_string(), r#"<img id="test-img">"#);
     }
 
+    #[test]
+    fn test_void_tags_have_no_closing_tag() {
+        for tag in [
+            Tag::Area,
+            Tag::Base,
+            Tag::Col,
+            Tag::Embed,
+            Tag::Hr,
+            Tag::Source,
+            Tag::Track,
+            Tag::Wbr,
+        ] {
+            assert!(Element::is_void(&tag), "{tag}");
+            assert_eq!(Element::close_tag(&tag), "");
+        }
+        assert_eq!(Element::close_tag(&Tag::Section), "</section>");
+        assert_eq!(Element::close_tag(&Tag::Textarea), "</textarea>");
+    }
+
     #[test]
     fn test_nested_elements() {
         let child = ElementBuilder::new(Tag::Span).content("child").build();
@@ -197,6 +350,72 @@ mod tests {
         assert_eq!(element.to_string(), "<p>Hello, world!</p>");
     }
 
+    #[test]
+    fn test_content_is_escaped() {
+        let element = ElementBuilder::new(Tag::P)
+            .content("<script>alert('x')</script> & more")
+            .build();
+
+        assert_eq!(
+            element.to_string(),
+            "<p>&lt;script&gt;alert('x')&lt;/script&gt; &amp; more</p>"
+        );
+    }
+
+    #[test]
+    fn test_attribute_values_are_escaped() {
+        let img = ElementBuilder::new(Tag::Img)
+            .attrs(Attrs::new().alt(r#"" onerror="alert(1)"#).build())
+            .build();
+        assert_eq!(
+            img.to_string(),
+            r#"<img alt="&quot; onerror=&quot;alert(1)">"#
+        );
+
+        let a = ElementBuilder::new(Tag::A)
+            .attrs(Attrs::new().href("javascript:alert(1)").build())
+            .content("link")
+            .build();
+        assert_eq!(a.to_string(), r#"<a href="about:invalid#blocked">link</a>"#);
+    }
+
+    #[test]
+    fn test_script_content_is_raw_text() {
+        let script = ElementBuilder::new(Tag::Script)
+            .content("if (a < b) { x = '</script>'; }")
+            .build();
+
+        assert_eq!(
+            script.to_string(),
+            r"<script>if (a < b) { x = '<\/script>'; }</script>"
+        );
+    }
+
+    #[test]
+    fn test_raw_markup() {
+        let div = ElementBuilder::new(Tag::Div)
+            .content("a < b")
+            .raw(Raw::new("<b>trusted</b>"))
+            .build();
+
+        assert_eq!(div.to_string(), "<div>a &lt; b<b>trusted</b></div>");
+
+        // Raw markup and children keep the order they were added in.
+        let p = ElementBuilder::new(Tag::P)
+            .raw(Raw::new("<br>"))
+            .children(("a", ElementBuilder::new(Tag::B).content("b").build()))
+            .children("c")
+            .build();
+        assert_eq!(p.to_string(), "<p><br>a<b>b</b>c</p>");
+    }
+
+    #[test]
+    fn test_comment_is_escaped() {
+        let comment = ElementBuilder::new(Tag::Comment).text("x --> y").build();
+
+        assert_eq!(comment.to_string(), "<!-- x - -> y -->");
+    }
+
     #[test]
     fn test_complex_nesting() {
         let inner_span = ElementBuilder::new(Tag::Span).content("inner text").build();
@@ -208,7 +427,7 @@ mod tests {
 
         assert_eq!(
             div.to_string(),
-            r#"<div class="test-class" id="outer"><span>inner text</span></div>"#
+            r#"<div id="outer" class="test-class"><span>inner text</span></div>"#
         );
     }
 }
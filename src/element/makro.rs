@@ -1,6 +1,6 @@
 use crate::attribute::Attrs;
 use crate::element::{Element, ElementBuilder};
-use crate::tag::Tag;
+use crate::tag::{MathTag, SvgTag, Tag};
 
 #[macro_export]
 macro_rules! html_element {
@@ -25,15 +25,44 @@ macro_rules! html_element {
 
 html_element! {
     A => Tag::A,
+    Abbr => Tag::Abbr,
+    Address => Tag::Address,
+    Area => Tag::Area,
+    Article => Tag::Article,
+    Aside => Tag::Aside,
+    Audio => Tag::Audio,
     B => Tag::B,
+    Base => Tag::Base,
+    Bdi => Tag::Bdi,
+    Bdo => Tag::Bdo,
+    Blockquote => Tag::Blockquote,
     Body => Tag::Body,
     Br => Tag::Br,
     Button => Tag::Button,
+    Canvas => Tag::Canvas,
+    Caption => Tag::Caption,
+    Cite => Tag::Cite,
     Code => Tag::Code,
     Comment => Tag::Comment,
+    Col => Tag::Col,
+    Colgroup => Tag::Colgroup,
+    Data => Tag::Data,
+    Datalist => Tag::Datalist,
+    Dd => Tag::Dd,
+    Del => Tag::Del,
+    Details => Tag::Details,
+    Dfn => Tag::Dfn,
+    Dialog => Tag::Dialog,
     Div => Tag::Div,
+    Dl => Tag::Dl,
+    Dt => Tag::Dt,
     Em => Tag::Em,
+    Embed => Tag::Embed,
+    Fieldset => Tag::Fieldset,
+    Figcaption => Tag::Figcaption,
+    Figure => Tag::Figure,
     Footer => Tag::Footer,
+    Form => Tag::Form,
     H1 => Tag::H1,
     H2 => Tag::H2,
     H3 => Tag::H3,
@@ -42,31 +71,356 @@ html_element! {
     H6 => Tag::H6,
     Head => Tag::Head,
     Header => Tag::Header,
+    Hgroup => Tag::Hgroup,
+    Hr => Tag::Hr,
     Html => Tag::Html,
     I => Tag::I,
+    Iframe => Tag::Iframe,
     Input => Tag::Input,
     Img => Tag::Img,
+    Ins => Tag::Ins,
     Kbd => Tag::Kbd,
     Label => Tag::Label,
+    Legend => Tag::Legend,
     Li => Tag::Li,
     Link => Tag::Link,
+    Main => Tag::Main,
+    Map => Tag::Map,
+    Mark => Tag::Mark,
+    Math => Tag::Math(MathTag::Math),
+    Menu => Tag::Menu,
     Meta => Tag::Meta,
+    Meter => Tag::Meter,
     Nav => Tag::Nav,
+    Noscript => Tag::Noscript,
+    Object => Tag::Object,
     Ol => Tag::Ol,
+    Optgroup => Tag::Optgroup,
+    // No `Option` struct, as it would shadow `std::option::Option` in glob
+    // imports. Use `ElementBuilder::new(Tag::Option)` instead.
+    Output => Tag::Output,
     P => Tag::P,
+    Picture => Tag::Picture,
     Pre => Tag::Pre,
+    Progress => Tag::Progress,
     Q => Tag::Q,
+    Rp => Tag::Rp,
+    Rt => Tag::Rt,
+    Ruby => Tag::Ruby,
+    S => Tag::S,
+    Samp => Tag::Samp,
     Script => Tag::Script,
+    Search => Tag::Search,
+    Section => Tag::Section,
+    Select => Tag::Select,
+    Slot => Tag::Slot,
+    Small => Tag::Small,
+    Source => Tag::Source,
     Span => Tag::Span,
     Strong => Tag::Strong,
+    Style => Tag::Style,
     Sub => Tag::Sub,
+    Summary => Tag::Summary,
     Sup => Tag::Sup,
+    Svg => Tag::Svg(SvgTag::Svg),
     Table => Tag::Table,
+    Template => Tag::Template,
+    Textarea => Tag::Textarea,
+    Tfoot => Tag::Tfoot,
     Thead => Tag::Thead,
     Tbody => Tag::Tbody,
     Th => Tag::Th,
+    Time => Tag::Time,
     Tr => Tag::Tr,
     Td => Tag::Td,
     Title => Tag::Title,
-    Ul => Tag::Ul
+    Track => Tag::Track,
+    U => Tag::U,
+    Ul => Tag::Ul,
+    Var => Tag::Var,
+    Video => Tag::Video,
+    Wbr => Tag::Wbr
+}
+
+// Build an `Element` from HTML-like markup, such as
+// `div.card #main(data-user = "1") { h1 { "Hello, " {name} } br; }`.
+//
+// Tags are resolved against `Tag` and attributes against the `AttrsBuilder`
+// methods, so unknown names fail to compile. Text is escaped, and `{expr}`
+// inserts anything that implements `IntoChildren`, such as a `Component`.
+// `@if`, `@if let`, `@else` and `@for` work as in Rust. Rust reserves `name#`
+// prefixes, so an `#id` after a class or tag name needs a space before it.
+#[macro_export]
+macro_rules! html {
+    ($tag:ident $($rest:tt)*) => {
+        $crate::__html_element!(@el [root] $tag [] [] $($rest)*)
+    };
+}
+
+// Push each node in the markup onto the `$v` vector.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __html_nodes {
+    ($v:ident;) => {};
+    ($v:ident; $text:literal $($rest:tt)*) => {
+        $crate::element::IntoChildren::push_into($text, &mut $v);
+        $crate::__html_nodes!($v; $($rest)*);
+    };
+    ($v:ident; { $($expr:tt)* } $($rest:tt)*) => {
+        $crate::element::IntoChildren::push_into({ $($expr)* }, &mut $v);
+        $crate::__html_nodes!($v; $($rest)*);
+    };
+    ($v:ident; @if $($rest:tt)*) => {
+        $crate::__html_if!(@cond $v [if] [] $($rest)*);
+    };
+    ($v:ident; @for $pat:pat in $($rest:tt)*) => {
+        $crate::__html_for!($v [$pat] [] $($rest)*);
+    };
+    ($v:ident; $tag:ident $($rest:tt)*) => {
+        $crate::__html_element!(@el [push $v] $tag [] [] $($rest)*);
+    };
+}
+
+// Collect an `@if` / `@else if` / `@else` chain into a Rust `if` expression.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __html_if {
+    (@cond $v:ident [$($out:tt)*] [$($cond:tt)*] { $($body:tt)* } $($rest:tt)*) => {
+        $crate::__html_if!(@after $v [$($out)* $($cond)* { $crate::__html_nodes!($v; $($body)*); }] $($rest)*)
+    };
+    (@cond $v:ident [$($out:tt)*] [$($cond:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__html_if!(@cond $v [$($out)*] [$($cond)* $next] $($rest)*)
+    };
+    (@after $v:ident [$($out:tt)*] @else if $($rest:tt)*) => {
+        $crate::__html_if!(@cond $v [$($out)* else if] [] $($rest)*)
+    };
+    (@after $v:ident [$($out:tt)*] @else { $($body:tt)* } $($rest:tt)*) => {
+        $($out)* else { $crate::__html_nodes!($v; $($body)*); }
+        $crate::__html_nodes!($v; $($rest)*);
+    };
+    (@after $v:ident [$($out:tt)*] $($rest:tt)*) => {
+        $($out)*
+        $crate::__html_nodes!($v; $($rest)*);
+    };
+}
+
+// Collect the iterator of a `@for` loop up to its body.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __html_for {
+    ($v:ident [$pat:pat] [$($iter:tt)*] { $($body:tt)* } $($rest:tt)*) => {
+        for $pat in $($iter)* {
+            $crate::__html_nodes!($v; $($body)*);
+        }
+        $crate::__html_nodes!($v; $($rest)*);
+    };
+    ($v:ident [$pat:pat] [$($iter:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__html_for!($v [$pat] [$($iter)* $next] $($rest)*)
+    };
+}
+
+// Parse an element: its `.class` and `#id` shorthand, `(attributes)` and
+// `{ children }` or `;` for an element without children.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __html_element {
+    // .class shorthand, with hyphenated names such as `.btn-primary`
+    (@el $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] . $c:literal $($rest:tt)*) => {
+        $crate::__html_element!(@el $s $t [$($cls)* $c,] [$($calls)*] $($rest)*)
+    };
+    (@el $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] . $c:ident $($rest:tt)*) => {
+        $crate::__html_element!(@class $s $t [$($cls)*] [$($calls)*] [stringify!($c)] $($rest)*)
+    };
+    (@class $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($name:tt)*] - $part:tt $($rest:tt)*) => {
+        $crate::__html_element!(@class $s $t [$($cls)*] [$($calls)*] [$($name)*, "-", stringify!($part)] $($rest)*)
+    };
+    (@class $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($name:tt)*] $($rest:tt)*) => {
+        $crate::__html_element!(@el $s $t [$($cls)* concat!($($name)*),] [$($calls)*] $($rest)*)
+    };
+    // #id shorthand
+    (@el $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] # $i:literal $($rest:tt)*) => {
+        $crate::__html_element!(@el $s $t [$($cls)*] [$($calls)* .id($i)] $($rest)*)
+    };
+    (@el $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] # $i:ident $($rest:tt)*) => {
+        $crate::__html_element!(@id $s $t [$($cls)*] [$($calls)*] [stringify!($i)] $($rest)*)
+    };
+    (@id $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($name:tt)*] - $part:tt $($rest:tt)*) => {
+        $crate::__html_element!(@id $s $t [$($cls)*] [$($calls)*] [$($name)*, "-", stringify!($part)] $($rest)*)
+    };
+    (@id $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($name:tt)*] $($rest:tt)*) => {
+        $crate::__html_element!(@el $s $t [$($cls)*] [$($calls)* .id(concat!($($name)*))] $($rest)*)
+    };
+    // (attributes)
+    (@el $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] ( $($attrs:tt)* ) $($rest:tt)*) => {
+        $crate::__html_element!(@attr $s $t [$($cls)*] [$($calls)*] [$($rest)*] $($attrs)*)
+    };
+    (@attr $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($rest:tt)*]) => {
+        $crate::__html_element!(@el $s $t [$($cls)*] [$($calls)*] $($rest)*)
+    };
+    // Keyword and hyphenated names map onto their builder method.
+    (@attr $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($rest:tt)*] type = $v:expr $(, $($more:tt)*)?) => {
+        $crate::__html_element!(@attr $s $t [$($cls)*] [$($calls)* .typ($v)] [$($rest)*] $($($more)*)?)
+    };
+    (@attr $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($rest:tt)*] as = $v:expr $(, $($more:tt)*)?) => {
+        $crate::__html_element!(@attr $s $t [$($cls)*] [$($calls)* .az($v)] [$($rest)*] $($($more)*)?)
+    };
+    (@attr $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($rest:tt)*] for = $v:expr $(, $($more:tt)*)?) => {
+        $crate::__html_element!(@attr $s $t [$($cls)*] [$($calls)* .html_for($v)] [$($rest)*] $($($more)*)?)
+    };
+    (@attr $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($rest:tt)*] http - equiv = $v:expr $(, $($more:tt)*)?) => {
+        $crate::__html_element!(@attr $s $t [$($cls)*] [$($calls)* .http_equiv($v)] [$($rest)*] $($($more)*)?)
+    };
+    (@attr $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($rest:tt)*] data - $($key:ident)-+ = $v:expr $(, $($more:tt)*)?) => {
+        $crate::__html_element!(@attr $s $t [$($cls)*] [$($calls)* .data([$(stringify!($key)),+].join("-"), $v)] [$($rest)*] $($($more)*)?)
+    };
+    (@attr $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($rest:tt)*] class = $v:expr $(, $($more:tt)*)?) => {
+        $crate::__html_element!(@attr $s $t [$($cls)*] [$($calls)* .class(::std::vec::Vec::from($v))] [$($rest)*] $($($more)*)?)
+    };
+    (@attr $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($rest:tt)*] $name:ident = $v:expr $(, $($more:tt)*)?) => {
+        $crate::__html_element!(@attr $s $t [$($cls)*] [$($calls)* .$name($v)] [$($rest)*] $($($more)*)?)
+    };
+    // Boolean attributes such as `defer`.
+    (@attr $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] [$($rest:tt)*] $name:ident $(, $($more:tt)*)?) => {
+        $crate::__html_element!(@attr $s $t [$($cls)*] [$($calls)* .$name()] [$($rest)*] $($($more)*)?)
+    };
+    // { children }
+    (@el $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] { $($body:tt)* } $($rest:tt)*) => {
+        $crate::__html_element!(@done $s {
+            #[allow(unused_mut)]
+            let mut __children: ::std::vec::Vec<$crate::element::Element> = ::std::vec::Vec::new();
+            $crate::__html_nodes!(__children; $($body)*);
+            let mut __element = $crate::element::ElementBuilder::new($crate::tag::names::$t);
+            __element.attrs = $crate::__html_attrs!([$($cls)*] $($calls)*);
+            if !__children.is_empty() {
+                __element = __element.children(__children);
+            }
+            __element.build()
+        } $($rest)*)
+    };
+    // ; for an element without children
+    (@el $s:tt $t:ident [$($cls:tt)*] [$($calls:tt)*] ; $($rest:tt)*) => {
+        $crate::__html_element!(@done $s {
+            let mut __element = $crate::element::ElementBuilder::new($crate::tag::names::$t);
+            __element.attrs = $crate::__html_attrs!([$($cls)*] $($calls)*);
+            __element.build()
+        } $($rest)*)
+    };
+    (@done [push $v:ident] { $($element:tt)* } $($rest:tt)*) => {
+        $v.push({ $($element)* });
+        $crate::__html_nodes!($v; $($rest)*);
+    };
+    (@done [root] { $($element:tt)* }) => {{ $($element)* }};
+    (@done [root] { $($element:tt)* } $($rest:tt)+) => {
+        compile_error!("`html!` expects a single root element")
+    };
+}
+
+// Build the `Attrs` of an element, if it has any.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __html_attrs {
+    ([]) => {
+        ::std::option::Option::None
+    };
+    ([] $($calls:tt)+) => {
+        ::std::option::Option::Some($crate::attribute::Attrs::new() $($calls)+ .build())
+    };
+    ([$($cls:tt)+] $($calls:tt)*) => {
+        ::std::option::Option::Some(
+            $crate::attribute::Attrs::new().class(::std::vec![$($cls)+]) $($calls)* .build()
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_html_macro_matches_builders() {
+        let built = ElementBuilder::new(Tag::Div)
+            .attrs(Attrs::new().id("main").class(vec!["card"]).build())
+            .children(vec![
+                ElementBuilder::new(Tag::H1)
+                    .children(vec![ElementBuilder::new(Tag::Text)
+                        .content("Title")
+                        .build()])
+                    .build(),
+                ElementBuilder::new(Tag::Br).build(),
+            ])
+            .build();
+        let markup = html! {
+            div(id = "main", class = ["card"]) {
+                h1 { "Title" }
+                br;
+            }
+        };
+
+        assert_eq!(markup.to_string(), built.to_string());
+    }
+
+    #[test]
+    fn test_html_macro_shorthand_and_attributes() {
+        let id = "intro";
+        let element = html! {
+            p.lead.btn-primary.col-6#page-top(data-user-id = 7.to_string(), lang = "en") {
+                script(src = "/app.js", type = "module", defer);
+                meta(http-equiv = "refresh", content = "5");
+                span(id = id) { "x" }
+            }
+        };
+
+        assert_eq!(
+            element.to_string(),
+            r#"<p class="lead btn-primary col-6" id="page-top" data-user-id="7" lang="en"><script src="/app.js" type="module" defer></script><meta http-equiv="refresh" content="5"><span id="intro">x</span></p>"#
+        );
+    }
+
+    #[test]
+    fn test_html_macro_interpolation_is_escaped() {
+        let name = "<b>Ferris</b>".to_string();
+        let count = 3;
+        let element = html! {
+            p { "Hi " {&name} ", you have " {count} " messages." {Raw::new("<hr>")} }
+        };
+
+        assert_eq!(
+            element.to_string(),
+            "<p>Hi &lt;b&gt;Ferris&lt;/b&gt;, you have 3 messages.<hr></p>"
+        );
+    }
+
+    #[test]
+    fn test_html_macro_control_flow() {
+        let list = |items: &[&str], admin: bool, role: Option<&str>| {
+            html! {
+                ul {
+                    @for (i, item) in items.iter().enumerate() {
+                        li { {i} ": " {*item} }
+                    }
+                    @if items.is_empty() {
+                        li { "empty" }
+                    } @else if admin {
+                        li.admin { "admin" }
+                    } @else {
+                        li { "user" }
+                    }
+                    @if let Some(role) = role { li { {role} } }
+                }
+            }
+        };
+
+        assert_eq!(
+            list(&["a", "b"], false, None).to_string(),
+            "<ul><li>0: a</li><li>1: b</li><li>user</li></ul>"
+        );
+        assert_eq!(
+            list(&[], true, Some("x")).to_string(),
+            "<ul><li>empty</li><li>x</li></ul>"
+        );
+        assert_eq!(
+            list(&["a"], true, None).to_string(),
+            r#"<ul><li>0: a</li><li class="admin">admin</li></ul>"#
+        );
+    }
 }
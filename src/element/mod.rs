@@ -1,5 +1,11 @@
+mod component;
 mod html;
 mod makro;
+mod query;
+mod selector;
 
-pub use html::{Element, ElementBuilder};
+pub use component::{Children, Component};
+pub use html::{Element, ElementBuilder, IntoChildren};
 pub use makro::*;
+pub use query::{Descendants, Visit};
+pub use selector::{Selector, SelectorError};
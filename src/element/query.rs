@@ -0,0 +1,405 @@
+use std::slice;
+
+use super::{Element, Selector, SelectorError};
+use crate::attribute::Attrs;
+use crate::document::Document;
+use crate::tag::Tag;
+
+// What to do after visiting an element in `walk`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Visit {
+    // Go on to the children of the element.
+    Continue,
+    // Go on to the next sibling, without visiting the children.
+    Skip,
+    // Stop walking the tree.
+    Stop,
+}
+
+// The descendants of an element or document, depth first in document order.
+pub struct Descendants<'a> {
+    stack: Vec<slice::Iter<'a, Element>>,
+}
+
+impl<'a> Descendants<'a> {
+    fn new(nodes: &'a [Element]) -> Self {
+        Descendants {
+            stack: vec![nodes.iter()],
+        }
+    }
+}
+impl<'a> Iterator for Descendants<'a> {
+    type Item = &'a Element;
+
+    fn next(&mut self) -> Option<&'a Element> {
+        loop {
+            let nodes = self.stack.last_mut()?;
+            match nodes.next() {
+                Some(node) => {
+                    if let Some(children) = &node.children {
+                        self.stack.push(children.iter());
+                    }
+                    return Some(node);
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+impl Element {
+    // Visit the element and its descendants, depth first.
+    pub fn walk(&self, mut visit: impl FnMut(&Element) -> Visit) {
+        walk(self, &mut visit);
+    }
+
+    // Visit the element and its descendants depth first, changing them in place.
+    pub fn walk_mut(&mut self, mut visit: impl FnMut(&mut Element) -> Visit) {
+        walk_mut(self, &mut visit);
+    }
+
+    pub fn descendants(&self) -> Descendants<'_> {
+        Descendants::new(self.children.as_deref().unwrap_or_default())
+    }
+
+    pub fn find_by_tag<'a>(&'a self, tag: &'a Tag) -> impl Iterator<Item = &'a Element> {
+        self.descendants()
+            .filter(move |element| element.tag == *tag)
+    }
+
+    pub fn find_by_id(&self, id: &str) -> Option<&Element> {
+        self.descendants()
+            .find(|element| element.attr("id") == Some(id))
+    }
+
+    pub fn find_by_class<'a>(&'a self, class: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.descendants().filter(move |element| {
+            element
+                .attrs
+                .as_ref()
+                .is_some_and(|attrs| attrs.has_class(class))
+        })
+    }
+
+    // Descendants with the attribute set, whatever its value.
+    pub fn find_by_attr<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.descendants()
+            .filter(move |element| element.attr(name).is_some())
+    }
+
+    // The descendants matching a CSS selector, such as `div.card > a[href]`.
+    pub fn select(&self, selector: &str) -> Result<Vec<&Element>, SelectorError> {
+        Ok(self.query(&Selector::parse(selector)?))
+    }
+
+    // The descendants matching the selector, in document order.
+    pub fn query(&self, selector: &Selector) -> Vec<&Element> {
+        let mut found = vec![];
+        query(
+            self.children.as_deref().unwrap_or_default(),
+            selector,
+            &mut vec![self],
+            &mut found,
+        );
+        found
+    }
+
+    // Change every descendant matching the selector, returning how many
+    // were changed. Descendants are changed before their ancestors.
+    pub fn update(&mut self, selector: &Selector, mut change: impl FnMut(&mut Element)) -> usize {
+        let paths = self.paths(selector);
+        for path in paths.iter().rev() {
+            change(node_mut(self.children.as_mut().unwrap(), path));
+        }
+        paths.len()
+    }
+
+    // Remove every descendant matching the selector, returning how many
+    // were removed.
+    pub fn remove(&mut self, selector: &Selector) -> usize {
+        let paths = self.paths(selector);
+        for path in paths.iter().rev() {
+            remove_node(self.children.as_mut().unwrap(), path);
+        }
+        paths.len()
+    }
+
+    // Keep only the descendants for which `keep` returns true. The children
+    // of a removed element are removed with it.
+    pub fn retain(&mut self, mut keep: impl FnMut(&Element) -> bool) {
+        if let Some(children) = &mut self.children {
+            retain(children, &mut keep);
+        }
+    }
+
+    // The value of an attribute, which is empty for a boolean attribute.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.as_ref().and_then(|attrs| attrs.get(name))
+    }
+
+    // The attributes, which are added if the element has none.
+    pub fn attrs_mut(&mut self) -> &mut Attrs {
+        self.attrs.get_or_insert_with(Attrs::default)
+    }
+
+    pub fn add_class(&mut self, class: &str) {
+        self.attrs_mut().add_class(class);
+    }
+
+    // The path of child indexes to each matching descendant, in document order.
+    fn paths(&self, selector: &Selector) -> Vec<Vec<usize>> {
+        let mut paths = vec![];
+        let children = self.children.as_deref().unwrap_or_default();
+        find_paths(children, selector, &mut vec![self], &mut vec![], &mut paths);
+        paths
+    }
+}
+
+// The same queries for the elements of a document.
+impl Document {
+    pub fn walk(&self, mut visit: impl FnMut(&Element) -> Visit) {
+        for element in &self.elements {
+            if !walk(element, &mut visit) {
+                return;
+            }
+        }
+    }
+
+    pub fn walk_mut(&mut self, mut visit: impl FnMut(&mut Element) -> Visit) {
+        for element in &mut self.elements {
+            if !walk_mut(element, &mut visit) {
+                return;
+            }
+        }
+    }
+
+    // Every element in the document, depth first in document order.
+    pub fn descendants(&self) -> Descendants<'_> {
+        Descendants::new(&self.elements)
+    }
+
+    pub fn find_by_id(&self, id: &str) -> Option<&Element> {
+        self.descendants()
+            .find(|element| element.attr("id") == Some(id))
+    }
+
+    pub fn select(&self, selector: &str) -> Result<Vec<&Element>, SelectorError> {
+        Ok(self.query(&Selector::parse(selector)?))
+    }
+
+    pub fn query(&self, selector: &Selector) -> Vec<&Element> {
+        let mut found = vec![];
+        query(&self.elements, selector, &mut vec![], &mut found);
+        found
+    }
+
+    pub fn update(&mut self, selector: &Selector, mut change: impl FnMut(&mut Element)) -> usize {
+        let paths = self.paths(selector);
+        for path in paths.iter().rev() {
+            change(node_mut(&mut self.elements, path));
+        }
+        paths.len()
+    }
+
+    pub fn remove(&mut self, selector: &Selector) -> usize {
+        let paths = self.paths(selector);
+        for path in paths.iter().rev() {
+            remove_node(&mut self.elements, path);
+        }
+        paths.len()
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(&Element) -> bool) {
+        retain(&mut self.elements, &mut keep);
+    }
+
+    fn paths(&self, selector: &Selector) -> Vec<Vec<usize>> {
+        let mut paths = vec![];
+        find_paths(
+            &self.elements,
+            selector,
+            &mut vec![],
+            &mut vec![],
+            &mut paths,
+        );
+        paths
+    }
+}
+
+// Returns false once the walk is stopped.
+fn walk(element: &Element, visit: &mut impl FnMut(&Element) -> Visit) -> bool {
+    match visit(element) {
+        Visit::Stop => false,
+        Visit::Skip => true,
+        Visit::Continue => element
+            .children
+            .iter()
+            .flatten()
+            .all(|child| walk(child, visit)),
+    }
+}
+
+fn walk_mut(element: &mut Element, visit: &mut impl FnMut(&mut Element) -> Visit) -> bool {
+    match visit(element) {
+        Visit::Stop => false,
+        Visit::Skip => true,
+        Visit::Continue => element
+            .children
+            .iter_mut()
+            .flatten()
+            .all(|child| walk_mut(child, visit)),
+    }
+}
+
+fn query<'a>(
+    nodes: &'a [Element],
+    selector: &Selector,
+    ancestors: &mut Vec<&'a Element>,
+    found: &mut Vec<&'a Element>,
+) {
+    for node in nodes {
+        if selector.matches(node, ancestors) {
+            found.push(node);
+        }
+        if let Some(children) = &node.children {
+            ancestors.push(node);
+            query(children, selector, ancestors, found);
+            ancestors.pop();
+        }
+    }
+}
+
+fn find_paths<'a>(
+    nodes: &'a [Element],
+    selector: &Selector,
+    ancestors: &mut Vec<&'a Element>,
+    path: &mut Vec<usize>,
+    paths: &mut Vec<Vec<usize>>,
+) {
+    for (index, node) in nodes.iter().enumerate() {
+        path.push(index);
+        if selector.matches(node, ancestors) {
+            paths.push(path.clone());
+        }
+        if let Some(children) = &node.children {
+            ancestors.push(node);
+            find_paths(children, selector, ancestors, path, paths);
+            ancestors.pop();
+        }
+        path.pop();
+    }
+}
+
+// Paths are applied in reverse document order, so changing or removing a
+// node never moves a node that is still to be visited.
+fn node_mut<'a>(nodes: &'a mut [Element], path: &[usize]) -> &'a mut Element {
+    let (first, rest) = path.split_first().expect("paths are never empty");
+    let node = &mut nodes[*first];
+    match rest.is_empty() {
+        true => node,
+        false => node_mut(node.children.as_mut().unwrap(), rest),
+    }
+}
+
+fn remove_node(nodes: &mut Vec<Element>, path: &[usize]) {
+    let (last, parent) = path.split_last().expect("paths are never empty");
+    match parent.is_empty() {
+        true => {
+            nodes.remove(*last);
+        }
+        false => {
+            let parent = node_mut(nodes, parent);
+            parent.children.as_mut().unwrap().remove(*last);
+        }
+    }
+}
+
+fn retain(nodes: &mut Vec<Element>, keep: &mut impl FnMut(&Element) -> bool) {
+    nodes.retain(|node| keep(node));
+    for node in nodes {
+        if let Some(children) = &mut node.children {
+            retain(children, keep);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn page() -> Document {
+        parse(concat!(
+            "<!DOCTYPE html><html><body>",
+            r#"<nav id="top"><a href="/">Home</a><a href="/about" class="active">About</a></nav>"#,
+            r#"<main><!-- note --><p>Hi <a href="https://x.com">x</a></p><script src="/ad.js"></script></main>"#,
+            "</body></html>"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_descendants_and_lookups() {
+        let page = page();
+        let nav = page.find_by_id("top").unwrap();
+
+        let tags: Vec<String> = nav.descendants().map(|e| e.tag.to_string()).collect();
+        assert_eq!(tags, vec!["a", "a"]);
+        assert_eq!(nav.find_by_tag(&Tag::A).count(), 2);
+        assert_eq!(
+            nav.find_by_class("active").next().unwrap().attr("href"),
+            Some("/about")
+        );
+        let body = page.descendants().find(|e| e.tag == Tag::Body).unwrap();
+        assert_eq!(body.find_by_attr("href").count(), 3);
+        assert_eq!(body.find_by_attr("src").count(), 1);
+        assert!(page.find_by_id("missing").is_none());
+    }
+
+    #[test]
+    fn test_walk() {
+        let page = page();
+        let mut visited = vec![];
+        page.walk(|element| {
+            visited.push(element.tag.to_string());
+            match element.tag {
+                Tag::Nav => Visit::Skip,
+                Tag::P => Visit::Stop,
+                _ => Visit::Continue,
+            }
+        });
+        assert_eq!(visited, vec!["html", "body", "nav", "main", "!--", "p"]);
+    }
+
+    #[test]
+    fn test_update_and_remove() {
+        let mut page = page();
+        let links = Selector::parse("a[href^=http]").unwrap();
+        let changed = page.update(&links, |a| {
+            a.attrs_mut().set("rel", Some("noopener"));
+            a.add_class("external");
+        });
+        assert_eq!(changed, 1);
+
+        assert_eq!(page.remove(&Selector::parse("main > script").unwrap()), 1);
+        page.retain(|element| element.tag != Tag::Comment);
+        page.walk_mut(|element| {
+            if element.tag == Tag::Nav {
+                element.attrs_mut().remove("id");
+            }
+            Visit::Continue
+        });
+
+        assert_eq!(
+            page.to_string(),
+            concat!(
+                "<!DOCTYPE html><html><body>",
+                r#"<nav><a href="/">Home</a><a href="/about" class="active">About</a></nav>"#,
+                r#"<main><p>Hi <a href="https://x.com" rel="noopener" class="external">x</a></p></main>"#,
+                "</body></html>"
+            )
+        );
+    }
+}
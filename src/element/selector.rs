@@ -0,0 +1,409 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::Element;
+use crate::tag::Tag;
+
+// A CSS selector, such as `div.card > a[href]`, for finding elements.
+//
+// Supports type, universal, `#id`, `.class` and attribute selectors
+// (`[name]`, `=`, `~=`, `|=`, `^=`, `$=` and `*=`), the descendant and
+// child (`>`) combinators, and lists separated by commas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+    alternatives: Vec<Complex>,
+}
+
+// Compound selectors with the combinator before each, the last being the
+// element that is selected.
+type Complex = Vec<(Combinator, Compound)>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Compound {
+    tag: Option<String>,
+    ids: Vec<String>,
+    classes: Vec<String>,
+    attrs: Vec<AttrSelector>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct AttrSelector {
+    name: String,
+    value: Option<(AttrOp, String)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AttrOp {
+    Equals,
+    Includes,
+    DashMatch,
+    Prefix,
+    Suffix,
+    Contains,
+}
+
+impl Selector {
+    pub fn parse(selector: &str) -> Result<Selector, SelectorError> {
+        SelectorParser {
+            input: selector,
+            pos: 0,
+        }
+        .selector()
+    }
+
+    // Whether the element matches, given its ancestors from the root down.
+    pub(crate) fn matches(&self, element: &Element, ancestors: &[&Element]) -> bool {
+        self.alternatives
+            .iter()
+            .any(|complex| matches_complex(complex, element, ancestors))
+    }
+}
+impl FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Selector::parse(s)
+    }
+}
+
+// Match from the right, trying each ancestor for descendant combinators.
+fn matches_complex(
+    complex: &[(Combinator, Compound)],
+    element: &Element,
+    ancestors: &[&Element],
+) -> bool {
+    let Some(((combinator, compound), rest)) = complex.split_last() else {
+        return true;
+    };
+    if !compound.matches(element) {
+        return false;
+    }
+    if rest.is_empty() {
+        return true;
+    }
+    match combinator {
+        Combinator::Child => ancestors
+            .split_last()
+            .is_some_and(|(parent, above)| matches_complex(rest, parent, above)),
+        Combinator::Descendant => (0..ancestors.len())
+            .rev()
+            .any(|i| matches_complex(rest, ancestors[i], &ancestors[..i])),
+    }
+}
+
+impl Compound {
+    fn matches(&self, element: &Element) -> bool {
+        // Text, comments and raw markup are not elements to select.
+        if matches!(
+            element.tag,
+            Tag::Text | Tag::Comment | Tag::Raw | Tag::Doctype
+        ) {
+            return false;
+        }
+        if let Some(tag) = &self.tag {
+            if !element.tag.to_string().eq_ignore_ascii_case(tag) {
+                return false;
+            }
+        }
+        let attrs = element.attrs.as_ref();
+        // Attribute names are not case-sensitive, as in HTML.
+        let get = |name: &str| {
+            attrs?
+                .iter()
+                .find(|(found, _)| found.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.unwrap_or_default())
+        };
+        self.ids.iter().all(|id| get("id") == Some(id.as_str()))
+            && self
+                .classes
+                .iter()
+                .all(|class| attrs.is_some_and(|attrs| attrs.has_class(class)))
+            && self
+                .attrs
+                .iter()
+                .all(|attr| match (get(&attr.name), &attr.value) {
+                    (None, _) => false,
+                    (Some(_), None) => true,
+                    (Some(found), Some((op, value))) => op.matches(found, value),
+                })
+    }
+}
+
+impl AttrOp {
+    fn matches(&self, found: &str, value: &str) -> bool {
+        match self {
+            AttrOp::Equals => found == value,
+            AttrOp::Includes => found.split_ascii_whitespace().any(|word| word == value),
+            AttrOp::DashMatch => {
+                found == value
+                    || found
+                        .strip_prefix(value)
+                        .is_some_and(|rest| rest.starts_with('-'))
+            }
+            // An empty value never matches, as in CSS.
+            AttrOp::Prefix => !value.is_empty() && found.starts_with(value),
+            AttrOp::Suffix => !value.is_empty() && found.ends_with(value),
+            AttrOp::Contains => !value.is_empty() && found.contains(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectorError {
+    // The selector, or one of a list, is empty.
+    Empty,
+    // A character that cannot appear at this position.
+    UnexpectedChar { found: char, position: usize },
+    // The selector ends within a name, string or attribute selector.
+    UnexpectedEnd,
+}
+impl Display for SelectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SelectorError::Empty => write!(f, "empty CSS selector"),
+            SelectorError::UnexpectedChar { found, position } => {
+                write!(
+                    f,
+                    "unexpected '{found}' at position {position} in CSS selector"
+                )
+            }
+            SelectorError::UnexpectedEnd => write!(f, "unexpected end of CSS selector"),
+        }
+    }
+}
+impl std::error::Error for SelectorError {}
+
+struct SelectorParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl SelectorParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    // Skip whitespace, returning whether there was any.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.bump();
+        }
+        self.pos > start
+    }
+
+    fn unexpected(&self) -> SelectorError {
+        match self.peek() {
+            Some(found) => SelectorError::UnexpectedChar {
+                found,
+                position: self.pos,
+            },
+            None => SelectorError::UnexpectedEnd,
+        }
+    }
+
+    fn selector(mut self) -> Result<Selector, SelectorError> {
+        let mut alternatives = vec![self.complex()?];
+        while self.peek() == Some(',') {
+            self.bump();
+            alternatives.push(self.complex()?);
+        }
+        match self.peek() {
+            Some(_) => Err(self.unexpected()),
+            None => Ok(Selector { alternatives }),
+        }
+    }
+
+    fn complex(&mut self) -> Result<Complex, SelectorError> {
+        self.skip_whitespace();
+        if matches!(self.peek(), None | Some(',')) {
+            return Err(SelectorError::Empty);
+        }
+        let mut complex = vec![(Combinator::Descendant, self.compound()?)];
+        loop {
+            let space = self.skip_whitespace();
+            let combinator = match self.peek() {
+                None | Some(',') => return Ok(complex),
+                Some('>') => {
+                    self.bump();
+                    self.skip_whitespace();
+                    Combinator::Child
+                }
+                Some(_) if space => Combinator::Descendant,
+                Some(_) => return Err(self.unexpected()),
+            };
+            complex.push((combinator, self.compound()?));
+        }
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let mut compound = Compound::default();
+        let start = self.pos;
+        match self.peek() {
+            Some('*') => {
+                self.bump();
+            }
+            Some(c) if is_name_char(c) => compound.tag = Some(self.name()?),
+            _ => {}
+        }
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    self.bump();
+                    compound.ids.push(self.name()?);
+                }
+                Some('.') => {
+                    self.bump();
+                    compound.classes.push(self.name()?);
+                }
+                Some('[') => {
+                    self.bump();
+                    compound.attrs.push(self.attribute()?);
+                }
+                _ if self.pos == start => return Err(self.unexpected()),
+                _ => return Ok(compound),
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, SelectorError> {
+        let start = self.pos;
+        while self.peek().is_some_and(is_name_char) {
+            self.bump();
+        }
+        match self.pos > start {
+            true => Ok(self.input[start..self.pos].to_string()),
+            false => Err(self.unexpected()),
+        }
+    }
+
+    fn attribute(&mut self) -> Result<AttrSelector, SelectorError> {
+        self.skip_whitespace();
+        let name = self.name()?;
+        self.skip_whitespace();
+        let op = match self.peek() {
+            Some(']') => None,
+            Some('=') => Some(AttrOp::Equals),
+            Some(c) => {
+                let op = match c {
+                    '~' => AttrOp::Includes,
+                    '|' => AttrOp::DashMatch,
+                    '^' => AttrOp::Prefix,
+                    '$' => AttrOp::Suffix,
+                    '*' => AttrOp::Contains,
+                    _ => return Err(self.unexpected()),
+                };
+                self.bump();
+                if self.peek() != Some('=') {
+                    return Err(self.unexpected());
+                }
+                Some(op)
+            }
+            None => return Err(SelectorError::UnexpectedEnd),
+        };
+        let value = match op {
+            Some(op) => {
+                self.bump();
+                self.skip_whitespace();
+                let value = match self.peek() {
+                    Some(quote @ ('"' | '\'')) => {
+                        self.bump();
+                        let start = self.pos;
+                        while self.peek().is_some_and(|c| c != quote) {
+                            self.bump();
+                        }
+                        let value = self.input[start..self.pos].to_string();
+                        self.bump().ok_or(SelectorError::UnexpectedEnd)?;
+                        value
+                    }
+                    _ => self.name()?,
+                };
+                self.skip_whitespace();
+                Some((op, value))
+            }
+            None => None,
+        };
+        match self.bump() {
+            Some(']') => Ok(AttrSelector { name, value }),
+            Some(found) => Err(SelectorError::UnexpectedChar {
+                found,
+                position: self.pos - found.len_utf8(),
+            }),
+            None => Err(SelectorError::UnexpectedEnd),
+        }
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn page() -> Element {
+        parse_fragment(concat!(
+            r#"<div id="main"><div class="card featured" lang="en-GB">"#,
+            r#"<a href="/a">A</a><p><a href="https://x.com" rel="nofollow noopener">B</a></p>"#,
+            r#"</div><a>C</a></div>"#
+        ))
+        .unwrap()
+        .remove(0)
+    }
+
+    fn texts(elements: Vec<&Element>) -> Vec<&str> {
+        elements
+            .iter()
+            .map(|element| element.content.as_deref().unwrap_or_default())
+            .collect()
+    }
+
+    #[test]
+    fn test_select() {
+        let page = page();
+        let select = |selector: &str| texts(page.select(selector).unwrap());
+
+        assert_eq!(select("a"), vec!["A", "B", "C"]);
+        assert_eq!(select("div.card > a[href]"), vec!["A"]);
+        assert_eq!(select(".card a"), vec!["A", "B"]);
+        assert_eq!(select("#main > a"), vec!["C"]);
+        assert_eq!(select("A[HREF^='http']"), vec!["B"]);
+        assert_eq!(select(r#"[rel~="noopener"]"#), vec!["B"]);
+        assert_eq!(select("[lang|=en]"), vec![""]);
+        // Lists select each element once, in document order.
+        assert_eq!(select("p a, a[href='/a'], div > a"), vec!["A", "B", "C"]);
+        assert_eq!(select("span, *[href*=x]"), vec!["B"]);
+    }
+
+    #[test]
+    fn test_selector_errors() {
+        assert_eq!(Selector::parse(""), Err(SelectorError::Empty));
+        assert_eq!(Selector::parse("a,"), Err(SelectorError::Empty));
+        assert_eq!(Selector::parse("a[href"), Err(SelectorError::UnexpectedEnd));
+        assert_eq!(
+            Selector::parse("a:hover"),
+            Err(SelectorError::UnexpectedChar {
+                found: ':',
+                position: 1
+            })
+        );
+        assert_eq!(
+            "div >> a".parse::<Selector>().unwrap_err().to_string(),
+            "unexpected '>' at position 5 in CSS selector"
+        );
+    }
+}
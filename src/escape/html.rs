@@ -0,0 +1,283 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Write};
+
+// Replacement for URLs with a scheme that could execute script.
+const BLOCKED_URL: &str = "about:invalid#blocked";
+
+// The output context a value is written into.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Context {
+    // Text between an opening and closing tag.
+    Text,
+    // A double quoted attribute value.
+    Attribute,
+    // A double quoted attribute value that holds a URL (href, src).
+    Url,
+    // The content of a raw text element (script, style).
+    RawText,
+    // The text of a HTML comment.
+    Comment,
+    // The inside of a JavaScript string literal, in a script or an event
+    // handler attribute.
+    JsString,
+}
+
+// Escape a value for the given context.
+pub fn escape(value: &str, context: Context) -> Cow<'_, str> {
+    let needs_escape = match context {
+        Context::Text => value.contains(['&', '<', '>']),
+        Context::Attribute => value.contains(['&', '<', '>', '"']),
+        Context::Url => blocked_url(value) || value.contains(['&', '<', '>', '"']),
+        Context::RawText => find_raw_text_end(value).is_some(),
+        Context::Comment => value.contains("--") || value.starts_with('>'),
+        Context::JsString => value.contains(is_js_special),
+    };
+    if needs_escape {
+        Cow::Owned(Escaped(value, context).to_string())
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+// Escape text placed between an opening and closing tag.
+pub fn escape_text(value: &str) -> Cow<'_, str> {
+    escape(value, Context::Text)
+}
+
+// Escape a double quoted attribute value.
+pub fn escape_attr(value: &str) -> Cow<'_, str> {
+    escape(value, Context::Attribute)
+}
+
+// Escape a URL attribute value, replacing script URLs.
+pub fn escape_url(value: &str) -> Cow<'_, str> {
+    escape(value, Context::Url)
+}
+
+// Escape a value placed within a JavaScript string literal.
+pub fn escape_js(value: &str) -> Cow<'_, str> {
+    escape(value, Context::JsString)
+}
+
+// Writes a value escaped for a context without allocating.
+#[derive(Debug, Clone, Copy)]
+pub struct Escaped<'a>(pub &'a str, pub Context);
+
+impl Display for Escaped<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Escaped(value, context) = *self;
+        match context {
+            Context::Text => write_entities(f, value, &['&', '<', '>']),
+            Context::Attribute => write_entities(f, value, &['&', '<', '>', '"']),
+            Context::Url if blocked_url(value) => f.write_str(BLOCKED_URL),
+            Context::Url => write_entities(f, value, &['&', '<', '>', '"']),
+            Context::RawText => write_raw_text(f, value),
+            Context::Comment => write_comment(f, value),
+            Context::JsString => write_js_string(f, value),
+        }
+    }
+}
+
+// Trusted markup that is written to the output as is.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Raw(pub String);
+
+impl Raw {
+    pub fn new(markup: impl Into<String>) -> Self {
+        Raw(markup.into())
+    }
+}
+impl Display for Raw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl From<Raw> for String {
+    fn from(raw: Raw) -> Self {
+        raw.0
+    }
+}
+
+// Alias for `Raw`, for those coming from other templating crates.
+pub type PreEscaped = Raw;
+
+fn write_entities(w: &mut impl Write, value: &str, special: &[char]) -> std::fmt::Result {
+    let mut last = 0;
+    for (index, ch) in value.match_indices(special) {
+        w.write_str(&value[last..index])?;
+        w.write_str(match ch {
+            "&" => "&amp;",
+            "<" => "&lt;",
+            ">" => "&gt;",
+            _ => "&quot;",
+        })?;
+        last = index + ch.len();
+    }
+    w.write_str(&value[last..])
+}
+
+// A closing script or style tag would end a raw text element early.
+fn find_raw_text_end(value: &str) -> Option<usize> {
+    value
+        .match_indices("</")
+        .map(|(index, _)| index)
+        .find(|index| {
+            let rest = &value.as_bytes()[index + 2..];
+            [b"script".as_slice(), b"style".as_slice()]
+                .iter()
+                .any(|name| {
+                    rest.len() >= name.len() && rest[..name.len()].eq_ignore_ascii_case(name)
+                })
+        })
+}
+
+fn write_raw_text(w: &mut impl Write, mut value: &str) -> std::fmt::Result {
+    while let Some(index) = find_raw_text_end(value) {
+        w.write_str(&value[..index])?;
+        w.write_str(r"<\/")?;
+        value = &value[index + 2..];
+    }
+    w.write_str(value)
+}
+
+// A comment must not contain `--` or start with `>`.
+fn write_comment(w: &mut impl Write, value: &str) -> std::fmt::Result {
+    let value = match value.strip_prefix('>') {
+        Some(rest) => {
+            w.write_str("&gt;")?;
+            rest
+        }
+        None => value,
+    };
+    let mut previous = ' ';
+    for ch in value.chars() {
+        if previous == '-' && ch == '-' {
+            w.write_char(' ')?;
+        }
+        w.write_char(ch)?;
+        previous = ch;
+    }
+    Ok(())
+}
+
+// Quotes and backslashes would end the string, and markup characters are
+// escaped as well so the result is safe in HTML without further escaping.
+fn is_js_special(c: char) -> bool {
+    matches!(
+        c,
+        '"' | '\'' | '`' | '\\' | '<' | '>' | '&' | '\u{2028}' | '\u{2029}'
+    ) || c.is_control()
+}
+
+fn write_js_string(w: &mut impl Write, value: &str) -> std::fmt::Result {
+    for c in value.chars() {
+        match c {
+            '\\' => w.write_str(r"\\")?,
+            '\n' => w.write_str(r"\n")?,
+            '\r' => w.write_str(r"\r")?,
+            '\t' => w.write_str(r"\t")?,
+            c if is_js_special(c) => write!(w, r"\u{:04x}", c as u32)?,
+            c => w.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+// URLs with a javascript, vbscript or non image data scheme are not allowed.
+fn blocked_url(value: &str) -> bool {
+    // Browsers ignore leading whitespace and embedded tabs and newlines.
+    let scheme: String = value
+        .trim_start_matches(|c: char| c.is_ascii_whitespace() || c.is_ascii_control())
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .take_while(|c| *c != ':' && *c != '/' && *c != '?' && *c != '#')
+        .take(16)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let rest = value
+        .split_once(':')
+        .map_or("", |(_, rest)| rest)
+        .trim_start();
+    match scheme.as_str() {
+        "javascript" | "vbscript" => value.contains(':'),
+        "data" => {
+            let rest = rest.to_ascii_lowercase();
+            !["image/png", "image/gif", "image/jpeg", "image/webp"]
+                .iter()
+                .any(|mime| rest.starts_with(mime))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(
+            escape_text("a < b && c > d"),
+            "a &lt; b &amp;&amp; c &gt; d"
+        );
+        assert_eq!(escape_text(r#"say "hi""#), r#"say "hi""#);
+        assert!(matches!(escape_text("plain"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_escape_attr() {
+        assert_eq!(escape_attr(r#"" onload="x"#), "&quot; onload=&quot;x");
+        assert_eq!(escape_attr("it's <b>"), "it's &lt;b&gt;");
+    }
+
+    #[test]
+    fn test_escape_url() {
+        assert_eq!(
+            escape_url("https://example.com/?a=1&b=2"),
+            "https://example.com/?a=1&amp;b=2"
+        );
+        assert_eq!(escape_url("/relative/path"), "/relative/path");
+        assert_eq!(escape_url("javascript:alert(1)"), BLOCKED_URL);
+        assert_eq!(escape_url("  JavaScript:alert(1)"), BLOCKED_URL);
+        assert_eq!(escape_url("java\tscript:alert(1)"), BLOCKED_URL);
+        assert_eq!(escape_url("vbscript:msgbox"), BLOCKED_URL);
+        assert_eq!(escape_url("data:text/html,<script>"), BLOCKED_URL);
+        assert_eq!(
+            escape_url("data:image/png;base64,AAAA"),
+            "data:image/png;base64,AAAA"
+        );
+    }
+
+    #[test]
+    fn test_escape_raw_text() {
+        assert_eq!(escape("if (a < b) {}", Context::RawText), "if (a < b) {}");
+        assert_eq!(
+            escape("x = '</script><b>'", Context::RawText),
+            r"x = '<\/script><b>'"
+        );
+        assert_eq!(escape("</STYLE>", Context::RawText), r"<\/STYLE>");
+    }
+
+    #[test]
+    fn test_escape_comment() {
+        assert_eq!(escape("a comment", Context::Comment), "a comment");
+        assert_eq!(escape("end --> here", Context::Comment), "end - -> here");
+        assert_eq!(escape(">start", Context::Comment), "&gt;start");
+    }
+
+    #[test]
+    fn test_escape_js() {
+        assert_eq!(escape_js("plain text"), "plain text");
+        assert_eq!(
+            escape_js("O'Brien said \"hi\"\n</script>"),
+            r"O\u0027Brien said \u0022hi\u0022\n\u003c/script\u003e"
+        );
+        assert_eq!(escape_js("C:\\path & \u{2028}"), r"C:\\path \u0026 \u2028");
+    }
+
+    #[test]
+    fn test_raw_display() {
+        assert_eq!(Raw::new("<b>trusted</b>").to_string(), "<b>trusted</b>");
+        assert_eq!(PreEscaped::new("&amp;").to_string(), "&amp;");
+    }
+}
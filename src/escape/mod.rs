@@ -0,0 +1,5 @@
+mod html;
+
+pub use html::{
+    escape, escape_attr, escape_js, escape_text, escape_url, Context, Escaped, PreEscaped, Raw,
+};
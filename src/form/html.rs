@@ -0,0 +1,402 @@
+use std::fmt::Display;
+
+use crate::attribute::{Attrs, AttrsBuilder};
+use crate::element::{Element, ElementBuilder};
+use crate::tag::Tag;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputType {
+    Button,
+    Checkbox,
+    Color,
+    Date,
+    DatetimeLocal,
+    Email,
+    File,
+    Hidden,
+    Image,
+    Month,
+    Number,
+    Password,
+    Radio,
+    Range,
+    Reset,
+    Search,
+    Submit,
+    Tel,
+    Text,
+    Time,
+    Url,
+    Week,
+}
+impl Display for InputType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputType::Button => write!(f, "button"),
+            InputType::Checkbox => write!(f, "checkbox"),
+            InputType::Color => write!(f, "color"),
+            InputType::Date => write!(f, "date"),
+            InputType::DatetimeLocal => write!(f, "datetime-local"),
+            InputType::Email => write!(f, "email"),
+            InputType::File => write!(f, "file"),
+            InputType::Hidden => write!(f, "hidden"),
+            InputType::Image => write!(f, "image"),
+            InputType::Month => write!(f, "month"),
+            InputType::Number => write!(f, "number"),
+            InputType::Password => write!(f, "password"),
+            InputType::Radio => write!(f, "radio"),
+            InputType::Range => write!(f, "range"),
+            InputType::Reset => write!(f, "reset"),
+            InputType::Search => write!(f, "search"),
+            InputType::Submit => write!(f, "submit"),
+            InputType::Tel => write!(f, "tel"),
+            InputType::Text => write!(f, "text"),
+            InputType::Time => write!(f, "time"),
+            InputType::Url => write!(f, "url"),
+            InputType::Week => write!(f, "week"),
+        }
+    }
+}
+impl From<InputType> for String {
+    fn from(typ: InputType) -> Self {
+        typ.to_string()
+    }
+}
+
+// The HTTP method a form is submitted with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Method {
+    Get,
+    Post,
+    // Closes the `dialog` the form is in, without submitting it.
+    Dialog,
+}
+impl Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Method::Get => write!(f, "get"),
+            Method::Post => write!(f, "post"),
+            Method::Dialog => write!(f, "dialog"),
+        }
+    }
+}
+impl From<Method> for String {
+    fn from(method: Method) -> Self {
+        method.to_string()
+    }
+}
+
+// How form data is encoded when submitted with `Method::Post`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Enctype {
+    UrlEncoded,
+    // Needed for `InputType::File` inputs.
+    Multipart,
+    Plain,
+}
+impl Display for Enctype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Enctype::UrlEncoded => write!(f, "application/x-www-form-urlencoded"),
+            Enctype::Multipart => write!(f, "multipart/form-data"),
+            Enctype::Plain => write!(f, "text/plain"),
+        }
+    }
+}
+impl From<Enctype> for String {
+    fn from(enctype: Enctype) -> Self {
+        enctype.to_string()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Control {
+    Input(InputType),
+    Select(Vec<(String, String)>),
+    Textarea,
+}
+
+// A form control and the label bound to it.
+pub struct Field;
+
+impl Field {
+    // A field with the given control id and label text. The control is a
+    // text input unless another is chosen.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> FieldBuilder {
+        FieldBuilder {
+            id: id.into(),
+            label: label.into(),
+            name: None,
+            value: None,
+            control: Control::Input(InputType::Text),
+            required: false,
+            checked: false,
+            errors: vec![],
+            attrs: Attrs::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldBuilder {
+    id: String,
+    label: String,
+    name: Option<String>,
+    value: Option<String>,
+    control: Control,
+    required: bool,
+    checked: bool,
+    errors: Vec<String>,
+    attrs: AttrsBuilder,
+}
+
+impl FieldBuilder {
+    pub fn input(mut self, typ: InputType) -> Self {
+        self.control = Control::Input(typ);
+        self
+    }
+
+    // A `select` with the given value and label for each option.
+    pub fn select(
+        mut self,
+        options: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        let options = options
+            .into_iter()
+            .map(|(value, label)| (value.into(), label.into()))
+            .collect();
+        self.control = Control::Select(options);
+        self
+    }
+
+    pub fn textarea(mut self) -> Self {
+        self.control = Control::Textarea;
+        self
+    }
+
+    // The name the value is submitted as, which defaults to the id.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    // The initial value. For a `select` this is the option that is selected,
+    // and for a `textarea` its content.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    // Whether a checkbox or radio input is checked.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    // A message shown after the control, which marks it as invalid.
+    pub fn error(mut self, message: impl Into<String>) -> Self {
+        self.errors.push(message.into());
+        self
+    }
+
+    // Further attributes for the control, such as `pattern` or `placeholder`.
+    pub fn attrs(mut self, attrs: AttrsBuilder) -> Self {
+        self.attrs = attrs;
+        self
+    }
+
+    // The label followed by the control and any error messages.
+    pub fn build(self) -> Vec<Element> {
+        let label = ElementBuilder::new(Tag::Label)
+            .attrs(Attrs::new().html_for(&self.id).build())
+            .content(self.label)
+            .build();
+        let name = self.name.unwrap_or_else(|| self.id.clone());
+        let mut attrs = self.attrs.id(&self.id).name(name);
+        if self.required {
+            attrs = attrs.required();
+        }
+        if self.checked {
+            attrs = attrs.checked();
+        }
+        // Each message has an id, so the control can be described by them.
+        let error_ids: Vec<String> = (0..self.errors.len())
+            .map(|i| format!("{}-error-{i}", self.id))
+            .collect();
+        if !error_ids.is_empty() {
+            attrs = attrs
+                .aria_invalid(true)
+                .aria_describedby(error_ids.join(" "));
+        }
+
+        let control = match self.control {
+            Control::Input(typ) => {
+                let attrs = match self.value {
+                    Some(value) => attrs.value(value),
+                    None => attrs,
+                };
+                ElementBuilder::new(Tag::Input)
+                    .attrs(attrs.typ(typ).build())
+                    .build()
+            }
+            Control::Select(options) => {
+                // An empty first option lets an optional field be left
+                // unanswered, and keeps a required one without a value from
+                // showing the first real option as chosen.
+                let empty = (!self.required || self.value.is_none()).then(|| {
+                    ElementBuilder::new(Tag::Option)
+                        .attrs(Attrs::new().value("").build())
+                        .build()
+                });
+                let options: Vec<Element> = empty
+                    .into_iter()
+                    .chain(options.into_iter().map(|(value, label)| {
+                        let selected = self.value.as_ref() == Some(&value);
+                        let attrs = Attrs::new().value(value);
+                        let attrs = if selected { attrs.selected() } else { attrs };
+                        ElementBuilder::new(Tag::Option)
+                            .attrs(attrs.build())
+                            .content(label)
+                            .build()
+                    }))
+                    .collect();
+                ElementBuilder::new(Tag::Select)
+                    .attrs(attrs.build())
+                    .children(options)
+                    .build()
+            }
+            Control::Textarea => {
+                let textarea = ElementBuilder::new(Tag::Textarea).attrs(attrs.build());
+                match self.value {
+                    Some(value) => textarea.content(value),
+                    None => textarea,
+                }
+                .build()
+            }
+        };
+        let errors = self.errors.into_iter().zip(error_ids).map(|(message, id)| {
+            ElementBuilder::new(Tag::P)
+                .attrs(Attrs::new().id(id).class(vec!["error"]).build())
+                .content(message)
+                .build()
+        });
+        let mut elements = vec![label, control];
+        elements.extend(errors);
+        elements
+    }
+}
+
+// A `fieldset` grouping fields under a `legend`.
+pub fn fieldset(legend: impl Into<String>, fields: Vec<Element>) -> Element {
+    let mut children = vec![ElementBuilder::new(Tag::Legend).content(legend).build()];
+    children.extend(fields);
+    ElementBuilder::new(Tag::Fieldset)
+        .children(children)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_input_type_display() {
+        assert_eq!(InputType::DatetimeLocal.to_string(), "datetime-local");
+        assert_eq!(Method::Post.to_string(), "post");
+        assert_eq!(Enctype::Multipart.to_string(), "multipart/form-data");
+    }
+
+    #[test]
+    fn test_field_binds_label_to_input() {
+        let field = Field::new("email", "Email")
+            .input(InputType::Email)
+            .attrs(Attrs::new().required().placeholder("you@example.com"))
+            .build();
+
+        assert_eq!(
+            field
+                .iter()
+                .map(|element| element.to_string())
+                .collect::<String>(),
+            concat!(
+                r#"<label for="email">Email</label>"#,
+                r#"<input required placeholder="you@example.com" id="email" name="email" type="email">"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_select_and_textarea_fields() {
+        let select = Field::new("role", "Role")
+            .select([("user", "User"), ("admin", "Admin")])
+            .value("admin")
+            .build();
+        assert_eq!(
+            select[1].to_string(),
+            r#"<select id="role" name="role"><option value=""></option><option value="user">User</option><option value="admin" selected>Admin</option></select>"#
+        );
+
+        // A required select only has an empty option until it has a value.
+        let role = Field::new("role", "Role")
+            .select([("user", "User"), ("admin", "Admin")])
+            .required(true);
+        assert_eq!(
+            role.clone().build()[1].to_string(),
+            r#"<select id="role" name="role" required><option value=""></option><option value="user">User</option><option value="admin">Admin</option></select>"#
+        );
+        assert_eq!(
+            role.value("user").build()[1].to_string(),
+            r#"<select id="role" name="role" required><option value="user" selected>User</option><option value="admin">Admin</option></select>"#
+        );
+
+        let bio = Field::new("bio", "Bio")
+            .name("profile[bio]")
+            .textarea()
+            .value("<b>hi</b>")
+            .attrs(Attrs::new().rows(4))
+            .build();
+        assert_eq!(
+            bio[1].to_string(),
+            r#"<textarea rows="4" id="bio" name="profile[bio]">&lt;b&gt;hi&lt;/b&gt;</textarea>"#
+        );
+    }
+
+    #[test]
+    fn test_form_is_valid_and_accessible() {
+        let mut fields = Field::new("name", "Name").build();
+        fields.extend(
+            Field::new("age", "Age")
+                .input(InputType::Number)
+                .attrs(Attrs::new().min("0").max("150").step("1"))
+                .build(),
+        );
+        let form = ElementBuilder::new(Tag::Form)
+            .attrs(
+                Attrs::new()
+                    .action("/people")
+                    .method(Method::Post)
+                    .enctype(Enctype::UrlEncoded)
+                    .build(),
+            )
+            .children(vec![
+                fieldset("Person", fields),
+                ElementBuilder::new(Tag::Button)
+                    .attrs(Attrs::new().typ(InputType::Submit).build())
+                    .content("Save")
+                    .build(),
+            ])
+            .build();
+
+        assert_eq!(form.validate(), Ok(()));
+        assert_eq!(form.lint_a11y(), Ok(()));
+        assert!(form
+            .to_string()
+            .starts_with(r#"<form action="/people" method="post" enctype="application/x-www-form-urlencoded"><fieldset><legend>Person</legend>"#));
+    }
+}
@@ -0,0 +1,8 @@
+mod html;
+mod value;
+
+pub use html::{fieldset, Enctype, Field, FieldBuilder, InputType, Method};
+pub use value::{FormErrors, FormValue, HtmlForm};
+
+#[cfg(feature = "derive")]
+pub use htmlatron_derive::{FormValue, HtmlForm};
@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+
+use super::{FieldBuilder, InputType};
+use crate::element::Element;
+
+// A type that can be edited with a form control, used by `#[derive(HtmlForm)]`
+// to choose the control for each field. `#[derive(FormValue)]` implements it
+// for an enum with unit variants, which is edited with a `select`.
+pub trait FormValue {
+    // Set the control for the field, and its value when there is one.
+    fn field(value: Option<&Self>, field: FieldBuilder) -> FieldBuilder;
+
+    // Whether the field must be filled in before the form is submitted.
+    fn required() -> bool {
+        true
+    }
+}
+
+// A struct that can be edited with a form, usually implemented with
+// `#[derive(HtmlForm)]`.
+pub trait HtmlForm {
+    // The form, filled in with the current values.
+    fn html_form(&self) -> Element {
+        self.html_form_with_errors(&FormErrors::new())
+    }
+
+    // The form, filled in with the current values and showing an error
+    // message after each field that has one. Use it to render a submitted
+    // form again when it did not pass validation.
+    fn html_form_with_errors(&self, errors: &FormErrors) -> Element;
+}
+
+// Error messages for the fields of a form, keyed by field name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormErrors {
+    errors: BTreeMap<String, Vec<String>>,
+}
+
+impl FormErrors {
+    pub fn new() -> Self {
+        FormErrors::default()
+    }
+    pub fn add(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.errors
+            .entry(field.into())
+            .or_default()
+            .push(message.into());
+    }
+    pub fn get(&self, field: &str) -> &[String] {
+        self.errors.get(field).map_or(&[], Vec::as_slice)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl FormValue for String {
+    fn field(value: Option<&Self>, field: FieldBuilder) -> FieldBuilder {
+        match value.filter(|value| !value.is_empty()) {
+            Some(value) => field.value(value),
+            None => field,
+        }
+    }
+}
+
+impl FormValue for bool {
+    fn field(value: Option<&Self>, field: FieldBuilder) -> FieldBuilder {
+        field
+            .input(InputType::Checkbox)
+            .value("true")
+            .checked(value.copied().unwrap_or_default())
+    }
+
+    // An unchecked checkbox is a valid answer.
+    fn required() -> bool {
+        false
+    }
+}
+
+impl<T: FormValue> FormValue for Option<T> {
+    fn field(value: Option<&Self>, field: FieldBuilder) -> FieldBuilder {
+        T::field(value.and_then(Option::as_ref), field)
+    }
+
+    fn required() -> bool {
+        false
+    }
+}
+
+macro_rules! form_value_number {
+    ($($ty:ty),*) => {
+        $(
+            impl FormValue for $ty {
+                fn field(value: Option<&Self>, field: FieldBuilder) -> FieldBuilder {
+                    let field = field.input(InputType::Number);
+                    match value {
+                        Some(value) => field.value(value.to_string()),
+                        None => field,
+                    }
+                }
+            }
+        )*
+    };
+}
+form_value_number!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(FormValue)]
+    #[allow(dead_code)]
+    enum Plan {
+        Free,
+        /// Team plan
+        TeamPro,
+        #[html_form(value = "ent", label = "Enterprise")]
+        Enterprise,
+    }
+
+    #[derive(HtmlForm)]
+    #[html_form(action = "/signup", submit = "Sign up")]
+    struct Signup {
+        /// Your name
+        name: String,
+        #[html_form(input = "email", placeholder = "you@example.com")]
+        email: String,
+        age: Option<u32>,
+        plan: Plan,
+        newsletter: bool,
+        #[html_form(skip)]
+        #[allow(dead_code)]
+        token: String,
+    }
+
+    fn signup() -> Signup {
+        Signup {
+            name: String::new(),
+            email: "ferris@example.com".to_string(),
+            age: Some(7),
+            plan: Plan::TeamPro,
+            newsletter: true,
+            token: "secret".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_derive_html_form() {
+        let form = signup().html_form();
+
+        assert_eq!(
+            form.to_string(),
+            concat!(
+                r#"<form method="post" action="/signup">"#,
+                r#"<label for="name">Your name</label>"#,
+                r#"<input id="name" name="name" required type="text">"#,
+                r#"<label for="email">Email</label>"#,
+                r#"<input placeholder="you@example.com" id="email" name="email" required value="ferris@example.com" type="email">"#,
+                r#"<label for="age">Age</label>"#,
+                r#"<input id="age" name="age" value="7" type="number">"#,
+                r#"<label for="plan">Plan</label>"#,
+                r#"<select id="plan" name="plan" required>"#,
+                r#"<option value="free">Free</option>"#,
+                r#"<option value="team_pro" selected>Team plan</option>"#,
+                r#"<option value="ent">Enterprise</option>"#,
+                r#"</select>"#,
+                r#"<label for="newsletter">Newsletter</label>"#,
+                r#"<input id="newsletter" name="newsletter" checked value="true" type="checkbox">"#,
+                r#"<button type="submit">Sign up</button>"#,
+                r#"</form>"#
+            )
+        );
+        assert_eq!(form.validate(), Ok(()));
+        assert_eq!(form.lint_a11y(), Ok(()));
+    }
+
+    #[test]
+    fn test_derive_html_form_with_errors() {
+        let mut errors = FormErrors::new();
+        errors.add("name", "Name is required");
+        errors.add("name", "Name must be <= 20 characters");
+        assert_eq!(errors.get("email"), &[] as &[String]);
+
+        let form = signup().html_form_with_errors(&errors);
+        let children = form.children.as_ref().unwrap();
+
+        assert_eq!(
+            children[1].to_string(),
+            r#"<input id="name" name="name" required aria-invalid="true" aria-describedby="name-error-0 name-error-1" type="text">"#
+        );
+        assert_eq!(
+            children[2].to_string(),
+            r#"<p id="name-error-0" class="error">Name is required</p>"#
+        );
+        assert_eq!(
+            children[3].to_string(),
+            r#"<p id="name-error-1" class="error">Name must be &lt;= 20 characters</p>"#
+        );
+        assert_eq!(children[4].tag, Tag::Label);
+    }
+
+    #[test]
+    fn test_derive_optional_select() {
+        #[derive(HtmlForm)]
+        struct Upgrade {
+            plan: Option<Plan>,
+        }
+
+        let form = Upgrade { plan: None }.html_form();
+        let children = form.children.as_ref().unwrap();
+        assert_eq!(
+            children[1].to_string(),
+            concat!(
+                r#"<select id="plan" name="plan"><option value=""></option>"#,
+                r#"<option value="free">Free</option>"#,
+                r#"<option value="team_pro">Team plan</option>"#,
+                r#"<option value="ent">Enterprise</option></select>"#
+            )
+        );
+        assert_eq!(form.validate(), Ok(()));
+        assert_eq!(form.lint_a11y(), Ok(()));
+
+        let form = Upgrade {
+            plan: Some(Plan::Free),
+        }
+        .html_form();
+        assert!(form
+            .to_string()
+            .contains(r#"<option value=""></option><option value="free" selected>Free</option>"#));
+    }
+}
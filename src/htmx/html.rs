@@ -0,0 +1,210 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::attribute::AttrsBuilder;
+
+// How htmx swaps a response into the target, for `hx-swap` and
+// `hx-swap-oob`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Swap {
+    #[default]
+    InnerHtml,
+    OuterHtml,
+    TextContent,
+    BeforeBegin,
+    AfterBegin,
+    BeforeEnd,
+    AfterEnd,
+    Delete,
+    None,
+}
+impl Display for Swap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Swap::InnerHtml => write!(f, "innerHTML"),
+            Swap::OuterHtml => write!(f, "outerHTML"),
+            Swap::TextContent => write!(f, "textContent"),
+            Swap::BeforeBegin => write!(f, "beforebegin"),
+            Swap::AfterBegin => write!(f, "afterbegin"),
+            Swap::BeforeEnd => write!(f, "beforeend"),
+            Swap::AfterEnd => write!(f, "afterend"),
+            Swap::Delete => write!(f, "delete"),
+            Swap::None => write!(f, "none"),
+        }
+    }
+}
+
+// What makes htmx send a request, for `hx-trigger`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Trigger {
+    Click,
+    Change,
+    Submit,
+    Input,
+    KeyUp,
+    Load,
+    // The element is scrolled into the viewport.
+    Revealed,
+    // The element intersects the viewport.
+    Intersect,
+    // Polling, such as `every 2s`.
+    Every(Duration),
+    // Any other trigger, with modifiers, such as `keyup changed delay:500ms`.
+    Event(String),
+}
+impl Display for Trigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Trigger::Click => write!(f, "click"),
+            Trigger::Change => write!(f, "change"),
+            Trigger::Submit => write!(f, "submit"),
+            Trigger::Input => write!(f, "input"),
+            Trigger::KeyUp => write!(f, "keyup"),
+            Trigger::Load => write!(f, "load"),
+            Trigger::Revealed => write!(f, "revealed"),
+            Trigger::Intersect => write!(f, "intersect"),
+            Trigger::Every(interval) if interval.subsec_millis() == 0 => {
+                write!(f, "every {}s", interval.as_secs())
+            }
+            Trigger::Every(interval) => write!(f, "every {}ms", interval.as_millis()),
+            Trigger::Event(event) => write!(f, "{event}"),
+        }
+    }
+}
+
+impl AttrsBuilder {
+    // Any `hx-*` attribute, for those without a typed method.
+    pub fn hx(self, name: &str, value: impl Display) -> Self {
+        self.attr(format!("hx-{name}"), value.to_string())
+    }
+
+    pub fn hx_get(self, url: impl Display) -> Self {
+        self.hx("get", url)
+    }
+
+    pub fn hx_post(self, url: impl Display) -> Self {
+        self.hx("post", url)
+    }
+
+    pub fn hx_put(self, url: impl Display) -> Self {
+        self.hx("put", url)
+    }
+
+    pub fn hx_patch(self, url: impl Display) -> Self {
+        self.hx("patch", url)
+    }
+
+    pub fn hx_delete(self, url: impl Display) -> Self {
+        self.hx("delete", url)
+    }
+
+    // The element to swap into, as a CSS selector or an extended selector
+    // such as `closest tr`.
+    pub fn hx_target(self, target: impl Display) -> Self {
+        self.hx("target", target)
+    }
+
+    pub fn hx_swap(self, swap: Swap) -> Self {
+        self.hx("swap", swap)
+    }
+
+    pub fn hx_trigger(self, trigger: Trigger) -> Self {
+        self.hx("trigger", trigger)
+    }
+
+    // Values to send with the request, written as JSON.
+    pub fn hx_vals(self, vals: Value) -> Self {
+        self.hx("vals", vals)
+    }
+
+    // Headers to send with the request, written as JSON.
+    pub fn hx_headers(self, headers: Value) -> Self {
+        self.hx("headers", headers)
+    }
+
+    pub fn hx_select(self, selector: impl Display) -> Self {
+        self.hx("select", selector)
+    }
+
+    pub fn hx_include(self, selector: impl Display) -> Self {
+        self.hx("include", selector)
+    }
+
+    pub fn hx_indicator(self, selector: impl Display) -> Self {
+        self.hx("indicator", selector)
+    }
+
+    pub fn hx_confirm(self, message: impl Display) -> Self {
+        self.hx("confirm", message)
+    }
+
+    // Push the request URL, or `url`, into the browser history.
+    pub fn hx_push_url(self, url: Option<&str>) -> Self {
+        self.hx("push-url", url.unwrap_or("true"))
+    }
+
+    pub fn hx_boost(self) -> Self {
+        self.hx("boost", true)
+    }
+
+    // Swap the element into the page by its id, from any response.
+    pub fn hx_swap_oob(self, swap: Swap) -> Self {
+        match swap {
+            Swap::OuterHtml => self.hx("swap-oob", true),
+            swap => self.hx("swap-oob", swap),
+        }
+    }
+
+    // An inline event handler, written as `hx-on:<event>`.
+    pub fn hx_on(self, event: &str, script: impl Display) -> Self {
+        self.hx(&format!("on:{event}"), script)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_hx_attributes() {
+        let attrs = Attrs::new()
+            .hx_get("/search")
+            .hx_target("#results")
+            .hx_swap(Swap::OuterHtml)
+            .hx_trigger(Trigger::Event("keyup changed delay:500ms".to_string()))
+            .hx_vals(serde_json::json!({"page": 2, "q": "a\"b"}))
+            .hx_push_url(None)
+            .hx_on("htmx:after-request", "this.reset()")
+            .build();
+
+        assert_eq!(
+            attrs.get_attrs(&Tag::Input),
+            vec![
+                r#"hx-get="/search""#,
+                r##"hx-target="#results""##,
+                r#"hx-swap="outerHTML""#,
+                r#"hx-trigger="keyup changed delay:500ms""#,
+                r#"hx-vals="{&quot;page&quot;:2,&quot;q&quot;:&quot;a\&quot;b&quot;}""#,
+                r#"hx-push-url="true""#,
+                r#"hx-on:htmx:after-request="this.reset()""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_triggers() {
+        assert_eq!(
+            Trigger::Every(Duration::from_secs(2)).to_string(),
+            "every 2s"
+        );
+        assert_eq!(
+            Trigger::Every(Duration::from_millis(1500)).to_string(),
+            "every 1500ms"
+        );
+        assert_eq!(Trigger::Revealed.to_string(), "revealed");
+        assert_eq!(Swap::default().to_string(), "innerHTML");
+    }
+}
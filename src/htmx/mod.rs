@@ -0,0 +1,5 @@
+mod html;
+mod partial;
+
+pub use html::{Swap, Trigger};
+pub use partial::{Partial, PartialBuilder};
@@ -0,0 +1,146 @@
+use std::fmt::{Display, Write};
+
+use log::warn;
+
+use super::Swap;
+use crate::element::{Element, IntoChildren};
+use crate::render::{Config, Render, RenderError};
+
+// The response to an htmx request: a fragment for the request's target,
+// followed by elements that htmx swaps in out of band, elsewhere on the
+// page. Rendered without a doctype or page shell.
+#[derive(Debug, Clone, Default)]
+pub struct Partial {
+    elements: Vec<Element>,
+}
+
+impl Partial {
+    pub fn new() -> PartialBuilder {
+        PartialBuilder::default()
+    }
+    // The fragment, then the out-of-band elements.
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+}
+
+impl Render for Partial {
+    fn render_to(&self, w: &mut impl Write) -> std::fmt::Result {
+        for element in &self.elements {
+            element.render_to(w)?;
+        }
+        Ok(())
+    }
+
+    fn render_with(&self, config: &Config, w: &mut impl Write) -> Result<(), RenderError> {
+        for (i, element) in self.elements.iter().enumerate() {
+            if config.pretty && i > 0 {
+                w.write_char('\n')?;
+            }
+            element.render_with(config, w)?;
+        }
+        Ok(())
+    }
+}
+impl Display for Partial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render_to(f)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PartialBuilder {
+    content: Vec<Element>,
+    oob: Vec<Element>,
+}
+
+impl PartialBuilder {
+    // The fragment swapped into the target of the request.
+    pub fn content(mut self, content: impl IntoChildren) -> Self {
+        content.push_into(&mut self.content);
+        self
+    }
+
+    // An element that replaces, or is swapped into, the element with the
+    // same id. Elements without an id are swapped nowhere, so are left out
+    // with a warning.
+    pub fn oob(self, mut element: Element, swap: Swap) -> Self {
+        if element.attr("id").is_none() {
+            warn!("out-of-band <{}> has no id to swap into", element.tag);
+            return self;
+        }
+        let value = match swap {
+            Swap::OuterHtml => "true".to_string(),
+            swap => swap.to_string(),
+        };
+        element.attrs_mut().set("hx-swap-oob", Some(value));
+        self.push_oob(element)
+    }
+
+    // Swap the children of `element` into the elements matching `target`,
+    // a CSS selector, such as appending rows with `Swap::BeforeEnd`.
+    pub fn oob_into(self, target: &str, swap: Swap, mut element: Element) -> Self {
+        element
+            .attrs_mut()
+            .set("hx-swap-oob", Some(format!("{swap}:{target}")));
+        self.push_oob(element)
+    }
+
+    fn push_oob(mut self, element: Element) -> Self {
+        self.oob.push(element);
+        self
+    }
+
+    pub fn build(self) -> Partial {
+        let mut elements = self.content;
+        elements.extend(self.oob);
+        Partial { elements }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_partial_with_oob_swaps() {
+        let row = ElementBuilder::new(Tag::Tr)
+            .children(ElementBuilder::new(Tag::Td).content("Ada").build())
+            .build();
+        let count = ElementBuilder::new(Tag::Span)
+            .attrs(Attrs::new().id("count").build())
+            .content("3")
+            .build();
+        let toast = ElementBuilder::new(Tag::Div)
+            .attrs(Attrs::new().id("toast").build())
+            .content("Saved")
+            .build();
+        let rows = ElementBuilder::new(Tag::Tbody).children(row).build();
+
+        let partial = Partial::new()
+            .content(ElementBuilder::new(Tag::P).content("Added Ada").build())
+            .oob(count, Swap::OuterHtml)
+            .oob(toast, Swap::InnerHtml)
+            .oob(ElementBuilder::new(Tag::Div).build(), Swap::OuterHtml)
+            .oob_into("#people", Swap::BeforeEnd, rows)
+            .build();
+
+        assert_eq!(partial.elements().len(), 4);
+        assert_eq!(
+            partial.to_string(),
+            concat!(
+                "<p>Added Ada</p>",
+                r#"<span id="count" hx-swap-oob="true">3</span>"#,
+                r#"<div id="toast" hx-swap-oob="innerHTML">Saved</div>"#,
+                r#"<tbody hx-swap-oob="beforeend:#people"><tr><td>Ada</td></tr></tbody>"#
+            )
+        );
+
+        let mut out = String::new();
+        partial.render_with(&Config::pretty(), &mut out).unwrap();
+        assert!(
+            out.starts_with("<p>Added Ada</p>\n<span id=\"count\" hx-swap-oob=\"true\">3</span>\n")
+        );
+    }
+}
@@ -0,0 +1,406 @@
+// Conversion between elements and JsonML, the array form of markup used by
+// JavaScript tools:
+//
+//   ["a", {"href": "/", "hidden": true}, "Home ", ["b", "now"]]
+//
+// An element is an array of its tag name, an optional object of attributes,
+// then its children, with strings for text. Boolean attributes are `true`.
+// Comments are `["!--", "text"]` and raw markup is `["#raw", "markup"]`,
+// which is read back as text unless trusted, as JsonML may come from other
+// teams and services.
+// Names are those written by `Tag` and `Attr`, read back with `FromStr`.
+
+use std::fmt::Display;
+
+use log::warn;
+use serde_json::{Map, Value};
+
+use crate::attribute::Attrs;
+use crate::element::{Element, ElementBuilder};
+use crate::tag::{CustomElements, MathTag, SvgTag, Tag};
+use crate::validate::{validate_opening_tag, NodePath};
+
+// Which names child elements are read as, as with the parser.
+#[derive(Debug, Clone, Copy)]
+enum Namespace {
+    Html,
+    Svg,
+    MathMl,
+}
+
+// The element as JsonML, with the attributes that would be rendered.
+pub fn to_jsonml(element: &Element) -> Value {
+    let tag = &element.tag;
+    let content = element.content.as_deref();
+    match tag {
+        Tag::Text => return Value::from(content.unwrap_or_default()),
+        Tag::Raw => return Value::from(vec!["#raw", content.unwrap_or_default()]),
+        _ => {}
+    }
+
+    let mut node = vec![Value::from(tag.to_string())];
+    if let Some(attrs) = &element.attrs {
+        let attrs: Map<String, Value> = attrs
+            .supported(tag)
+            .iter()
+            .map(|entry| {
+                let value = entry.value().map_or(Value::Bool(true), Value::from);
+                (entry.name().to_string(), value)
+            })
+            .collect();
+        if !attrs.is_empty() {
+            node.push(Value::Object(attrs));
+        }
+    }
+    match (tag, &element.text) {
+        (Tag::Comment | Tag::Doctype, Some(text)) => node.push(Value::from(text.as_str())),
+        (_, Some(_)) => warn!("the text within the <{tag}> tag has no JsonML form"),
+        _ => {}
+    }
+    if let Some(content) = content {
+        node.push(Value::from(content));
+    }
+    node.extend(element.children.iter().flatten().map(to_jsonml));
+    Value::Array(node)
+}
+
+// Read an element from JsonML. Leading text becomes the element content,
+// as with the parser, and raw markup becomes text. Unknown tags, invalid
+// attribute names and attributes the tag does not support are errors.
+pub fn from_jsonml(value: &Value) -> Result<Element, InvalidJsonMl> {
+    JsonMlReader::default().read(value)
+}
+
+// Reads JsonML as `from_jsonml` does, with the definitions of custom
+// elements, and optionally with raw markup kept as it is, to be rendered
+// unescaped. Trust raw markup only for JsonML as trusted as the code.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonMlReader<'a> {
+    custom_elements: Option<&'a CustomElements>,
+    trust_raw: bool,
+}
+
+impl<'a> JsonMlReader<'a> {
+    pub fn new() -> JsonMlReaderBuilder<'a> {
+        JsonMlReaderBuilder {
+            reader: JsonMlReader::default(),
+        }
+    }
+
+    pub fn read(&self, value: &Value) -> Result<Element, InvalidJsonMl> {
+        self.node(value, Namespace::Html, "")
+    }
+
+    fn node(
+        &self,
+        value: &Value,
+        namespace: Namespace,
+        path: &str,
+    ) -> Result<Element, InvalidJsonMl> {
+        let error = |reason: String| InvalidJsonMl {
+            path: path.to_string(),
+            reason,
+        };
+        let items = match value {
+            Value::String(text) => return Ok(ElementBuilder::new(Tag::Text).content(text).build()),
+            Value::Array(items) => items,
+            other => {
+                return Err(error(format!(
+                    "expected text or an element array, found {}",
+                    kind(other)
+                )))
+            }
+        };
+        let name = match items.first() {
+            Some(Value::String(name)) => name,
+            Some(other) => {
+                return Err(error(format!("expected a tag name, found {}", kind(other))))
+            }
+            None => {
+                return Err(error(
+                    "expected a tag name, found an empty array".to_string(),
+                ))
+            }
+        };
+        let tag = tag_from_name(name, namespace, self.trust_raw).map_err(error)?;
+        let tag = match self.custom_elements {
+            Some(elements) => elements.resolve(&tag).unwrap_or(tag),
+            None => tag,
+        };
+
+        // Comments, doctypes and raw markup hold only text.
+        if matches!(tag, Tag::Comment | Tag::Doctype | Tag::Raw | Tag::Text) {
+            let mut text = String::new();
+            for (i, item) in items.iter().enumerate().skip(1) {
+                match item {
+                    Value::String(part) => text.push_str(part),
+                    other => {
+                        return Err(InvalidJsonMl {
+                            path: format!("{path}/{i}"),
+                            reason: format!("'{name}' holds only text, found {}", kind(other)),
+                        })
+                    }
+                }
+            }
+            let element = ElementBuilder::new(tag);
+            return Ok(match element.tag {
+                Tag::Raw | Tag::Text => element.content(text),
+                _ => element.text(text),
+            }
+            .build());
+        }
+
+        let mut element = ElementBuilder::new(tag.clone());
+        let mut start = 1;
+        if let Some(Value::Object(attrs)) = items.get(1) {
+            let path = format!("{path}/1");
+            let attrs = attributes(attrs, &path)?;
+            let opening = ElementBuilder::new(tag.clone())
+                .attrs(attrs.clone())
+                .build();
+            if let Some(error) = validate_opening_tag(&opening, &tag, &NodePath::default()).first()
+            {
+                return Err(InvalidJsonMl {
+                    path,
+                    reason: error.to_string(),
+                });
+            }
+            element = element.attrs(attrs);
+            start = 2;
+        }
+        let inner = match &tag {
+            Tag::Svg(svg) if !svg.is_html_integration_point() => Namespace::Svg,
+            Tag::Math(math) if !math.is_html_integration_point() => Namespace::MathMl,
+            _ => Namespace::Html,
+        };
+        let mut children = vec![];
+        for (i, item) in items.iter().enumerate().skip(start) {
+            match item {
+                Value::String(content) if i == start => element = element.content(content),
+                item => children.push(self.node(item, inner, &format!("{path}/{i}"))?),
+            }
+        }
+        if !children.is_empty() {
+            element = element.children(children);
+        }
+        Ok(element.build())
+    }
+}
+
+#[derive(Debug)]
+pub struct JsonMlReaderBuilder<'a> {
+    reader: JsonMlReader<'a>,
+}
+
+impl<'a> JsonMlReaderBuilder<'a> {
+    // Give the custom elements read their definitions.
+    pub fn custom_elements(mut self, custom_elements: &'a CustomElements) -> Self {
+        self.reader.custom_elements = Some(custom_elements);
+        self
+    }
+
+    // Read `#raw` as raw markup rather than text.
+    pub fn trust_raw(mut self, trust_raw: bool) -> Self {
+        self.reader.trust_raw = trust_raw;
+        self
+    }
+
+    pub fn build(self) -> JsonMlReader<'a> {
+        self.reader
+    }
+}
+
+fn tag_from_name(name: &str, namespace: Namespace, trust_raw: bool) -> Result<Tag, String> {
+    match (name, namespace) {
+        ("!--", _) => Ok(Tag::Comment),
+        ("#raw", _) if trust_raw => Ok(Tag::Raw),
+        ("#raw", _) => Ok(Tag::Text),
+        (_, Namespace::Svg) => name.parse::<SvgTag>().map(Tag::Svg).map_err(describe),
+        (_, Namespace::MathMl) => name.parse::<MathTag>().map(Tag::Math).map_err(describe),
+        (_, Namespace::Html) => name.parse::<Tag>().map_err(describe),
+    }
+}
+
+fn attributes(attrs: &Map<String, Value>, path: &str) -> Result<Attrs, InvalidJsonMl> {
+    let mut builder = Attrs::new();
+    for (name, value) in attrs {
+        let error = |reason: String| InvalidJsonMl {
+            path: format!("{path}/{name}"),
+            reason,
+        };
+        let value = match value {
+            Value::String(value) => Some(value.clone()),
+            Value::Number(number) => Some(number.to_string()),
+            Value::Bool(true) | Value::Null => None,
+            Value::Bool(false) => continue,
+            other => {
+                return Err(error(format!(
+                    "expected an attribute value, found {}",
+                    kind(other)
+                )))
+            }
+        };
+        builder = builder
+            .try_attr(name.as_str(), value)
+            .map_err(|e| error(e.to_string()))?;
+    }
+    Ok(builder.build())
+}
+
+fn describe(error: impl Display) -> String {
+    error.to_string()
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+// Error returned when a value is not JsonML for an element, with the JSON
+// pointer to the offending value, such as `/2/1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidJsonMl {
+    pub path: String,
+    pub reason: String,
+}
+
+impl Display for InvalidJsonMl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.path.is_empty() {
+            true => write!(f, "invalid JsonML: {}", self.reason),
+            false => write!(f, "invalid JsonML at {}: {}", self.path, self.reason),
+        }
+    }
+}
+impl std::error::Error for InvalidJsonMl {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn test_to_jsonml() {
+        let element = parse_fragment(concat!(
+            r#"<a href="/" hidden>Home <b>now</b><!-- x -->"#,
+            r#"<svg viewBox="0 0 2 2"><circle r="1"></circle></svg></a>"#
+        ))
+        .unwrap()
+        .remove(0);
+
+        assert_eq!(
+            to_jsonml(&element),
+            json!(["a", {"hidden": true, "href": "/"}, "Home ", ["b", "now"], ["!--", "x"],
+                ["svg", {"viewBox": "0 0 2 2"}, ["circle", {"r": "1"}]]])
+        );
+        let p = ElementBuilder::new(Tag::P)
+            .children(("Hi ", Raw::new("<br>")))
+            .build();
+        assert_eq!(to_jsonml(&p), json!(["p", "Hi ", ["#raw", "<br>"]]));
+    }
+
+    #[test]
+    fn test_from_jsonml() {
+        let value = json!(["ul", {"class": "menu", "data-n": 2, "hidden": true, "lang": false},
+            ["li", "A & B ", ["b", "now"], " later"],
+            ["svg", {"viewBox": "0 0 2 2"}, ["linearGradient"]],
+            ["script", "a < b"],
+            ["!--", "note"]]);
+        let element = from_jsonml(&value).unwrap();
+
+        assert_eq!(
+            element.to_string(),
+            concat!(
+                r#"<ul class="menu" data-n="2" hidden><li>A &amp; B <b>now</b> later</li>"#,
+                r#"<svg viewBox="0 0 2 2"><linearGradient></linearGradient></svg>"#,
+                "<script>a < b</script><!-- note --></ul>"
+            )
+        );
+        let back = from_jsonml(&to_jsonml(&element)).unwrap();
+        assert_eq!(back.to_string(), element.to_string());
+        assert_eq!(
+            from_jsonml(&json!("hi")).unwrap().to_string(),
+            "hi".to_string()
+        );
+    }
+
+    #[test]
+    fn test_invalid_jsonml() {
+        let error = |value: serde_json::Value| from_jsonml(&value).unwrap_err().to_string();
+
+        assert_eq!(
+            error(json!(["div", ["p", ["blink"]]])),
+            "invalid JsonML at /1/1: unknown HTML tag 'blink'"
+        );
+        assert_eq!(
+            error(json!(["svg", ["div"]])),
+            "invalid JsonML at /1: unknown SVG tag 'div'"
+        );
+        assert_eq!(
+            error(json!(["div", {"href": "/"}])),
+            "invalid JsonML at /1: HTML tag 'div' does not support the 'href' attribute"
+        );
+        assert_eq!(
+            error(json!(["p", {"x onload": "1"}])),
+            "invalid JsonML at /1/x onload: invalid HTML attribute name 'x onload'"
+        );
+        assert_eq!(
+            error(json!(["p", "a", 1])),
+            "invalid JsonML at /2: expected text or an element array, found a number"
+        );
+        assert_eq!(
+            error(json!([{"id": "x"}])),
+            "invalid JsonML: expected a tag name, found an object"
+        );
+        assert_eq!(
+            error(json!(["!--", ["b"]])),
+            "invalid JsonML at /1: '!--' holds only text, found an array"
+        );
+    }
+
+    #[test]
+    fn test_jsonml_raw() {
+        let value = json!(["p", ["#raw", "<script>x()</script>"]]);
+
+        // Untrusted raw markup is read as text, and escaped.
+        assert_eq!(
+            from_jsonml(&value).unwrap().to_string(),
+            "<p>&lt;script&gt;x()&lt;/script&gt;</p>"
+        );
+        let p = JsonMlReader::new()
+            .trust_raw(true)
+            .build()
+            .read(&value)
+            .unwrap();
+        assert_eq!(p.to_string(), "<p><script>x()</script></p>");
+        assert_eq!(to_jsonml(&p), value);
+    }
+
+    #[test]
+    fn test_jsonml_custom_elements() {
+        let elements = CustomElements::new()
+            .define("rating-stars", ["max-stars"])
+            .build();
+        let reader = JsonMlReader::new().custom_elements(&elements).build();
+
+        let value = json!(["div", ["rating-stars", {"max-stars": "5"}]]);
+        let div = reader.read(&value).unwrap();
+        assert_eq!(
+            div.children.unwrap()[0].tag,
+            elements.get("rating-stars").unwrap()
+        );
+        let value = json!(["div", ["rating-stars", {"colour": "red"}]]);
+        assert_eq!(
+            reader.read(&value).unwrap_err().to_string(),
+            "invalid JsonML at /1/1: HTML tag 'rating-stars' does not support the 'colour' attribute"
+        );
+        assert!(from_jsonml(&value).is_ok());
+    }
+}
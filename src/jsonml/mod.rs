@@ -0,0 +1,3 @@
+mod html;
+
+pub use html::{from_jsonml, to_jsonml, InvalidJsonMl, JsonMlReader, JsonMlReaderBuilder};
@@ -0,0 +1,386 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write};
+use std::io;
+use std::sync::Arc;
+
+use log::warn;
+
+use crate::document::Document;
+use crate::element::{Element, IntoChildren};
+use crate::render::{Config, Render, RenderError};
+use crate::tag::Tag;
+
+type Template = Arc<dyn Fn(&Blocks) -> Document + Send + Sync>;
+type BlockFn = Arc<dyn Fn(&Blocks) -> Vec<Element> + Send + Sync>;
+
+// A page shell with named blocks, such as `title`, `head`, `main` and
+// `footer`, which pages and other layouts fill in or override.
+//
+// The base layout is a template that builds the document from the blocks.
+// `extend` starts a layout or page on top of another, so layouts can be
+// nested as deep as needed. The last definition of a block wins, and can
+// include the definition it overrides with `Blocks::parent`.
+#[derive(Clone)]
+pub struct Layout {
+    template: Template,
+    // Every definition of each block, from the base layout up.
+    blocks: BTreeMap<String, Vec<BlockFn>>,
+    required: Vec<String>,
+}
+
+impl Layout {
+    pub fn new(template: impl Fn(&Blocks) -> Document + Send + Sync + 'static) -> LayoutBuilder {
+        LayoutBuilder {
+            layout: Layout {
+                template: Arc::new(template),
+                blocks: BTreeMap::new(),
+                required: vec![],
+            },
+        }
+    }
+
+    // A layout or page that fills in or overrides the blocks of this one.
+    pub fn extend(&self) -> LayoutBuilder {
+        LayoutBuilder {
+            layout: self.clone(),
+        }
+    }
+
+    // Build the document, failing if a required block is empty or a block
+    // includes itself.
+    pub fn document(&self) -> Result<Document, LayoutError> {
+        let state = RefCell::new(BuildState::default());
+        let blocks = Blocks {
+            layout: self,
+            current: None,
+            state: &state,
+        };
+        for name in &self.required {
+            if blocks.get(name).is_empty() {
+                if let Some(error) = state.take().error {
+                    return Err(error);
+                }
+                return Err(LayoutError::MissingBlock(name.clone()));
+            }
+        }
+        let document = (self.template)(&blocks);
+        match state.take().error {
+            Some(error) => Err(error),
+            None => Ok(document),
+        }
+    }
+}
+
+// `fmt::Error` cannot say which block failed, so `render_to` only logs it.
+// `render_with`, `render_io` and `document` return the `LayoutError`.
+impl Render for Layout {
+    fn render_to(&self, w: &mut impl Write) -> std::fmt::Result {
+        let document = self.document().map_err(|error| {
+            warn!("{error}");
+            std::fmt::Error
+        })?;
+        document.render_to(w)
+    }
+
+    fn render_io(&self, w: &mut impl io::Write) -> io::Result<()> {
+        self.document().map_err(io::Error::other)?.render_io(w)
+    }
+
+    fn render_with(&self, config: &Config, w: &mut impl Write) -> Result<(), RenderError> {
+        self.document()
+            .map_err(RenderError::Layout)?
+            .render_with(config, w)
+    }
+}
+
+#[derive(Clone)]
+pub struct LayoutBuilder {
+    layout: Layout,
+}
+
+impl LayoutBuilder {
+    // Set the content of a block, overriding any earlier definition.
+    pub fn block(self, name: impl Into<String>, content: impl IntoChildren) -> Self {
+        let mut elements = vec![];
+        content.push_into(&mut elements);
+        self.block_with(name, move |_| elements.clone())
+    }
+
+    // Set the content of a block from the other blocks, such as wrapping
+    // a block that a page fills in, or the content it overrides.
+    pub fn block_with(
+        mut self,
+        name: impl Into<String>,
+        content: impl Fn(&Blocks) -> Vec<Element> + Send + Sync + 'static,
+    ) -> Self {
+        self.layout
+            .blocks
+            .entry(name.into())
+            .or_default()
+            .push(Arc::new(content));
+        self
+    }
+
+    // A block that must not be empty when the document is built.
+    pub fn required(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        if !self.layout.required.contains(&name) {
+            self.layout.required.push(name);
+        }
+        self
+    }
+
+    pub fn build(self) -> Layout {
+        self.layout
+    }
+}
+
+// The blocks of a layout, as seen by its template and block definitions.
+pub struct Blocks<'a> {
+    layout: &'a Layout,
+    // The block being built and the index of its definition.
+    current: Option<(&'a str, usize)>,
+    state: &'a RefCell<BuildState>,
+}
+
+// The block definitions being built, innermost last, and the first error.
+#[derive(Default)]
+struct BuildState {
+    building: Vec<(String, usize)>,
+    error: Option<LayoutError>,
+}
+
+impl<'a> Blocks<'a> {
+    // The content of a block, which is empty if it was never set. A block
+    // that reads itself is empty, and fails the document; use `parent` to
+    // include the definition it overrides.
+    pub fn get(&self, name: &str) -> Vec<Element> {
+        match self.layout.blocks.get_key_value(name) {
+            Some((name, definitions)) => self.build(name, definitions.len() - 1),
+            None => vec![],
+        }
+    }
+
+    // The text within a block, such as the page title.
+    pub fn text(&self, name: &str) -> String {
+        let mut text = String::new();
+        for element in self.get(name) {
+            push_text(&element, &mut text);
+        }
+        text
+    }
+
+    // Whether the block has content.
+    pub fn is_set(&self, name: &str) -> bool {
+        !self.get(name).is_empty()
+    }
+
+    // The content of the definition the current block overrides, which is
+    // empty in a template or the first definition.
+    pub fn parent(&self) -> Vec<Element> {
+        match self.current {
+            Some((name, index)) if index > 0 => self.build(name, index - 1),
+            _ => vec![],
+        }
+    }
+
+    fn build(&self, name: &'a str, index: usize) -> Vec<Element> {
+        {
+            let mut state = self.state.borrow_mut();
+            if state.building.iter().any(|(n, i)| n == name && *i == index) {
+                state
+                    .error
+                    .get_or_insert_with(|| LayoutError::RecursiveBlock(name.to_string()));
+                return vec![];
+            }
+            state.building.push((name.to_string(), index));
+        }
+        let blocks = Blocks {
+            layout: self.layout,
+            current: Some((name, index)),
+            state: self.state,
+        };
+        let elements = (self.layout.blocks[name][index])(&blocks);
+        self.state.borrow_mut().building.pop();
+        elements
+    }
+}
+
+fn push_text(element: &Element, text: &mut String) {
+    if matches!(element.tag, Tag::Comment | Tag::Raw) {
+        return;
+    }
+    if let Some(content) = &element.content {
+        text.push_str(content);
+    }
+    for child in element.children.iter().flatten() {
+        push_text(child, text);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayoutError {
+    // A required block is empty.
+    MissingBlock(String),
+    // A block reads itself, directly or through other blocks.
+    RecursiveBlock(String),
+}
+impl Display for LayoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayoutError::MissingBlock(name) => {
+                write!(f, "required layout block '{name}' is empty")
+            }
+            LayoutError::RecursiveBlock(name) => {
+                write!(f, "layout block '{name}' includes itself")
+            }
+        }
+    }
+}
+impl std::error::Error for LayoutError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn base() -> Layout {
+        Layout::new(|blocks| {
+            let head = blocks.get("head").into_iter().fold(
+                HeadBuilder::new().title(blocks.text("title")),
+                |head, element| head.element(element),
+            );
+            let main = ElementBuilder::new(Tag::Main)
+                .children(blocks.get("main"))
+                .build();
+            let footer = ElementBuilder::new(Tag::Footer)
+                .children(blocks.get("footer"))
+                .build();
+            Document::new()
+                .lang("en")
+                .head(head)
+                .body(vec![main, footer])
+                .build()
+        })
+        .required("title")
+        .required("main")
+        .block("footer", "Example Ltd")
+        .build()
+    }
+
+    #[test]
+    fn test_page_fills_blocks() {
+        let page = base()
+            .extend()
+            .block("title", "Home")
+            .block(
+                "main",
+                ElementBuilder::new(Tag::H1).content("Welcome").build(),
+            )
+            .build();
+
+        assert_eq!(
+            page.document().unwrap().to_string(),
+            concat!(
+                r#"<!DOCTYPE html><html lang="en"><head><title>Home</title></head>"#,
+                "<body><main><h1>Welcome</h1></main><footer>Example Ltd</footer></body></html>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_nested_layouts_override_blocks() {
+        // The docs layout puts a sidebar around a new `content` block, and
+        // adds to the footer of the base layout.
+        let docs = base()
+            .extend()
+            .block_with("main", |blocks| {
+                let nav = ElementBuilder::new(Tag::Nav).content("Contents").build();
+                let article = ElementBuilder::new(Tag::Article)
+                    .children(blocks.get("content"))
+                    .build();
+                vec![nav, article]
+            })
+            .block_with("footer", |blocks| {
+                let mut footer = blocks.parent();
+                footer.push(ElementBuilder::new(Tag::A).content(" Docs").build());
+                footer
+            })
+            .required("content")
+            .build();
+        let head = ElementBuilder::new(Tag::Link)
+            .attrs(Attrs::new().rel("stylesheet").href("/docs.css").build())
+            .build();
+        let page = docs
+            .extend()
+            .block("title", "Install")
+            .block("head", head)
+            .block(
+                "content",
+                ElementBuilder::new(Tag::P).content("Run it.").build(),
+            )
+            .build();
+
+        let mut out = String::new();
+        page.render_with(&Config::default(), &mut out).unwrap();
+        assert_eq!(
+            out,
+            concat!(
+                r#"<!DOCTYPE html><html lang="en"><head><title>Install</title>"#,
+                r#"<link rel="stylesheet" href="/docs.css"></head><body><main>"#,
+                "<nav>Contents</nav><article><p>Run it.</p></article></main>",
+                "<footer>Example Ltd<a> Docs</a></footer></body></html>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_missing_required_block() {
+        let page = base().extend().block("main", "Hi").build();
+        assert_eq!(
+            page.document().unwrap_err(),
+            LayoutError::MissingBlock("title".to_string())
+        );
+
+        let mut out = String::new();
+        let error = page.render_with(&Config::default(), &mut out).unwrap_err();
+        assert_eq!(error.to_string(), "required layout block 'title' is empty");
+        assert!(out.is_empty());
+        let error = page.render_io(&mut Vec::new()).unwrap_err();
+        assert_eq!(error.to_string(), "required layout block 'title' is empty");
+
+        // An empty block counts as missing.
+        let page = base()
+            .extend()
+            .block("main", "Hi")
+            .block("title", Vec::<Element>::new())
+            .build();
+        assert!(page.document().is_err());
+    }
+
+    #[test]
+    fn test_recursive_block() {
+        let page = base()
+            .extend()
+            .block("title", "Home")
+            .block_with("main", |blocks| blocks.get("aside"))
+            .block_with("aside", |blocks| blocks.get("main"))
+            .build();
+        assert_eq!(
+            page.document().unwrap_err(),
+            LayoutError::RecursiveBlock("main".to_string())
+        );
+
+        // Reading the overridden definition is not recursion.
+        let page = base()
+            .extend()
+            .block("title", "Home")
+            .block("main", "Hi")
+            .build()
+            .extend()
+            .block_with("main", |blocks| blocks.parent())
+            .build();
+        assert!(page.document().is_ok());
+    }
+}
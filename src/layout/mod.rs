@@ -0,0 +1,3 @@
+mod html;
+
+pub use html::{Blocks, Layout, LayoutBuilder, LayoutError};
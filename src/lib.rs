@@ -1,11 +1,40 @@
+// Lets `#[derive(HtmlForm)]` and `#[derive(FormValue)]` refer to `::htmlatron` within this crate.
+extern crate self as htmlatron;
+
 pub mod attribute;
 pub mod document;
 pub mod element;
+pub mod escape;
+pub mod form;
+#[cfg(feature = "htmx")]
+pub mod htmx;
+#[cfg(feature = "jsonml")]
+pub mod jsonml;
+pub mod layout;
+pub mod parse;
+pub mod render;
+#[cfg(feature = "serde")]
+pub mod serialize;
+pub mod style;
 pub mod tag;
+pub mod validate;
 
 pub mod prelude {
     pub use super::attribute::*;
     pub use super::document::*;
     pub use super::element::*;
+    pub use super::escape::*;
+    pub use super::form::*;
+    #[cfg(feature = "htmx")]
+    pub use super::htmx::*;
+    #[cfg(feature = "jsonml")]
+    pub use super::jsonml::*;
+    pub use super::layout::*;
+    pub use super::parse::*;
+    pub use super::render::*;
+    #[cfg(feature = "serde")]
+    pub use super::serialize::*;
+    pub use super::style::*;
     pub use super::tag::*;
+    pub use super::validate::*;
 }
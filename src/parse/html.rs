@@ -0,0 +1,977 @@
+use std::fmt::Display;
+
+use crate::attribute::{Attrs, AttrsBuilder, SvgAttr};
+use crate::document::{Doctype, Document};
+use crate::element::{Element, ElementBuilder};
+use crate::tag::{CustomElements, MathTag, SvgTag, Tag};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    // The input ended inside a tag or attribute.
+    UnexpectedEof,
+    // A character that is not valid at this position.
+    UnexpectedChar(char),
+    // A `<!--` without a matching `-->`.
+    UnterminatedComment,
+    // An opening tag without a closing tag.
+    UnclosedTag(String),
+    // A closing tag without an opening tag.
+    UnexpectedClosingTag(String),
+    // A closing tag that does not match the open tag.
+    MismatchedClosingTag { expected: String, found: String },
+    // A doctype this crate cannot represent.
+    UnsupportedDoctype(String),
+    // A doctype after the start of the document.
+    MisplacedDoctype,
+    // Elements nested deeper than `MAX_DEPTH`.
+    TooDeep,
+}
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::UnexpectedEof => write!(f, "unexpected end of input"),
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected character '{c}'"),
+            ParseErrorKind::UnterminatedComment => write!(f, "comment is not terminated"),
+            ParseErrorKind::UnclosedTag(tag) => write!(f, "tag '{tag}' is not closed"),
+            ParseErrorKind::UnexpectedClosingTag(tag) => {
+                write!(f, "closing tag '{tag}' has no opening tag")
+            }
+            ParseErrorKind::MismatchedClosingTag { expected, found } => {
+                write!(f, "expected closing tag '{expected}', found '{found}'")
+            }
+            ParseErrorKind::UnsupportedDoctype(doctype) => {
+                write!(f, "unsupported doctype '{doctype}'")
+            }
+            ParseErrorKind::MisplacedDoctype => {
+                write!(f, "doctype must be at the start of the document")
+            }
+            ParseErrorKind::TooDeep => {
+                write!(f, "elements are nested more than {MAX_DEPTH} deep")
+            }
+        }
+    }
+}
+
+// An error in the markup, with the 1-based line and column where it was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub line: usize,
+    pub column: usize,
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.kind, self.line, self.column
+        )
+    }
+}
+impl std::error::Error for ParseError {}
+
+// Parse a HTML document, with an optional leading doctype.
+pub fn parse(input: impl AsRef<str>) -> Result<Document, ParseError> {
+    parse_with(input, &CustomElements::default())
+}
+
+// Parse a HTML document, giving the custom elements found their
+// definitions, so validating it checks their attributes.
+pub fn parse_with(
+    input: impl AsRef<str>,
+    custom_elements: &CustomElements,
+) -> Result<Document, ParseError> {
+    let mut parser = Parser::new(input.as_ref(), custom_elements);
+    let doctype = parser.doctype()?;
+    let elements = parser.nodes(None)?;
+    Ok(Document {
+        doctype: doctype.unwrap_or_default(),
+        elements,
+    })
+}
+
+// Parse a HTML fragment into a list of elements.
+pub fn parse_fragment(input: impl AsRef<str>) -> Result<Vec<Element>, ParseError> {
+    parse_fragment_with(input, &CustomElements::default())
+}
+
+// Parse a HTML fragment, giving the custom elements found their definitions.
+pub fn parse_fragment_with(
+    input: impl AsRef<str>,
+    custom_elements: &CustomElements,
+) -> Result<Vec<Element>, ParseError> {
+    Parser::new(input.as_ref(), custom_elements).nodes(None)
+}
+
+// The deepest elements are nested, so markup cannot overflow the stack
+// of the recursive parser.
+const MAX_DEPTH: usize = 128;
+
+// An open tag, with the position of its `<`.
+struct OpenTag<'a> {
+    name: &'a str,
+    line: usize,
+    column: usize,
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    line: usize,
+    column: usize,
+    // The namespace of the element being parsed, which decides how tag
+    // names are read.
+    namespace: Namespace,
+    // How many elements are open.
+    depth: usize,
+    custom_elements: &'a CustomElements,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Namespace {
+    Html,
+    Svg,
+    MathMl,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str, custom_elements: &'a CustomElements) -> Self {
+        Parser {
+            input,
+            pos: 0,
+            line: 1,
+            column: 1,
+            namespace: Namespace::Html,
+            depth: 0,
+            custom_elements,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn advance(&mut self, bytes: usize) {
+        let end = self.pos + bytes;
+        while self.pos < end {
+            self.bump();
+        }
+    }
+
+    fn starts_with_ci(&self, prefix: &str) -> bool {
+        let rest = self.rest().as_bytes();
+        rest.len() >= prefix.len() && rest[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+    }
+
+    fn eat(&mut self, prefix: &str) -> bool {
+        if self.rest().starts_with(prefix) {
+            self.advance(prefix.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(found) if found == c => {
+                self.bump();
+                Ok(())
+            }
+            Some(found) => Err(self.error(ParseErrorKind::UnexpectedChar(found))),
+            None => Err(self.error(ParseErrorKind::UnexpectedEof)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.bump();
+        }
+    }
+
+    // Consume characters while the predicate holds.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&predicate) {
+            self.bump();
+        }
+        &self.input[start..self.pos]
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            kind,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn doctype(&mut self) -> Result<Option<Doctype>, ParseError> {
+        let (pos, line, column) = (self.pos, self.line, self.column);
+        self.skip_whitespace();
+        if !self.starts_with_ci("<!doctype") {
+            // Keep any leading whitespace as text.
+            (self.pos, self.line, self.column) = (pos, line, column);
+            return Ok(None);
+        }
+        let (line, column) = (self.line, self.column);
+        self.advance("<!doctype".len());
+        let value = self.take_while(|c| c != '>').trim();
+        self.expect('>')?;
+        let doctype = match public_id(value) {
+            Some(id) => Doctype::from_public_id(id),
+            None if value.eq_ignore_ascii_case("html") => Some(Doctype::Html),
+            None => None,
+        };
+        match doctype {
+            Some(doctype) => Ok(Some(doctype)),
+            None => Err(ParseError {
+                kind: ParseErrorKind::UnsupportedDoctype(value.to_string()),
+                line,
+                column,
+            }),
+        }
+    }
+
+    // Parse nodes until the closing tag of `parent`, or the end of input.
+    fn nodes(&mut self, parent: Option<&OpenTag>) -> Result<Vec<Element>, ParseError> {
+        let mut nodes = vec![];
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return match parent {
+                    Some(parent) if optional_end_tag(parent.name) => Ok(nodes),
+                    Some(parent) => Err(ParseError {
+                        kind: ParseErrorKind::UnclosedTag(parent.name.to_string()),
+                        line: parent.line,
+                        column: parent.column,
+                    }),
+                    None => Ok(nodes),
+                };
+            } else if !starts_markup(rest) {
+                // Text runs up to the next markup, taking in any `<` that
+                // starts none, as in `1 < 2`.
+                let start = self.pos;
+                self.bump();
+                while !self.rest().is_empty() && !starts_markup(self.rest()) {
+                    self.bump();
+                    self.take_while(|c| c != '<');
+                }
+                let text = &self.input[start..self.pos];
+                nodes.push(ElementBuilder::new(Tag::Text).content(decode(text)).build());
+            } else if rest.starts_with("</") {
+                let (pos, line, column) = (self.pos, self.line, self.column);
+                self.advance(2);
+                let name = self.tag_name()?.to_ascii_lowercase();
+                self.skip_whitespace();
+                self.expect('>')?;
+                let kind = match parent {
+                    Some(parent) if parent.name == name => return Ok(nodes),
+                    // Leave the closing tag to the element it belongs to.
+                    Some(parent) if optional_end_tag(parent.name) => {
+                        (self.pos, self.line, self.column) = (pos, line, column);
+                        return Ok(nodes);
+                    }
+                    Some(parent) => ParseErrorKind::MismatchedClosingTag {
+                        expected: parent.name.to_string(),
+                        found: name,
+                    },
+                    None => ParseErrorKind::UnexpectedClosingTag(name),
+                };
+                return Err(ParseError { kind, line, column });
+            } else if rest.starts_with("<!--") {
+                nodes.push(self.comment()?);
+            } else if self.starts_with_ci("<!doctype") {
+                return Err(self.error(ParseErrorKind::MisplacedDoctype));
+            } else if let Some(tag) = rest.strip_prefix('<') {
+                let next = tag
+                    .split(|c: char| !c.is_ascii_alphanumeric())
+                    .next()
+                    .unwrap_or_default();
+                if parent.is_some_and(|parent| {
+                    self.namespace == Namespace::Html && closed_by(parent.name, next)
+                }) {
+                    return Ok(nodes);
+                }
+                nodes.push(self.element()?);
+            }
+        }
+    }
+
+    fn comment(&mut self) -> Result<Element, ParseError> {
+        let error = self.error(ParseErrorKind::UnterminatedComment);
+        self.advance("<!--".len());
+        let Some(end) = self.rest().find("-->") else {
+            return Err(error);
+        };
+        let text = &self.rest()[..end];
+        self.advance(end + "-->".len());
+        // The renderer pads the comment text with a space on each side.
+        let text = text.strip_prefix(' ').unwrap_or(text);
+        let text = text.strip_suffix(' ').unwrap_or(text);
+        Ok(ElementBuilder::new(Tag::Comment).text(text).build())
+    }
+
+    fn tag_name(&mut self) -> Result<&'a str, ParseError> {
+        let name = self.take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | ':' | '_'));
+        match (name.chars().next(), self.peek()) {
+            (Some(first), _) if first.is_ascii_alphabetic() => Ok(name),
+            (_, Some(c)) => Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+            (_, None) => Err(self.error(ParseErrorKind::UnexpectedEof)),
+        }
+    }
+
+    fn element(&mut self) -> Result<Element, ParseError> {
+        let (line, column) = (self.line, self.column);
+        self.expect('<')?;
+        let name = self.tag_name()?.to_ascii_lowercase();
+        let tag = match self.namespace {
+            Namespace::Html => name.parse::<Tag>().ok(),
+            Namespace::Svg => name.parse::<SvgTag>().map(Tag::Svg).ok(),
+            Namespace::MathMl => name.parse::<MathTag>().map(Tag::Math).ok(),
+        }
+        .or_else(|| Tag::unknown(name.as_str()).ok())
+        .expect("tag names are read as valid names");
+        let tag = self.custom_elements.resolve(&tag).unwrap_or(tag);
+        let mut element = ElementBuilder::new(tag.clone());
+        if let Some(attrs) = self.attributes(&tag)? {
+            element = element.attrs(attrs);
+        }
+        if self.eat("/>") || {
+            self.expect('>')?;
+            Element::is_void(&tag)
+        } {
+            return Ok(element.build());
+        }
+
+        let open = OpenTag {
+            name: &name,
+            line,
+            column,
+        };
+        if self.depth == MAX_DEPTH {
+            return Err(ParseError {
+                kind: ParseErrorKind::TooDeep,
+                line,
+                column,
+            });
+        }
+        let escapable =
+            self.namespace == Namespace::Html && matches!(name.as_str(), "title" | "textarea");
+        if escapable || matches!(name.as_str(), "script" | "style") {
+            // Raw text runs up to the closing tag, with entities decoded
+            // only in escapable raw text, such as titles.
+            let close = format!("</{name}");
+            let end = self
+                .rest()
+                .as_bytes()
+                .windows(close.len())
+                .position(|window| window.eq_ignore_ascii_case(close.as_bytes()));
+            let Some(end) = end else {
+                return Err(ParseError {
+                    kind: ParseErrorKind::UnclosedTag(name.clone()),
+                    line,
+                    column,
+                });
+            };
+            let content = &self.rest()[..end];
+            self.advance(end);
+            if !content.is_empty() {
+                element = element.content(match escapable {
+                    true => decode(content),
+                    false => content.to_string(),
+                });
+            }
+            self.nodes(Some(&open))?;
+            return Ok(element.build());
+        }
+
+        let namespace = self.namespace;
+        self.namespace = match &tag {
+            Tag::Svg(tag) if !tag.is_html_integration_point() => Namespace::Svg,
+            Tag::Math(tag) if !tag.is_html_integration_point() => Namespace::MathMl,
+            _ => Namespace::Html,
+        };
+        self.depth += 1;
+        let nodes = self.nodes(Some(&open));
+        self.depth -= 1;
+        self.namespace = namespace;
+        let mut nodes = nodes?.into_iter().peekable();
+        // Leading text is the element content, everything after it the children.
+        if let Some(text) = nodes.next_if(|node| node.tag == Tag::Text) {
+            element.content = text.content;
+        }
+        let children: Vec<Element> = nodes.collect();
+        if !children.is_empty() {
+            element = element.children(children);
+        }
+        Ok(element.build())
+    }
+
+    fn attributes(&mut self, tag: &Tag) -> Result<Option<Attrs>, ParseError> {
+        let mut attrs = Attrs::new();
+        let mut names: Vec<String> = vec![];
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Err(self.error(ParseErrorKind::UnexpectedEof)),
+                Some('>') => break,
+                Some('/') if self.rest().starts_with("/>") => break,
+                Some(_) => {}
+            }
+            let name = self.take_while(|c| {
+                !c.is_ascii_whitespace() && !matches!(c, '/' | '>' | '=' | '"' | '\'' | '<')
+            });
+            if name.is_empty() {
+                let c = self.peek().unwrap_or_default();
+                return Err(self.error(ParseErrorKind::UnexpectedChar(c)));
+            }
+            self.skip_whitespace();
+            let value = if self.eat("=") {
+                self.skip_whitespace();
+                Some(self.attribute_value()?)
+            } else {
+                None
+            };
+            // The first of a repeated attribute wins, as in browsers.
+            if names.iter().any(|found| found.eq_ignore_ascii_case(name)) {
+                continue;
+            }
+            names.push(name.to_string());
+            attrs = set_attr(attrs, tag, name, value);
+        }
+        Ok(if names.is_empty() { None } else { Some(attrs.build()) })
+    }
+
+    fn attribute_value(&mut self) -> Result<String, ParseError> {
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.bump();
+                let value = self.take_while(|c| c != quote);
+                self.expect(quote)?;
+                value
+            }
+            Some(_) => {
+                let value =
+                    self.take_while(|c| !c.is_ascii_whitespace() && !matches!(c, '>' | '"' | '\''));
+                if value.is_empty() {
+                    let c = self.peek().unwrap_or_default();
+                    return Err(self.error(ParseErrorKind::UnexpectedChar(c)));
+                }
+                value
+            }
+            None => return Err(self.error(ParseErrorKind::UnexpectedEof)),
+        };
+        Ok(decode(value))
+    }
+}
+
+// Whether the input starts with a tag, closing tag, comment or doctype,
+// rather than a `<` that is text.
+fn starts_markup(rest: &str) -> bool {
+    let mut chars = rest.strip_prefix('<').unwrap_or_default().chars();
+    match chars.next() {
+        Some('!') => true,
+        Some('/') => chars.next().is_some_and(|c| c.is_ascii_alphabetic()),
+        Some(c) => c.is_ascii_alphabetic(),
+        None => false,
+    }
+}
+
+// Elements whose closing tag may be left out, as in `<li>one<li>two`. They
+// are closed by the closing tag of their parent or the end of the input.
+fn optional_end_tag(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "li" | "dt" | "dd" | "tr" | "td" | "th" | "option" | "optgroup"
+    )
+}
+
+// Whether the opening tag `next` closes the open element `name`, which
+// leaves out its closing tag.
+fn closed_by(name: &str, next: &str) -> bool {
+    let next = next.to_ascii_lowercase();
+    match name {
+        "p" => matches!(
+            next.as_str(),
+            "address"
+                | "article"
+                | "aside"
+                | "blockquote"
+                | "details"
+                | "dialog"
+                | "div"
+                | "dl"
+                | "fieldset"
+                | "figcaption"
+                | "figure"
+                | "footer"
+                | "form"
+                | "h1"
+                | "h2"
+                | "h3"
+                | "h4"
+                | "h5"
+                | "h6"
+                | "header"
+                | "hgroup"
+                | "hr"
+                | "main"
+                | "menu"
+                | "nav"
+                | "ol"
+                | "p"
+                | "pre"
+                | "search"
+                | "section"
+                | "table"
+                | "ul"
+        ),
+        "li" => next == "li",
+        "dt" | "dd" => matches!(next.as_str(), "dt" | "dd"),
+        "tr" => next == "tr",
+        "td" | "th" => matches!(next.as_str(), "td" | "th" | "tr"),
+        "option" => matches!(next.as_str(), "option" | "optgroup"),
+        "optgroup" => next == "optgroup",
+        _ => false,
+    }
+}
+
+// The quoted public identifier in `html PUBLIC "id" "url"`.
+fn public_id(doctype: &str) -> Option<&str> {
+    let rest = strip_prefix_ci(doctype, "html")?.trim_start();
+    let rest = strip_prefix_ci(rest, "public")?.trim_start();
+    let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let rest = &rest[1..];
+    rest.find(quote).map(|end| &rest[..end])
+}
+
+fn strip_prefix_ci<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = text.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &text[prefix.len()..])
+}
+
+// Attribute names are not case-sensitive in HTML, so they are kept lowercase.
+// SVG attributes get their SVG casing back, such as `viewBox`.
+fn set_attr(attrs: AttrsBuilder, tag: &Tag, name: &str, value: Option<String>) -> AttrsBuilder {
+    match (tag, name.parse::<SvgAttr>()) {
+        (Tag::Svg(_), Ok(attr)) => attrs.other(attr.to_string(), value),
+        _ => attrs.other(name.to_ascii_lowercase(), value),
+    }
+}
+
+// The longest character reference looked for after a `&`, so text with
+// many `&`s is not scanned to the end for a `;` each time.
+const MAX_REFERENCE: usize = 32;
+
+// Replace character references with the characters they stand for.
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let len = rest.as_bytes()[1..]
+            .iter()
+            .take(MAX_REFERENCE)
+            .take_while(|b| b.is_ascii_alphanumeric() || **b == b'#')
+            .count();
+        let reference = rest[1 + len..].starts_with(';').then(|| &rest[1..1 + len]);
+        match reference.and_then(decode_reference) {
+            Some(c) => {
+                decoded.push(c);
+                rest = &rest[reference.map_or(0, str::len) + 2..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_reference(reference: &str) -> Option<char> {
+    if let Some(hex) = reference
+        .strip_prefix("#x")
+        .or_else(|| reference.strip_prefix("#X"))
+    {
+        return u32::from_str_radix(hex, 16).ok().and_then(char::from_u32);
+    }
+    if let Some(decimal) = reference.strip_prefix('#') {
+        return decimal.parse().ok().and_then(char::from_u32);
+    }
+    let c = match reference {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "hellip" => '…',
+        "mdash" => '—',
+        "ndash" => '–',
+        "laquo" => '«',
+        "raquo" => '»',
+        _ => return None,
+    };
+    Some(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_parse_round_trip() {
+        let doc = Document {
+            doctype: Doctype::Html,
+            elements: vec![ElementBuilder::new(Tag::Html)
+                .children(vec![
+                    ElementBuilder::new(Tag::Head)
+                        .children(vec![
+                            ElementBuilder::new(Tag::Meta)
+                                .attrs(Attrs::new().charset("utf-8").build())
+                                .build(),
+                            ElementBuilder::new(Tag::Title)
+                                .content("Tom & Jerry")
+                                .build(),
+                            ElementBuilder::new(Tag::Script)
+                                .attrs(Attrs::new().src("/app.js").defer().build())
+                                .build(),
+                        ])
+                        .build(),
+                    ElementBuilder::new(Tag::Body)
+                        .children(vec![
+                            ElementBuilder::new(Tag::Comment).text("navigation").build(),
+                            ElementBuilder::new(Tag::Div)
+                                .attrs(
+                                    Attrs::new()
+                                        .id("main")
+                                        .class(vec!["card", "wide"])
+                                        .data("user", "1")
+                                        .build(),
+                                )
+                                .content("Hello <world>")
+                                .children(vec![
+                                    ElementBuilder::new(Tag::Br).build(),
+                                    ElementBuilder::new(Tag::A)
+                                        .attrs(Attrs::new().href("/a?b=1&c=2").build())
+                                        .content("link")
+                                        .build(),
+                                    ElementBuilder::new(Tag::Img)
+                                        .attrs(Attrs::new().alt(r#"say "hi""#).src("x.png").build())
+                                        .build(),
+                                ])
+                                .build(),
+                        ])
+                        .build(),
+                ])
+                .build()],
+        };
+
+        let html = doc.to_string();
+        assert_eq!(parse(&html).unwrap().to_string(), html);
+    }
+
+    #[test]
+    fn test_parse_mixed_content() {
+        let html = "<p>Hello <b>bold</b> and <i>italic</i>!</p>";
+        let elements = parse_fragment(html).unwrap();
+        let p = &elements[0];
+
+        assert_eq!(p.tag, Tag::P);
+        assert_eq!(p.content.as_deref(), Some("Hello "));
+        let children = p.children.as_ref().unwrap();
+        assert_eq!(children.len(), 4);
+        assert_eq!(children[1].tag, Tag::Text);
+        assert_eq!(children[1].content.as_deref(), Some(" and "));
+        assert_eq!(p.to_string(), html);
+    }
+
+    #[test]
+    fn test_parse_attributes() {
+        let elements =
+            parse_fragment(r#"<a HREF='/x' target=_blank data-id="7" role="link" download>x</a>"#)
+                .unwrap();
+        let attrs = elements[0].attrs.as_ref().unwrap();
+
+        assert_eq!(attrs.get("href"), Some("/x"));
+        assert_eq!(attrs.get("target"), Some("_blank"));
+        assert_eq!(attrs.get("data-id"), Some("7"));
+        assert_eq!(attrs.len(), 5);
+        assert_eq!(
+            elements[0].to_string(),
+            r#"<a href="/x" target="_blank" data-id="7" role="link" download>x</a>"#
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_tag_and_void_elements() {
+        let elements = parse_fragment("<my-widget><br/><img src=a.png></my-widget>").unwrap();
+
+        assert_eq!(elements[0].tag, Tag::custom("my-widget").unwrap());
+        let children = elements[0].children.as_ref().unwrap();
+        assert_eq!(children[0].tag, Tag::Br);
+        assert_eq!(children[1].tag, Tag::Img);
+
+        let elements = parse_fragment("<blink>old</blink>").unwrap();
+        assert_eq!(elements[0].tag, Tag::unknown("blink").unwrap());
+    }
+
+    #[test]
+    fn test_parse_entities() {
+        let elements =
+            parse_fragment("<p>&lt;b&gt; &amp; &#65;&#x42; &unknown; & done</p>").unwrap();
+
+        assert_eq!(
+            elements[0].content.as_deref(),
+            Some("<b> & AB &unknown; & done")
+        );
+
+        // A `;` far after a `&` does not make a reference.
+        let text = format!("a &{} b;", "x".repeat(40));
+        assert_eq!(decode(&text), text);
+        assert_eq!(decode("&amp&lt;"), "&amp<");
+    }
+
+    #[test]
+    fn test_parse_implied_end_tags() {
+        let elements = parse_fragment(concat!(
+            "<div><p>a<p>b<ul><li>one<li>two</ul></div>",
+            "<table><tr><td>1<td>2<tr><th>3</table>",
+            "<select><option>x<option>y</select><p>end"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            elements.iter().map(|e| e.to_string()).collect::<String>(),
+            concat!(
+                "<div><p>a</p><p>b</p><ul><li>one</li><li>two</li></ul></div>",
+                "<table><tr><td>1</td><td>2</td></tr><tr><th>3</th></tr></table>",
+                "<select><option>x</option><option>y</option></select><p>end</p>"
+            )
+        );
+        // Other closing tags are still required.
+        let error = parse_fragment("<div><b>a</div>").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::MismatchedClosingTag {
+                expected: "b".to_string(),
+                found: "div".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_script_is_raw_text() {
+        let elements = parse_fragment("<script>if (a < b && c) {}</script>").unwrap();
+
+        assert_eq!(elements[0].content.as_deref(), Some("if (a < b && c) {}"));
+
+        // Titles and text areas are raw text with entities.
+        let elements =
+            parse_fragment("<title>a < b &amp; <i>c</i></title><textarea><p></textarea>").unwrap();
+        assert_eq!(elements[0].content.as_deref(), Some("a < b & <i>c</i>"));
+        assert_eq!(elements[1].content.as_deref(), Some("<p>"));
+    }
+
+    #[test]
+    fn test_parse_bare_less_than() {
+        let elements = parse_fragment("<p>1 < 2 <3 and <b>4</b> <</p>").unwrap();
+
+        assert_eq!(elements[0].content.as_deref(), Some("1 < 2 <3 and "));
+        assert_eq!(
+            elements[0].to_string(),
+            "<p>1 &lt; 2 &lt;3 and <b>4</b> &lt;</p>"
+        );
+    }
+
+    #[test]
+    fn test_parse_repeated_attribute() {
+        let elements = parse_fragment(r#"<a href="/a" HREF="/b" id=x href="/c">a</a>"#).unwrap();
+        let attrs = elements[0].attrs.as_ref().unwrap();
+
+        assert_eq!(attrs.get("href"), Some("/a"));
+        assert_eq!(attrs.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_depth() {
+        // Parsing recurses once per element, so it is given the stack of a
+        // main thread rather than the smaller one tests run on.
+        let test = || {
+            let deep =
+                |depth: usize| format!("{}{}", "<div>".repeat(depth), "</div>".repeat(depth));
+            assert!(parse_fragment(deep(MAX_DEPTH)).is_ok());
+
+            let error = parse_fragment(deep(MAX_DEPTH + 1)).unwrap_err();
+            assert_eq!(error.kind, ParseErrorKind::TooDeep);
+            assert_eq!((error.line, error.column), (1, 5 * MAX_DEPTH + 1));
+            // Without closing tags, the error comes before the stack runs out.
+            let error = parse_fragment("<div>".repeat(200_000)).unwrap_err();
+            assert_eq!(error.kind, ParseErrorKind::TooDeep);
+        };
+        std::thread::Builder::new()
+            .stack_size(8 << 20)
+            .spawn(test)
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_parse_doctype_and_comment() {
+        let doc = parse("\n<!doctype HTML>\n<!-- hi --><html></html>").unwrap();
+
+        assert_eq!(doc.doctype, Doctype::Html);
+        assert_eq!(doc.elements[0].tag, Tag::Text);
+        assert_eq!(doc.elements[1].tag, Tag::Comment);
+        assert_eq!(doc.elements[1].text.as_deref(), Some("hi"));
+        assert_eq!(doc.elements[2].tag, Tag::Html);
+    }
+
+    #[test]
+    fn test_parse_svg() {
+        let input = concat!(
+            r#"<p><svg viewbox="0 0 10 10" class="icon"><linearGradient id="g">"#,
+            r#"<stop offset="0" stop-color="red"></stop></linearGradient>"#,
+            r#"<rect width="10" height="10" fill="url(#g)"/></svg></p>"#
+        );
+        let elements = parse_fragment(input).unwrap();
+        let svg = &elements[0].children.as_ref().unwrap()[0];
+        let children = svg.children.as_deref().unwrap();
+
+        assert_eq!(svg.tag, Tag::Svg(SvgTag::Svg));
+        assert_eq!(children[0].tag, Tag::Svg(SvgTag::LinearGradient));
+        assert_eq!(children[1].tag, Tag::Svg(SvgTag::Rect));
+        assert_eq!(
+            svg.attrs.as_ref().unwrap().get("viewBox"),
+            Some("0 0 10 10")
+        );
+        assert_eq!(
+            elements[0].to_string(),
+            concat!(
+                r#"<p><svg viewBox="0 0 10 10" class="icon"><linearGradient id="g">"#,
+                r#"<stop offset="0" stop-color="red"></stop></linearGradient>"#,
+                r#"<rect width="10" height="10" fill="url(#g)"></rect></svg></p>"#
+            )
+        );
+
+        // Outside of `svg`, and within `foreignObject`, names are HTML again.
+        let elements =
+            parse_fragment("<svg><foreignObject><p>Hi</p></foreignObject></svg><rect></rect>")
+                .unwrap();
+        let object = &elements[0].children.as_ref().unwrap()[0];
+        assert_eq!(object.children.as_ref().unwrap()[0].tag, Tag::P);
+        assert_eq!(elements[1].tag, Tag::unknown("rect").unwrap());
+    }
+
+    #[test]
+    fn test_parse_math() {
+        let input = r#"<p><math display="block"><msup><mi>x</mi><mn>2</mn></msup></math></p>"#;
+        let elements = parse_fragment(input).unwrap();
+        let math = &elements[0].children.as_ref().unwrap()[0];
+        let msup = &math.children.as_ref().unwrap()[0];
+
+        assert_eq!(math.tag, Tag::Math(MathTag::Math));
+        assert_eq!(msup.tag, Tag::Math(MathTag::Msup));
+        assert_eq!(
+            msup.children.as_ref().unwrap()[0].tag,
+            Tag::Math(MathTag::Mi)
+        );
+        assert_eq!(elements[0].to_string(), input);
+        assert_eq!(elements[0].validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_parse_legacy_doctypes() {
+        for doctype in Doctype::ALL {
+            let doc = parse(format!("<!DOCTYPE {doctype}><html></html>")).unwrap();
+            assert_eq!(doc.doctype, doctype);
+        }
+        // The system identifier is optional.
+        let doc = parse(r#"<!doctype html public '-//w3c//dtd html 4.01//en'>"#).unwrap();
+        assert_eq!(doc.doctype, Doctype::Html401Strict);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_fragment("<div>\n  <b>text</div>").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                kind: ParseErrorKind::MismatchedClosingTag {
+                    expected: "b".to_string(),
+                    found: "div".to_string()
+                },
+                line: 2,
+                column: 10,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "expected closing tag 'b', found 'div' at line 2, column 10"
+        );
+
+        let error = parse_fragment("<ul>\n<li>one</li>").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::UnclosedTag("ul".to_string()));
+        assert_eq!((error.line, error.column), (1, 1));
+
+        let error = parse_fragment("</p>").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::UnexpectedClosingTag("p".to_string())
+        );
+
+        let error = parse_fragment("text <!-- open").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::UnterminatedComment);
+        assert_eq!((error.line, error.column), (1, 6));
+
+        let error = parse_fragment("<a href=\"x").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::UnexpectedEof);
+
+        let error = parse_fragment("<p =x>1</p>").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::UnexpectedChar('='));
+
+        let error = parse("<!DOCTYPE html PUBLIC>").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::UnsupportedDoctype("html PUBLIC".to_string())
+        );
+
+        let error =
+            parse(r#"<!DOCTYPE html PUBLIC "-//W3C//DTD HTML 3.2 Final//EN">"#).unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::UnsupportedDoctype(
+                r#"html PUBLIC "-//W3C//DTD HTML 3.2 Final//EN""#.to_string()
+            )
+        );
+
+        let error = parse("<p></p><!DOCTYPE html>").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::MisplacedDoctype);
+    }
+}
@@ -0,0 +1,5 @@
+mod html;
+
+pub use html::{
+    parse, parse_fragment, parse_fragment_with, parse_with, ParseError, ParseErrorKind,
+};
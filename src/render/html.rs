@@ -0,0 +1,739 @@
+use std::fmt::{self, Display, Write};
+use std::io;
+
+use log::warn;
+
+use crate::document::Document;
+use crate::element::Element;
+use crate::escape::{Context, Escaped};
+use crate::layout::LayoutError;
+use crate::tag::{MathTag, Tag};
+use crate::validate::ValidationError;
+
+// Write HTML straight into an output buffer.
+//
+// Every node in a tree writes into the same sink, so rendering a page
+// does not allocate a `String` per element. `Display` is built on top of it.
+pub trait Render {
+    // Write the HTML into a `fmt::Write` sink such as a `String`.
+    fn render_to(&self, w: &mut impl Write) -> fmt::Result;
+
+    // Write the HTML using the rendering options in `config`.
+    fn render_with(&self, _config: &Config, w: &mut impl Write) -> Result<(), RenderError> {
+        Ok(self.render_to(w)?)
+    }
+
+    // Write the HTML into an `io::Write` sink such as a file or socket.
+    // Wrap unbuffered sinks in a `BufWriter`, as the output is written in small pieces.
+    fn render_io(&self, w: &mut impl io::Write) -> io::Result<()> {
+        let mut writer = IoWriter {
+            inner: w,
+            error: None,
+        };
+        self.render_to(&mut writer).map_err(|_| {
+            writer
+                .error
+                .unwrap_or_else(|| io::Error::other("failed to render HTML"))
+        })
+    }
+}
+
+// Rendering options.
+#[derive(Debug, Clone)]
+pub struct Config {
+    // Fail with the validation errors instead of dropping invalid attributes.
+    pub strict: bool,
+    // Put block elements on their own lines, indented by depth.
+    pub pretty: bool,
+    // Number of indent characters per level when pretty printing.
+    pub indent: usize,
+    pub indent_style: IndentStyle,
+    // Elements that fit within this many columns stay on one line.
+    pub max_width: usize,
+    // Write XML, as used by XHTML: void elements close themselves, boolean
+    // attributes get a value and the `html` element gets the XHTML namespace.
+    pub xml: bool,
+}
+impl Default for Config {
+    fn default() -> Self {
+        ConfigBuilder::new().build()
+    }
+}
+impl Config {
+    pub fn new() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+    // The options used by `{:#}` formatting.
+    pub fn pretty() -> Config {
+        ConfigBuilder::new().pretty(true).build()
+    }
+    // The options for XML serialization.
+    pub fn xml() -> Config {
+        ConfigBuilder::new().xml(true).build()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndentStyle {
+    Spaces,
+    Tabs,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigBuilder {
+    pub strict: bool,
+    pub pretty: bool,
+    pub indent: usize,
+    pub indent_style: IndentStyle,
+    pub max_width: usize,
+    pub xml: bool,
+}
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        ConfigBuilder {
+            strict: false,
+            pretty: false,
+            indent: 2,
+            indent_style: IndentStyle::Spaces,
+            max_width: 80,
+            xml: false,
+        }
+    }
+
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    pub fn indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    pub fn xml(mut self, xml: bool) -> Self {
+        self.xml = xml;
+        self
+    }
+
+    pub fn build(self) -> Config {
+        Config {
+            strict: self.strict,
+            pretty: self.pretty,
+            indent: self.indent,
+            indent_style: self.indent_style,
+            max_width: self.max_width,
+            xml: self.xml,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RenderError {
+    // The output sink returned an error.
+    Fmt(fmt::Error),
+    // Strict rendering found invalid markup.
+    Invalid(Vec<ValidationError>),
+    // A layout could not build its document.
+    Layout(LayoutError),
+}
+impl Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderError::Fmt(_) => write!(f, "failed to write HTML"),
+            RenderError::Invalid(errors) => {
+                write!(f, "invalid HTML:")?;
+                for error in errors {
+                    write!(f, "\n  {error}")?;
+                }
+                Ok(())
+            }
+            RenderError::Layout(error) => write!(f, "{error}"),
+        }
+    }
+}
+impl std::error::Error for RenderError {}
+
+impl From<fmt::Error> for RenderError {
+    fn from(error: fmt::Error) -> Self {
+        RenderError::Fmt(error)
+    }
+}
+
+const XHTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+// Writes elements and documents according to a `Config`.
+pub(crate) struct Printer<'a, W: Write> {
+    w: &'a mut W,
+    config: &'a Config,
+}
+
+impl<'a, W: Write> Printer<'a, W> {
+    pub(crate) fn new(config: &'a Config, w: &'a mut W) -> Self {
+        Printer { w, config }
+    }
+
+    pub(crate) fn document(&mut self, document: &Document) -> fmt::Result {
+        write!(self.w, "<{} {}>", Tag::Doctype, document.doctype)?;
+        for element in &document.elements {
+            if self.config.pretty {
+                if is_blank(element) {
+                    continue;
+                }
+                self.w.write_char('\n')?;
+            }
+            self.element(element, 0)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn element(&mut self, element: &Element, depth: usize) -> fmt::Result {
+        if !self.config.pretty || self.fits_on_line(element, depth) {
+            return self.compact(element);
+        }
+
+        self.open_tag(element)?;
+        // Runs of text and inline elements share a line, block elements get their own.
+        let mut run: Vec<Inline> = vec![];
+        if let Some(content) = element.content.as_ref().filter(|c| !c.trim().is_empty()) {
+            run.push(Inline::Content(
+                content,
+                Element::content_context(&element.tag),
+            ));
+        }
+        for child in element.children.iter().flatten() {
+            if is_blank(child) {
+                continue;
+            }
+            if is_inline(child) {
+                run.push(Inline::Node(child));
+                continue;
+            }
+            self.inline_run(&mut run, depth + 1)?;
+            self.newline(depth + 1)?;
+            self.element(child, depth + 1)?;
+        }
+        self.inline_run(&mut run, depth + 1)?;
+        self.newline(depth)?;
+        self.close_tag(element)
+    }
+
+    // Write the element and its children without any added whitespace.
+    fn compact(&mut self, element: &Element) -> fmt::Result {
+        match element.tag {
+            Tag::Raw => {
+                return self
+                    .w
+                    .write_str(element.content.as_deref().unwrap_or_default())
+            }
+            Tag::Text => {
+                let text = element.content.as_deref().unwrap_or_default();
+                return write!(self.w, "{}", Escaped(text, Context::Text));
+            }
+            _ => {}
+        }
+        self.open_tag(element)?;
+        if let Some(content) = &element.content {
+            let context = Element::content_context(&element.tag);
+            write!(self.w, "{}", Escaped(content, context))?;
+        }
+        for child in element.children.iter().flatten() {
+            self.compact(child)?;
+        }
+        self.close_tag(element)
+    }
+
+    fn open_tag(&mut self, element: &Element) -> fmt::Result {
+        let tag = &element.tag;
+        let attributes = element
+            .attrs
+            .as_ref()
+            .map_or(vec![], |attrs| attrs.supported(tag));
+
+        // Opening tag, with the attributes or the text within it.
+        match tag {
+            Tag::Comment => self.w.write_str("<!-- ")?,
+            _ => write!(self.w, "<{tag}")?,
+        }
+        let xml = self.config.xml;
+        if xml && *tag == Tag::Html && !attributes.iter().any(|attr| attr.name() == "xmlns") {
+            write!(self.w, r#" xmlns="{XHTML_NAMESPACE}""#)?;
+        }
+        if !attributes.is_empty() {
+            for (i, attr) in attributes.into_iter().enumerate() {
+                let separator = if i == 0 && *tag == Tag::Comment { "" } else { " " };
+                let attr = if xml { attr.expanded() } else { attr };
+                write!(self.w, "{separator}{attr}")?;
+            }
+        } else if let Some(text) = &element.text {
+            // Only comments and doctypes have text within the tag. Elsewhere
+            // it would be read as attributes, so it is left out.
+            match tag {
+                Tag::Comment => write!(self.w, "{}", Escaped(text, Context::Comment))?,
+                Tag::Doctype => write!(self.w, " {}", Escaped(text, Context::Attribute))?,
+                _ => warn!("HTML tag '{tag}' cannot have text within its opening tag"),
+            }
+        }
+        match tag {
+            Tag::Comment => self.w.write_str(" -->"),
+            _ if self.config.xml && Element::is_void(tag) => self.w.write_str(" />"),
+            _ => self.w.write_char('>'),
+        }
+    }
+
+    fn close_tag(&mut self, element: &Element) -> fmt::Result {
+        match Element::is_void(&element.tag) {
+            true => Ok(()),
+            false => write!(self.w, "</{}>", element.tag),
+        }
+    }
+
+    // Write a run of inline nodes on its own line, trimming the whitespace around it.
+    fn inline_run(&mut self, run: &mut Vec<Inline>, depth: usize) -> fmt::Result {
+        let last = run.len().saturating_sub(1);
+        for (i, inline) in run.iter().enumerate() {
+            if i == 0 {
+                self.newline(depth)?;
+            }
+            match inline {
+                Inline::Content(text, context) => write!(
+                    self.w,
+                    "{}",
+                    Escaped(trim(text, i == 0, i == last), *context)
+                )?,
+                Inline::Node(element) if element.tag == Tag::Text => {
+                    let text = element.content.as_deref().unwrap_or_default();
+                    write!(
+                        self.w,
+                        "{}",
+                        Escaped(trim(text, i == 0, i == last), Context::Text)
+                    )?
+                }
+                Inline::Node(element) => self.compact(element)?,
+            }
+        }
+        run.clear();
+        Ok(())
+    }
+
+    fn newline(&mut self, depth: usize) -> fmt::Result {
+        self.w.write_char('\n')?;
+        let (indent, width) = match self.config.indent_style {
+            IndentStyle::Spaces => (' ', self.config.indent),
+            IndentStyle::Tabs => ('\t', 1),
+        };
+        for _ in 0..depth * width {
+            self.w.write_char(indent)?;
+        }
+        Ok(())
+    }
+
+    // Elements stay on one line when they are inline, verbatim, or short
+    // enough and without block children.
+    fn fits_on_line(&self, element: &Element, depth: usize) -> bool {
+        let children = element.children.as_deref().unwrap_or_default();
+        if is_inline(element) || is_verbatim(&element.tag) || children.is_empty() {
+            return true;
+        }
+        if !children.iter().all(is_inline) {
+            return false;
+        }
+        let limit = self
+            .config
+            .max_width
+            .saturating_sub(depth * self.config.indent);
+        let mut measure = Measure { width: 0, limit };
+        Printer::new(self.config, &mut measure)
+            .compact(element)
+            .is_ok()
+    }
+}
+
+enum Inline<'a> {
+    Content(&'a str, Context),
+    Node(&'a Element),
+}
+
+fn trim(text: &str, start: bool, end: bool) -> &str {
+    let text = if start { text.trim_start() } else { text };
+    if end {
+        text.trim_end()
+    } else {
+        text
+    }
+}
+
+// Whitespace between elements, which pretty printing replaces.
+fn is_blank(element: &Element) -> bool {
+    element.tag == Tag::Text
+        && element
+            .content
+            .as_deref()
+            .unwrap_or_default()
+            .trim()
+            .is_empty()
+}
+
+// Text and phrasing elements that are kept within a line.
+fn is_inline(element: &Element) -> bool {
+    match &element.tag {
+        // Formulas are inline unless displayed as a block, and stay whole.
+        Tag::Math(MathTag::Math) => {
+            let display = element
+                .attrs
+                .as_ref()
+                .and_then(|attrs| attrs.get("display"));
+            return display != Some("block");
+        }
+        Tag::Math(_) => return true,
+        _ => {}
+    }
+    matches!(
+        element.tag,
+        Tag::A
+            | Tag::Abbr
+            | Tag::B
+            | Tag::Bdi
+            | Tag::Bdo
+            | Tag::Br
+            | Tag::Cite
+            | Tag::Code
+            | Tag::Data
+            | Tag::Del
+            | Tag::Dfn
+            | Tag::Em
+            | Tag::I
+            | Tag::Img
+            | Tag::Input
+            | Tag::Ins
+            | Tag::Kbd
+            | Tag::Label
+            | Tag::Mark
+            | Tag::Q
+            | Tag::Raw
+            | Tag::S
+            | Tag::Samp
+            | Tag::Small
+            | Tag::Span
+            | Tag::Strong
+            | Tag::Sub
+            | Tag::Sup
+            | Tag::Text
+            | Tag::Time
+            | Tag::U
+            | Tag::Var
+            | Tag::Wbr
+    )
+}
+
+// Elements where whitespace is significant, which are written as is.
+fn is_verbatim(tag: &Tag) -> bool {
+    matches!(
+        tag,
+        Tag::Code | Tag::Pre | Tag::Script | Tag::Style | Tag::Textarea
+    )
+}
+
+// Counts the columns written, failing once past the limit or on a line break.
+struct Measure {
+    width: usize,
+    limit: usize,
+}
+impl Write for Measure {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.width += s.chars().count();
+        if self.width > self.limit || s.contains('\n') {
+            Err(fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Adapts an `io::Write` to `fmt::Write`, keeping the underlying io error.
+struct IoWriter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+impl<W: io::Write> Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn page() -> Document {
+        let rows: Vec<Element> = (0..3)
+            .map(|i| {
+                ElementBuilder::new(Tag::Tr)
+                    .children(vec![ElementBuilder::new(Tag::Td)
+                        .content(i.to_string())
+                        .build()])
+                    .build()
+            })
+            .collect();
+        Document {
+            doctype: Doctype::Html,
+            elements: vec![ElementBuilder::new(Tag::Html)
+                .children(vec![ElementBuilder::new(Tag::Body)
+                    .children(vec![ElementBuilder::new(Tag::Table).children(rows).build()])
+                    .build()])
+                .build()],
+        }
+    }
+
+    #[test]
+    fn test_render_to_string() {
+        let mut out = String::new();
+        page().render_to(&mut out).unwrap();
+        assert_eq!(
+            out,
+            "<!DOCTYPE html><html><body><table><tr><td>0</td></tr><tr><td>1</td></tr>\
+             <tr><td>2</td></tr></table></body></html>"
+        );
+        assert_eq!(out, page().to_string());
+    }
+
+    #[test]
+    fn test_render_io() {
+        let mut out: Vec<u8> = vec![];
+        page().render_io(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), page().to_string());
+    }
+
+    #[test]
+    fn test_render_io_error() {
+        struct Broken;
+        impl io::Write for Broken {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let err = page().render_io(&mut Broken).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn test_render_strict() {
+        let div = ElementBuilder::new(Tag::Div)
+            .attrs(Attrs::new().id("x").href("/").build())
+            .build();
+
+        let mut out = String::new();
+        div.render_with(&Config::default(), &mut out).unwrap();
+        assert_eq!(out, r#"<div id="x"></div>"#);
+
+        let mut out = String::new();
+        let strict = Config::new().strict(true).build();
+        match div.render_with(&strict, &mut out) {
+            Err(RenderError::Invalid(errors)) => assert_eq!(errors.len(), 1),
+            other => panic!("expected validation errors, got {other:?}"),
+        }
+        assert!(out.is_empty());
+
+        let doc = Document {
+            doctype: Doctype::Html,
+            elements: vec![div],
+        };
+        let error = doc.render_with(&strict, &mut out).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid HTML:\n  div[0]: HTML tag 'div' does not support the 'href' attribute"
+        );
+    }
+
+    #[test]
+    fn test_render_xml() {
+        let doc = Document {
+            doctype: Doctype::Xhtml1Strict,
+            elements: vec![ElementBuilder::new(Tag::Html)
+                .attrs(Attrs::new().lang("en").build())
+                .children(vec![ElementBuilder::new(Tag::Body)
+                    .children(vec![
+                        ElementBuilder::new(Tag::Br).build(),
+                        ElementBuilder::new(Tag::Script)
+                            .attrs(Attrs::new().src("/app.js").defer().build())
+                            .build(),
+                    ])
+                    .build()])
+                .build()],
+        };
+
+        assert_eq!(
+            doc.to_string(),
+            concat!(
+                r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" "#,
+                r#""http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd">"#,
+                r#"<html xmlns="http://www.w3.org/1999/xhtml" lang="en"><body><br />"#,
+                r#"<script src="/app.js" defer="defer"></script></body></html>"#
+            )
+        );
+
+        // HTML serialization can still be chosen for an XHTML doctype.
+        let mut out = String::new();
+        doc.render_with(&Config::default(), &mut out).unwrap();
+        assert!(out.ends_with(
+            r#"<html lang="en"><body><br><script src="/app.js" defer></script></body></html>"#
+        ));
+    }
+
+    #[test]
+    fn test_render_xml_element() {
+        let input = ElementBuilder::new(Tag::Input)
+            .attrs(Attrs::new().required().build())
+            .build();
+
+        let mut out = String::new();
+        input.render_with(&Config::xml(), &mut out).unwrap();
+        assert_eq!(out, r#"<input required="required" />"#);
+    }
+
+    #[test]
+    fn test_pretty_document() {
+        assert_eq!(
+            format!("{:#}", page()),
+            "<!DOCTYPE html>
+<html>
+  <body>
+    <table>
+      <tr>
+        <td>0</td>
+      </tr>
+      <tr>
+        <td>1</td>
+      </tr>
+      <tr>
+        <td>2</td>
+      </tr>
+    </table>
+  </body>
+</html>"
+        );
+    }
+
+    #[test]
+    fn test_pretty_keeps_inline_and_verbatim_elements() {
+        let article = ElementBuilder::new(Tag::Div)
+            .children(vec![
+                ElementBuilder::new(Tag::P)
+                    .content("Some ")
+                    .children(vec![
+                        ElementBuilder::new(Tag::B).content("bold").build(),
+                        ElementBuilder::new(Tag::Text).content(" and ").build(),
+                        ElementBuilder::new(Tag::Em).content("emphasised").build(),
+                        ElementBuilder::new(Tag::Text).content(" text.").build(),
+                    ])
+                    .build(),
+                ElementBuilder::new(Tag::Pre)
+                    .children(vec![ElementBuilder::new(Tag::Code)
+                        .content("fn main() {\n    println!(\"hi\");\n}")
+                        .build()])
+                    .build(),
+                ElementBuilder::new(Tag::Script)
+                    .content("let a = 1;\nlet b = 2;")
+                    .build(),
+            ])
+            .build();
+
+        assert_eq!(
+            format!("{article:#}"),
+            "<div>
+  <p>Some <b>bold</b> and <em>emphasised</em> text.</p>
+  <pre><code>fn main() {
+    println!(\"hi\");
+}</code></pre>
+  <script>let a = 1;
+let b = 2;</script>
+</div>"
+        );
+    }
+
+    #[test]
+    fn test_pretty_max_width() {
+        let p = ElementBuilder::new(Tag::P)
+            .content("A paragraph that is long enough to wrap, with ")
+            .children(vec![
+                ElementBuilder::new(Tag::A)
+                    .attrs(Attrs::new().href("/more").build())
+                    .content("a link")
+                    .build(),
+                ElementBuilder::new(Tag::Text).content(" inside.").build(),
+            ])
+            .build();
+        let div = ElementBuilder::new(Tag::Div).children(vec![p]).build();
+
+        let config = Config::new()
+            .pretty(true)
+            .max_width(40)
+            .indent_style(IndentStyle::Tabs)
+            .build();
+        let mut out = String::new();
+        div.render_with(&config, &mut out).unwrap();
+        assert_eq!(
+            out,
+            "<div>
+\t<p>
+\t\tA paragraph that is long enough to wrap, with <a href=\"/more\">a link</a> inside.
+\t</p>
+</div>"
+        );
+    }
+
+    #[test]
+    fn test_pretty_output_reparses() {
+        let pretty = format!("{:#}", page());
+        let reparsed = parse(&pretty).unwrap();
+
+        assert_eq!(format!("{reparsed:#}"), pretty);
+    }
+
+    #[test]
+    fn test_render_attribute() {
+        let mut out = String::new();
+        Href("/a?b=1&c=2".to_string()).render_to(&mut out).unwrap();
+        assert_eq!(out, r#"href="/a?b=1&amp;c=2""#);
+    }
+
+    #[test]
+    fn test_text_in_opening_tag() {
+        let div = ElementBuilder::new(Tag::Div)
+            .text("onmouseover=alert(1)")
+            .build();
+        assert_eq!(div.to_string(), "<div></div>");
+        assert!(div
+            .render_with(&Config::new().strict(true).build(), &mut String::new())
+            .is_err());
+
+        let doctype = ElementBuilder::new(Tag::Doctype).text("html").build();
+        assert_eq!(doctype.to_string(), "<!DOCTYPE html>");
+    }
+}
@@ -0,0 +1,5 @@
+mod html;
+
+pub use html::{Config, ConfigBuilder, IndentStyle, Render, RenderError};
+
+pub(crate) use html::Printer;
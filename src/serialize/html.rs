@@ -0,0 +1,548 @@
+// Serde support for the tree, behind the `serde` feature. The shape is
+// stable, as fragments are stored and sent between services:
+//
+//   Document  {"doctype": "html", "elements": [Element, ...]}
+//   Element   {"tag": "a", "attrs": Attrs, "text": "..", "content": "..",
+//              "children": [Element, ...]}, leaving out the fields that
+//             are not set
+//   Tag       the name, such as "div", with "#text" for text, "#raw" for
+//             raw markup, read as text unless trusted, "!--" for comments,
+//             and "svg:circle" or "math:mi" for SVG and MathML elements
+//             within the "svg" and "math" roots
+//   Attrs     {"href": "/", "disabled": null}, null for a boolean attribute
+//   Attr      the name, such as "href"
+//   Doctype   "html", "xhtml1-strict", "xhtml1-transitional", "xhtml11",
+//             "html401-strict" or "html401-transitional"
+//
+// Deserializing checks attribute names, and rejects text in the opening
+// tag and attributes the element does not support, as rendering would drop
+// them. Fragments may come from a database or another service, so "#raw"
+// is read as text unless a `Seed` trusts it.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::attribute::{Attr, Attrs, MathAttr, SvgAttr, UnknownAttr};
+use crate::document::{Doctype, Document};
+use crate::element::Element;
+use crate::tag::{CustomElements, MathTag, SvgTag, Tag, UnknownTag};
+use crate::validate::{validate_opening_tag, NodePath};
+
+// Deserializes an `Element`, `Document` or `Vec<Element>` with what
+// `Deserialize` cannot be given: the definitions of custom elements, and
+// whether "#raw" is read as raw markup, which renders unescaped. Trust raw
+// markup only for input as trusted as the code itself.
+pub struct Seed<'a, T> {
+    custom_elements: Option<&'a CustomElements>,
+    trust_raw: bool,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Seed<'a, T> {
+    pub fn new() -> SeedBuilder<'a, T> {
+        SeedBuilder {
+            seed: Seed::untrusted(),
+        }
+    }
+
+    // How `Deserialize` reads, without definitions or raw markup.
+    fn untrusted() -> Self {
+        Seed {
+            custom_elements: None,
+            trust_raw: false,
+            value: PhantomData,
+        }
+    }
+
+    // The same options, for reading another type.
+    fn to<U>(&self) -> Seed<'a, U> {
+        Seed {
+            custom_elements: self.custom_elements,
+            trust_raw: self.trust_raw,
+            value: PhantomData,
+        }
+    }
+
+    fn tag(&self, name: &str) -> Result<Tag, String> {
+        let tag = tag_from_name(name, self.trust_raw)?;
+        let resolved = self
+            .custom_elements
+            .and_then(|elements| elements.resolve(&tag));
+        Ok(resolved.unwrap_or(tag))
+    }
+}
+impl<T> Clone for Seed<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Seed<'_, T> {}
+impl<T> fmt::Debug for Seed<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Seed")
+            .field("custom_elements", &self.custom_elements)
+            .field("trust_raw", &self.trust_raw)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub struct SeedBuilder<'a, T> {
+    seed: Seed<'a, T>,
+}
+
+impl<'a, T> SeedBuilder<'a, T> {
+    // Give the custom elements read their definitions.
+    pub fn custom_elements(mut self, custom_elements: &'a CustomElements) -> Self {
+        self.seed.custom_elements = Some(custom_elements);
+        self
+    }
+
+    // Read "#raw" as raw markup rather than text.
+    pub fn trust_raw(mut self, trust_raw: bool) -> Self {
+        self.seed.trust_raw = trust_raw;
+        self
+    }
+
+    pub fn build(self) -> Seed<'a, T> {
+        self.seed
+    }
+}
+
+impl Serialize for Tag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Tag::Text => serializer.serialize_str("#text"),
+            Tag::Raw => serializer.serialize_str("#raw"),
+            Tag::Svg(SvgTag::Svg) | Tag::Math(MathTag::Math) => serializer.collect_str(self),
+            Tag::Svg(tag) => serializer.collect_str(&format_args!("svg:{tag}")),
+            Tag::Math(tag) => serializer.collect_str(&format_args!("math:{tag}")),
+            tag => serializer.collect_str(tag),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        tag_from_name(&name, false).map_err(D::Error::custom)
+    }
+}
+
+fn tag_from_name(name: &str, trust_raw: bool) -> Result<Tag, String> {
+    if let Some(svg) = name.strip_prefix("svg:") {
+        return svg.parse().map(Tag::Svg).map_err(|e| format!("{e}"));
+    }
+    if let Some(math) = name.strip_prefix("math:") {
+        return math.parse().map(Tag::Math).map_err(|e| format!("{e}"));
+    }
+    match name {
+        "#raw" if trust_raw => return Ok(Tag::Raw),
+        "#text" | "#raw" => return Ok(Tag::Text),
+        _ => {}
+    }
+    if let Ok(tag) = name.parse() {
+        return Ok(tag);
+    }
+    if let Ok(tag) = Tag::custom(name.to_string()) {
+        return Ok(tag);
+    }
+    // Other names are kept as the parser keeps them, if they are names.
+    Tag::unknown(name).map_err(|_| UnknownTag(name.to_string()).to_string())
+}
+
+impl Serialize for Attr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+impl<'de> Deserialize<'de> for Attr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse()
+            .or_else(|_| name.parse::<SvgAttr>().map(Attr::Svg))
+            .or_else(|_| name.parse::<MathAttr>().map(Attr::Math))
+            .map_err(|_| D::Error::custom(UnknownAttr(name)))
+    }
+}
+
+impl Serialize for Attrs {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+impl<'de> Deserialize<'de> for Attrs {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(AttrsVisitor)
+    }
+}
+
+// Reads attributes in the order they are written.
+struct AttrsVisitor;
+
+impl<'de> Visitor<'de> for AttrsVisitor {
+    type Value = Attrs;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of attribute names to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Attrs, A::Error> {
+        let mut attrs = Attrs::new();
+        while let Some((name, value)) = map.next_entry::<String, Option<String>>()? {
+            attrs = attrs.try_attr(name, value).map_err(A::Error::custom)?;
+        }
+        Ok(attrs.build())
+    }
+}
+
+#[derive(Serialize)]
+struct ElementRef<'a> {
+    tag: &'a Tag,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attrs: Option<&'a Attrs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    children: Option<&'a [Element]>,
+}
+
+impl Serialize for Element {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ElementRef {
+            tag: &self.tag,
+            attrs: self.attrs.as_ref(),
+            text: self.text.as_deref(),
+            content: self.content.as_deref(),
+            children: self.children.as_deref(),
+        }
+        .serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for Element {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Seed::<Element>::untrusted().deserialize(deserializer)
+    }
+}
+
+const ELEMENT_FIELDS: &[&str] = &["tag", "attrs", "text", "content", "children"];
+
+impl<'de> DeserializeSeed<'de> for Seed<'_, Element> {
+    type Value = Element;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Element, D::Error> {
+        deserializer.deserialize_struct("Element", ELEMENT_FIELDS, self)
+    }
+}
+impl<'de> Visitor<'de> for Seed<'_, Element> {
+    type Value = Element;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an element")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Element, A::Error> {
+        let mut tag = None;
+        let (mut attrs, mut text, mut content, mut children) = (None, None, None, None);
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "tag" => {
+                    let name = map.next_value::<String>()?;
+                    set(&mut tag, "tag", self.tag(&name).map_err(A::Error::custom)?)?
+                }
+                "attrs" => set(&mut attrs, "attrs", map.next_value()?)?,
+                "text" => set(&mut text, "text", map.next_value()?)?,
+                "content" => set(&mut content, "content", map.next_value()?)?,
+                "children" => set(
+                    &mut children,
+                    "children",
+                    map.next_value_seed(Optional(self.to::<Vec<Element>>()))?,
+                )?,
+                other => return Err(A::Error::unknown_field(other, ELEMENT_FIELDS)),
+            }
+        }
+        let element = Element {
+            tag: tag.ok_or_else(|| A::Error::missing_field("tag"))?,
+            attrs: attrs.flatten(),
+            text: text.flatten(),
+            content: content.flatten(),
+            children: children.flatten(),
+        };
+        match validate_opening_tag(&element, &element.tag, &NodePath::default()).first() {
+            Some(error) => Err(A::Error::custom(error)),
+            None => Ok(element),
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for Seed<'_, Vec<Element>> {
+    type Value = Vec<Element>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+impl<'de> Visitor<'de> for Seed<'_, Vec<Element>> {
+    type Value = Vec<Element>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a list of elements")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut elements = vec![];
+        while let Some(element) = seq.next_element_seed(self.to::<Element>())? {
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+}
+
+// Reads a value that may be null with a seed.
+struct Optional<S>(S);
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Optional<S> {
+    type Value = Option<S::Value>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_option(self)
+    }
+}
+impl<'de, S: DeserializeSeed<'de>> Visitor<'de> for Optional<S> {
+    type Value = Option<S::Value>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a value or null")
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.0.deserialize(deserializer).map(Some)
+    }
+}
+
+// Set a field, which may only be given once.
+fn set<T, E: Error>(field: &mut Option<T>, name: &'static str, value: T) -> Result<(), E> {
+    match field.replace(value) {
+        Some(_) => Err(E::duplicate_field(name)),
+        None => Ok(()),
+    }
+}
+
+impl Serialize for Doctype {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(doctype_name(self))
+    }
+}
+impl<'de> Deserialize<'de> for Doctype {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Doctype::ALL
+            .into_iter()
+            .find(|doctype| doctype_name(doctype) == name)
+            .ok_or_else(|| D::Error::custom(format!("unknown doctype '{name}'")))
+    }
+}
+
+fn doctype_name(doctype: &Doctype) -> &'static str {
+    match doctype {
+        Doctype::Html => "html",
+        Doctype::Xhtml1Strict => "xhtml1-strict",
+        Doctype::Xhtml1Transitional => "xhtml1-transitional",
+        Doctype::Xhtml11 => "xhtml11",
+        Doctype::Html401Strict => "html401-strict",
+        Doctype::Html401Transitional => "html401-transitional",
+    }
+}
+
+#[derive(Serialize)]
+struct DocumentRef<'a> {
+    doctype: &'a Doctype,
+    elements: &'a [Element],
+}
+
+impl Serialize for Document {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DocumentRef {
+            doctype: &self.doctype,
+            elements: &self.elements,
+        }
+        .serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for Document {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Seed::<Document>::untrusted().deserialize(deserializer)
+    }
+}
+
+const DOCUMENT_FIELDS: &[&str] = &["doctype", "elements"];
+
+impl<'de> DeserializeSeed<'de> for Seed<'_, Document> {
+    type Value = Document;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Document, D::Error> {
+        deserializer.deserialize_struct("Document", DOCUMENT_FIELDS, self)
+    }
+}
+impl<'de> Visitor<'de> for Seed<'_, Document> {
+    type Value = Document;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a document")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Document, A::Error> {
+        let (mut doctype, mut elements) = (None, None);
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "doctype" => set(&mut doctype, "doctype", map.next_value()?)?,
+                "elements" => set(
+                    &mut elements,
+                    "elements",
+                    map.next_value_seed(self.to::<Vec<Element>>())?,
+                )?,
+                other => return Err(A::Error::unknown_field(other, DOCUMENT_FIELDS)),
+            }
+        }
+        Ok(Document {
+            doctype: doctype.unwrap_or_default(),
+            elements: elements.ok_or_else(|| A::Error::missing_field("elements"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeSeed;
+
+    use crate::prelude::*;
+
+    #[test]
+    fn test_element_json_shape() {
+        let element = parse_fragment(r#"<a href="/" hidden>Home <b>now</b><!-- x --></a>"#)
+            .unwrap()
+            .remove(0);
+
+        assert_eq!(
+            serde_json::to_string(&element).unwrap(),
+            concat!(
+                r#"{"tag":"a","attrs":{"href":"/","hidden":null},"content":"Home ","#,
+                r#""children":[{"tag":"b","content":"now"},{"tag":"!--","text":"x"}]}"#
+            )
+        );
+        let p = ElementBuilder::new(Tag::P)
+            .children(("Hi", Raw::new("<br>")))
+            .build();
+        assert_eq!(
+            serde_json::to_string(&p).unwrap(),
+            r##"{"tag":"p","children":[{"tag":"#text","content":"Hi"},{"tag":"#raw","content":"<br>"}]}"##
+        );
+    }
+
+    #[test]
+    fn test_document_round_trip() {
+        let html = concat!(
+            r#"<!DOCTYPE html><html lang="en"><body><my-card>Hi</my-card>"#,
+            r#"<svg viewBox="0 0 2 2"><circle r="1"></circle></svg>"#,
+            r#"<math><mi>x</mi></math><script>a < b</script></body></html>"#
+        );
+        let page = parse(html).unwrap();
+        let json = serde_json::to_value(&page).unwrap();
+
+        assert_eq!(json["doctype"], "html");
+        let body = &json["elements"][0]["children"][0]["children"];
+        assert_eq!(body[0]["tag"], "my-card");
+        assert_eq!(body[1]["children"][0]["tag"], "svg:circle");
+        assert_eq!(body[2]["children"][0]["tag"], "math:mi");
+
+        let back: Document = serde_json::from_value(json).unwrap();
+        assert_eq!(back.to_string(), html);
+        let xhtml: Document =
+            serde_json::from_str(r#"{"doctype":"xhtml11","elements":[]}"#).unwrap();
+        assert_eq!(xhtml.doctype, Doctype::Xhtml11);
+    }
+
+    #[test]
+    fn test_deserialize_validates() {
+        let error = |json: &str| {
+            serde_json::from_str::<Element>(json)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(error(r#"{"tag":"div","attrs":{"href":"/"}}"#)
+            .starts_with("HTML tag 'div' does not support the 'href' attribute"));
+        assert!(error(r#"{"tag":"p","attrs":{"x onload":"1"}}"#)
+            .starts_with("invalid HTML attribute name 'x onload'"));
+        assert!(error(r#"{"tag":"img src=x"}"#).starts_with("unknown HTML tag 'img src=x'"));
+        assert!(error(r#"{"tag":"svg:blink"}"#).contains("blink"));
+        assert!(error(r#"{"tag":"p","kids":[]}"#).contains("unknown field `kids`"));
+        assert!(error(r#"{"tag":"p","text":"onclick=go()"}"#)
+            .starts_with("HTML tag 'p' cannot have text within its opening tag"));
+        let nested = r#"{"tag":"div","children":[{"tag":"i","attrs":{"href":"/"}}]}"#;
+        assert!(error(nested).starts_with("HTML tag 'i' does not support the 'href' attribute"));
+
+        let attr: Attr = serde_json::from_str(r#""viewBox""#).unwrap();
+        assert_eq!(attr, Attr::Svg(SvgAttr::ViewBox));
+        assert_eq!(
+            serde_json::to_string(&Attr::HttpEquiv).unwrap(),
+            r#""http-equiv""#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_raw() {
+        let json = r##"{"tag":"p","children":[{"tag":"#raw","content":"<script>x()</script>"}]}"##;
+
+        // Untrusted raw markup is read as text, and escaped.
+        let p: Element = serde_json::from_str(json).unwrap();
+        assert_eq!(p.children.as_ref().unwrap()[0].tag, Tag::Text);
+        assert_eq!(p.to_string(), "<p>&lt;script&gt;x()&lt;/script&gt;</p>");
+
+        let seed = Seed::<Element>::new().trust_raw(true).build();
+        let p = seed
+            .deserialize(&mut serde_json::Deserializer::from_str(json))
+            .unwrap();
+        assert_eq!(p.to_string(), "<p><script>x()</script></p>");
+        assert_eq!(serde_json::to_string(&p).unwrap(), json);
+    }
+
+    #[test]
+    fn test_deserialize_custom_elements() {
+        let elements = CustomElements::new()
+            .define("rating-stars", ["max-stars"])
+            .build();
+        let json = r#"{"elements":[{"tag":"div","children":[{"tag":"rating-stars","attrs":{"colour":"red"}}]}]}"#;
+
+        // Without the definitions, custom elements support any attribute.
+        let page: Document = serde_json::from_str(json).unwrap();
+        assert_eq!(page.doctype, Doctype::Html);
+        let seed = Seed::<Document>::new().custom_elements(&elements).build();
+        let error = seed
+            .deserialize(&mut serde_json::Deserializer::from_str(json))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.starts_with("HTML tag 'rating-stars' does not support the 'colour' attribute")
+        );
+
+        let list =
+            r#"[{"tag":"rating-stars","attrs":{"max-stars":"5"}},{"tag":"p","children":null}]"#;
+        let seed = Seed::<Vec<Element>>::new()
+            .custom_elements(&elements)
+            .build();
+        let list = seed
+            .deserialize(&mut serde_json::Deserializer::from_str(list))
+            .unwrap();
+        assert_eq!(list[0].tag, elements.get("rating-stars").unwrap());
+        assert!(list[1].children.is_none());
+    }
+}
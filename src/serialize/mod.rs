@@ -0,0 +1,3 @@
+mod html;
+
+pub use html::{Seed, SeedBuilder};
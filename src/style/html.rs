@@ -0,0 +1,341 @@
+use std::fmt::Display;
+
+use log::warn;
+
+use super::{BorderStyle, Color, CssDisplay, Length, Position, TextAlign};
+use crate::attribute::{Attr, AttrsBuilder};
+
+// CSS declarations, such as `color: #333; margin: 0 auto`, for the `style`
+// attribute or a rule of a `Stylesheet`. Each property appears once, in the
+// order it was first set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Css {
+    declarations: Vec<(String, String)>,
+}
+
+impl Css {
+    pub fn new() -> CssBuilder {
+        CssBuilder::default()
+    }
+    // The value of a property.
+    pub fn get(&self, property: &str) -> Option<&str> {
+        self.declarations
+            .iter()
+            .find(|(name, _)| name == property)
+            .map(|(_, value)| value.as_str())
+    }
+    // Each property and value, in output order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.declarations
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+    pub fn len(&self) -> usize {
+        self.declarations.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+}
+impl Display for Css {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (name, value)) in self.declarations.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{name}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CssBuilder {
+    declarations: Vec<(String, String)>,
+}
+
+impl CssBuilder {
+    // Any property, for those without a typed method. Invalid declarations
+    // are ignored with a warning, as with `AttrsBuilder::other`.
+    pub fn property(self, name: impl Into<String>, value: impl Display) -> Self {
+        match declaration(name.into(), value.to_string()) {
+            Ok(declaration) => self.insert(declaration),
+            Err(error) => {
+                warn!("{error}");
+                self
+            }
+        }
+    }
+
+    // Like `property`, returning an error for an invalid declaration.
+    pub fn try_property(
+        self,
+        name: impl Into<String>,
+        value: impl Display,
+    ) -> Result<Self, InvalidCss> {
+        Ok(self.insert(declaration(name.into(), value.to_string())?))
+    }
+
+    // Setting a property again replaces its value, keeping its position.
+    fn insert(mut self, (name, value): (String, String)) -> Self {
+        match self
+            .declarations
+            .iter_mut()
+            .find(|(found, _)| *found == name)
+        {
+            Some(declaration) => declaration.1 = value,
+            None => self.declarations.push((name, value)),
+        }
+        self
+    }
+
+    // A custom property, such as `--accent`, for use with `Color::Var`.
+    pub fn var(self, name: &str, value: impl Display) -> Self {
+        self.property(format!("--{name}"), value)
+    }
+
+    pub fn color(self, color: Color) -> Self {
+        self.property("color", color)
+    }
+
+    pub fn background(self, color: Color) -> Self {
+        self.property("background-color", color)
+    }
+
+    pub fn display(self, display: CssDisplay) -> Self {
+        self.property("display", display)
+    }
+
+    pub fn position(self, position: Position) -> Self {
+        self.property("position", position)
+    }
+
+    pub fn width(self, width: Length) -> Self {
+        self.property("width", width)
+    }
+
+    pub fn height(self, height: Length) -> Self {
+        self.property("height", height)
+    }
+
+    pub fn min_width(self, width: Length) -> Self {
+        self.property("min-width", width)
+    }
+
+    pub fn max_width(self, width: Length) -> Self {
+        self.property("max-width", width)
+    }
+
+    pub fn min_height(self, height: Length) -> Self {
+        self.property("min-height", height)
+    }
+
+    pub fn max_height(self, height: Length) -> Self {
+        self.property("max-height", height)
+    }
+
+    pub fn margin(self, margin: Length) -> Self {
+        self.property("margin", margin)
+    }
+
+    // Vertical and horizontal margins, such as `0 auto` to center a block.
+    pub fn margin_xy(self, y: Length, x: Length) -> Self {
+        self.property("margin", format!("{y} {x}"))
+    }
+
+    pub fn padding(self, padding: Length) -> Self {
+        self.property("padding", padding)
+    }
+
+    pub fn padding_xy(self, y: Length, x: Length) -> Self {
+        self.property("padding", format!("{y} {x}"))
+    }
+
+    pub fn gap(self, gap: Length) -> Self {
+        self.property("gap", gap)
+    }
+
+    pub fn border(self, width: Length, style: BorderStyle, color: Color) -> Self {
+        self.property("border", format!("{width} {style} {color}"))
+    }
+
+    pub fn border_radius(self, radius: Length) -> Self {
+        self.property("border-radius", radius)
+    }
+
+    pub fn font_family(self, family: impl Display) -> Self {
+        self.property("font-family", family)
+    }
+
+    pub fn font_size(self, size: Length) -> Self {
+        self.property("font-size", size)
+    }
+
+    pub fn font_weight(self, weight: u16) -> Self {
+        self.property("font-weight", weight)
+    }
+
+    pub fn line_height(self, height: f32) -> Self {
+        self.property("line-height", height)
+    }
+
+    pub fn text_align(self, align: TextAlign) -> Self {
+        self.property("text-align", align)
+    }
+
+    pub fn opacity(self, opacity: f32) -> Self {
+        self.property("opacity", opacity)
+    }
+
+    pub fn z_index(self, z_index: i32) -> Self {
+        self.property("z-index", z_index)
+    }
+
+    pub fn build(self) -> Css {
+        Css {
+            declarations: self.declarations,
+        }
+    }
+}
+
+impl AttrsBuilder {
+    // The `style` attribute. Quotes in values, such as font names, are
+    // escaped with the rest of the attribute value.
+    pub fn style(self, style: Css) -> Self {
+        self.attr(Attr::Style.to_string(), style.to_string())
+    }
+}
+
+// Error returned for a declaration, selector or media query that would
+// end its rule or block early, or is otherwise not valid CSS.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidCss(pub String);
+
+impl Display for InvalidCss {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid CSS '{}'", self.0)
+    }
+}
+impl std::error::Error for InvalidCss {}
+
+fn declaration(name: String, value: String) -> Result<(String, String), InvalidCss> {
+    match is_property_name(&name) && is_safe(&value) && !value.trim().is_empty() {
+        true => Ok((name, value)),
+        false => Err(InvalidCss(format!("{name}: {value}"))),
+    }
+}
+
+// Property names are letters, digits and `-`, or `--` and a custom name.
+fn is_property_name(name: &str) -> bool {
+    let name = name.strip_prefix("--").unwrap_or(name);
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+}
+
+// Whether CSS text stays within its declaration or rule: `;`, `{` and `}`
+// only within strings, balanced brackets and strings, and no comments.
+pub(super) fn is_safe(css: &str) -> bool {
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut chars = css.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_control() && c != '\t' {
+            return false;
+        }
+        match (quote, c) {
+            (_, '\\') => {
+                if chars.next().is_none() {
+                    return false;
+                }
+            }
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, ';' | '{' | '}') => return false,
+            (None, '/') if chars.peek() == Some(&'*') => return false,
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => match depth.checked_sub(1) {
+                Some(open) => depth = open,
+                None => return false,
+            },
+            (None, _) => {}
+        }
+    }
+    quote.is_none() && depth == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_style_declarations() {
+        let style = Css::new()
+            .display(CssDisplay::Flex)
+            .margin_xy(Length::Zero, Length::Auto)
+            .max_width(Length::Rem(40.0))
+            .color(Color::Hex(0x336699))
+            .background(Color::Rgba(0, 0, 0, 0.5))
+            .var("accent", Color::Named("rebeccapurple".to_string()))
+            .property("border-color", Color::Var("accent".to_string()))
+            .line_height(1.5)
+            .display(CssDisplay::Grid)
+            .build();
+
+        assert_eq!(style.len(), 8);
+        assert_eq!(style.get("display"), Some("grid"));
+        assert_eq!(
+            style.to_string(),
+            concat!(
+                "display: grid; margin: 0 auto; max-width: 40rem; color: #336699; ",
+                "background-color: rgb(0 0 0 / 0.5); --accent: rebeccapurple; ",
+                "border-color: var(--accent); line-height: 1.5"
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_declarations() {
+        let style = Css::new()
+            .property("color", "red; background: url(evil)")
+            .property("margin", "0 } body { display: none")
+            .property("content", "'unclosed")
+            .property("width", "calc(100% - 2rem")
+            .property("bad name", "1")
+            .property("color", "/* hidden */ red")
+            .property("content", "'a; b'")
+            .build();
+
+        assert_eq!(style.to_string(), "content: 'a; b'");
+        assert_eq!(
+            Css::new().try_property("color", "").unwrap_err(),
+            InvalidCss("color: ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_style_attribute() {
+        let element = ElementBuilder::new(Tag::P)
+            .attrs(
+                Attrs::new()
+                    .style(
+                        Css::new()
+                            .font_family(r#""Open Sans", sans-serif"#)
+                            .text_align(TextAlign::Center)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .content("Hi")
+            .build();
+
+        assert_eq!(
+            element.to_string(),
+            r#"<p style="font-family: &quot;Open Sans&quot;, sans-serif; text-align: center">Hi</p>"#
+        );
+        assert_eq!(element.attrs.as_ref().unwrap().present(), vec![Attr::Style]);
+    }
+}
@@ -0,0 +1,7 @@
+mod html;
+mod sheet;
+mod units;
+
+pub use html::{Css, CssBuilder, InvalidCss};
+pub use sheet::{Stylesheet, StylesheetBuilder};
+pub use units::{BorderStyle, Color, CssDisplay, Length, Position, TextAlign};
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+use log::warn;
+
+use super::html::{is_safe, InvalidCss};
+use super::Css;
+use crate::element::{Component, Element, ElementBuilder};
+use crate::tag::Tag;
+
+// CSS rule sets, including media queries, for a `<style>` element. The
+// sheet is a component, so it can be used wherever an element can. Its
+// CSS is written as raw text, so selectors such as `nav > a` are not
+// escaped, and a `</style>` within it cannot end the element.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stylesheet {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Rule {
+    Style(String, Css),
+    Media(String, Stylesheet),
+}
+
+impl Stylesheet {
+    pub fn new() -> StylesheetBuilder {
+        StylesheetBuilder::default()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+impl Display for Stylesheet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, rule) in self.rules.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            match rule {
+                Rule::Style(selector, style) => write!(f, "{selector} {{ {style} }}")?,
+                Rule::Media(query, sheet) => write!(f, "@media {query} {{ {sheet} }}")?,
+            }
+        }
+        Ok(())
+    }
+}
+impl Component for Stylesheet {
+    fn render(&self) -> Element {
+        ElementBuilder::new(Tag::Style)
+            .content(self.to_string())
+            .build()
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct StylesheetBuilder {
+    rules: Vec<Rule>,
+}
+
+impl StylesheetBuilder {
+    // A rule set, such as `nav > a:hover`. An invalid selector is ignored
+    // with a warning, as are rules without declarations.
+    pub fn rule(self, selector: impl Into<String>, style: Css) -> Self {
+        let selector = selector.into();
+        if style.is_empty() {
+            return self;
+        }
+        match check(selector) {
+            Ok(selector) => self.push(Rule::Style(selector, style)),
+            Err(error) => {
+                warn!("{error}");
+                self
+            }
+        }
+    }
+
+    // Rules that apply when the media query matches, such as
+    // `(max-width: 600px)` or `print`.
+    pub fn media(self, query: impl Into<String>, sheet: Stylesheet) -> Self {
+        if sheet.is_empty() {
+            return self;
+        }
+        match check(query.into()) {
+            Ok(query) => self.push(Rule::Media(query, sheet)),
+            Err(error) => {
+                warn!("{error}");
+                self
+            }
+        }
+    }
+
+    fn push(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn build(self) -> Stylesheet {
+        Stylesheet { rules: self.rules }
+    }
+}
+
+// Selectors and media queries cannot be empty or end the rule early.
+fn check(prelude: String) -> Result<String, InvalidCss> {
+    match !prelude.trim().is_empty() && is_safe(&prelude) {
+        true => Ok(prelude),
+        false => Err(InvalidCss(prelude)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_stylesheet() {
+        let sheet = Stylesheet::new()
+            .rule(
+                "body",
+                Css::new()
+                    .margin(Length::Zero)
+                    .font_family("system-ui")
+                    .build(),
+            )
+            .rule(
+                "nav > a[href^=\"http\"]",
+                Css::new().color(Color::Hex(0xcc0000)).build(),
+            )
+            .rule("p } body {", Css::new().color(Color::Transparent).build())
+            .rule("main", Css::new().build())
+            .media(
+                "(max-width: 600px)",
+                Stylesheet::new()
+                    .rule("nav", Css::new().display(CssDisplay::None).build())
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(
+            sheet.to_string(),
+            concat!(
+                "body { margin: 0; font-family: system-ui } ",
+                "nav > a[href^=\"http\"] { color: #cc0000 } ",
+                "@media (max-width: 600px) { nav { display: none } }"
+            )
+        );
+    }
+
+    #[test]
+    fn test_style_element() {
+        let sheet = Stylesheet::new()
+            .rule(
+                "a::after",
+                Css::new().property("content", "'</style>'").build(),
+            )
+            .rule(
+                "ul > li",
+                Css::new()
+                    .border(Length::Px(1.0), BorderStyle::Solid, Color::Rgb(0, 0, 0))
+                    .build(),
+            )
+            .build();
+        let head = HeadBuilder::new().title("Styled").element(sheet.into());
+        let document = Document::new().head(head).body(vec![]).build();
+
+        assert_eq!(
+            document.to_string(),
+            concat!(
+                "<!DOCTYPE html><html><head><title>Styled</title><style>",
+                r"a::after { content: '<\/style>' } ul > li { border: 1px solid rgb(0 0 0) }",
+                "</style></head><body></body></html>"
+            )
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::Display;
+
+// A CSS length, such as `16px` or `1.5rem`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    Zero,
+    Auto,
+    Px(f32),
+    Em(f32),
+    Rem(f32),
+    Percent(f32),
+    Vw(f32),
+    Vh(f32),
+    Ch(f32),
+}
+impl Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Length::Zero => write!(f, "0"),
+            Length::Auto => write!(f, "auto"),
+            Length::Px(value) => write!(f, "{value}px"),
+            Length::Em(value) => write!(f, "{value}em"),
+            Length::Rem(value) => write!(f, "{value}rem"),
+            Length::Percent(value) => write!(f, "{value}%"),
+            Length::Vw(value) => write!(f, "{value}vw"),
+            Length::Vh(value) => write!(f, "{value}vh"),
+            Length::Ch(value) => write!(f, "{value}ch"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Color {
+    // A `#rrggbb` color, such as `Color::Hex(0x336699)`.
+    Hex(u32),
+    Rgb(u8, u8, u8),
+    // An RGB color with an alpha between 0 and 1.
+    Rgba(u8, u8, u8, f32),
+    // A named color, such as `rebeccapurple`.
+    Named(String),
+    // A custom property, written as `var(--name)`.
+    Var(String),
+    CurrentColor,
+    Transparent,
+}
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::Hex(rgb) => write!(f, "#{:06x}", rgb & 0xff_ffff),
+            Color::Rgb(r, g, b) => write!(f, "rgb({r} {g} {b})"),
+            Color::Rgba(r, g, b, a) => write!(f, "rgb({r} {g} {b} / {a})"),
+            Color::Named(name) => write!(f, "{name}"),
+            Color::Var(name) => write!(f, "var(--{name})"),
+            Color::CurrentColor => write!(f, "currentcolor"),
+            Color::Transparent => write!(f, "transparent"),
+        }
+    }
+}
+
+// Values of the `display` property.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CssDisplay {
+    None,
+    Block,
+    Inline,
+    InlineBlock,
+    Flex,
+    InlineFlex,
+    Grid,
+    InlineGrid,
+    Contents,
+}
+impl Display for CssDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssDisplay::None => write!(f, "none"),
+            CssDisplay::Block => write!(f, "block"),
+            CssDisplay::Inline => write!(f, "inline"),
+            CssDisplay::InlineBlock => write!(f, "inline-block"),
+            CssDisplay::Flex => write!(f, "flex"),
+            CssDisplay::InlineFlex => write!(f, "inline-flex"),
+            CssDisplay::Grid => write!(f, "grid"),
+            CssDisplay::InlineGrid => write!(f, "inline-grid"),
+            CssDisplay::Contents => write!(f, "contents"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Position {
+    Static,
+    Relative,
+    Absolute,
+    Fixed,
+    Sticky,
+}
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Position::Static => write!(f, "static"),
+            Position::Relative => write!(f, "relative"),
+            Position::Absolute => write!(f, "absolute"),
+            Position::Fixed => write!(f, "fixed"),
+            Position::Sticky => write!(f, "sticky"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextAlign {
+    Start,
+    End,
+    Left,
+    Right,
+    Center,
+    Justify,
+}
+impl Display for TextAlign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextAlign::Start => write!(f, "start"),
+            TextAlign::End => write!(f, "end"),
+            TextAlign::Left => write!(f, "left"),
+            TextAlign::Right => write!(f, "right"),
+            TextAlign::Center => write!(f, "center"),
+            TextAlign::Justify => write!(f, "justify"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderStyle {
+    None,
+    Solid,
+    Dashed,
+    Dotted,
+    Double,
+}
+impl Display for BorderStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BorderStyle::None => write!(f, "none"),
+            BorderStyle::Solid => write!(f, "solid"),
+            BorderStyle::Dashed => write!(f, "dashed"),
+            BorderStyle::Dotted => write!(f, "dotted"),
+            BorderStyle::Double => write!(f, "double"),
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use log::warn;
+
+use super::Tag;
+use crate::attribute::{validate_name as validate_attr_name, Attr};
+
+// Names the HTML standard reserves, which cannot be used for custom elements.
+const RESERVED: [&str; 8] = [
+    "annotation-xml",
+    "color-profile",
+    "font-face",
+    "font-face-src",
+    "font-face-uri",
+    "font-face-format",
+    "font-face-name",
+    "missing-glyph",
+];
+
+// Error returned when a name is not a valid custom element name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomTagError {
+    // The name has no hyphen, so it could clash with a future HTML element.
+    MissingHyphen(String),
+    // The name does not start with a lowercase ASCII letter.
+    InvalidStart(String),
+    // The name contains an uppercase letter, whitespace or markup character.
+    InvalidChar(String, char),
+    // The name is reserved by the HTML standard.
+    Reserved(String),
+    // An attribute the element is defined with is not a valid name.
+    InvalidAttribute(String, String),
+}
+
+impl Display for CustomTagError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomTagError::MissingHyphen(name) => {
+                write!(f, "custom element name '{name}' must contain a hyphen")
+            }
+            CustomTagError::InvalidStart(name) => write!(
+                f,
+                "custom element name '{name}' must start with a lowercase ASCII letter"
+            ),
+            CustomTagError::InvalidChar(name, c) => {
+                write!(f, "custom element name '{name}' cannot contain {c:?}")
+            }
+            CustomTagError::Reserved(name) => {
+                write!(f, "custom element name '{name}' is reserved")
+            }
+            CustomTagError::InvalidAttribute(name, attr) => write!(
+                f,
+                "custom element '{name}' cannot define the invalid attribute name '{attr}'"
+            ),
+        }
+    }
+}
+impl std::error::Error for CustomTagError {}
+
+// Check a name against the custom element naming rules.
+pub(crate) fn validate_name(name: &str) -> Result<(), CustomTagError> {
+    if !name.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return Err(CustomTagError::InvalidStart(name.to_string()));
+    }
+    let invalid = name.chars().find(|c| {
+        c.is_uppercase()
+            || c.is_whitespace()
+            || c.is_control()
+            || matches!(c, '/' | '>' | '<' | '=' | '"' | '\'' | '&' | '\0')
+    });
+    if let Some(c) = invalid {
+        return Err(CustomTagError::InvalidChar(name.to_string(), c));
+    }
+    if !name.contains('-') {
+        return Err(CustomTagError::MissingHyphen(name.to_string()));
+    }
+    if RESERVED.contains(&name) {
+        return Err(CustomTagError::Reserved(name.to_string()));
+    }
+    Ok(())
+}
+
+// The name of a custom element, with the attributes it was defined with.
+// It is only created by `Tag::custom`, `Tag::define` and `CustomElements`,
+// which check the name. Markup read without `CustomElements` gives custom
+// elements no definition, so they support any attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomTag {
+    name: Cow<'static, str>,
+    // `None` when the element was not defined, so supports any attribute.
+    defined: Option<Arc<Defined>>,
+}
+
+// Defined attributes, split into those with an `Attr` variant and other
+// names, such as a component's own `variant` or `size`.
+#[derive(Debug, Default, PartialEq)]
+struct Defined {
+    attrs: Vec<Attr>,
+    names: Vec<String>,
+}
+
+impl CustomTag {
+    pub(crate) fn new(name: Cow<'static, str>) -> Result<CustomTag, CustomTagError> {
+        validate_name(&name)?;
+        Ok(CustomTag {
+            name,
+            defined: None,
+        })
+    }
+
+    // A custom element supporting the global attributes and `attrs`, which
+    // may be any attribute names.
+    pub(crate) fn define(
+        name: Cow<'static, str>,
+        attrs: impl IntoIterator<Item = impl ToString>,
+    ) -> Result<CustomTag, CustomTagError> {
+        validate_name(&name)?;
+        let mut defined = Defined::default();
+        for attr in attrs {
+            let attr = attr.to_string();
+            match attr.parse::<Attr>() {
+                Ok(attr) => defined.attrs.push(attr),
+                Err(_) => {
+                    validate_attr_name(&attr).map_err(|_| {
+                        CustomTagError::InvalidAttribute(name.to_string(), attr.clone())
+                    })?;
+                    defined.names.push(attr);
+                }
+            }
+        }
+        Ok(CustomTag {
+            name,
+            defined: Some(Arc::new(defined)),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    // The defined attributes with an `Attr` variant, or `None` when the
+    // element was not defined.
+    pub(crate) fn attributes(&self) -> Option<&[Attr]> {
+        self.defined
+            .as_ref()
+            .map(|defined| defined.attrs.as_slice())
+    }
+
+    // Whether an attribute without an `Attr` variant is supported.
+    pub(crate) fn supports_other(&self, name: &str) -> bool {
+        self.defined
+            .as_ref()
+            .is_none_or(|defined| defined.names.iter().any(|defined| defined == name))
+    }
+}
+impl Display for CustomTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+// The custom elements a team has defined, by name. Markup read with them,
+// through `parse_with` or `validate_with`, gives each custom element found
+// its definition, so its attributes are checked as for `Tag::define`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CustomElements {
+    defined: BTreeMap<String, CustomTag>,
+}
+
+impl CustomElements {
+    pub fn new() -> CustomElementsBuilder {
+        CustomElementsBuilder {
+            elements: CustomElements::default(),
+        }
+    }
+
+    // The defined element with the name, if there is one.
+    pub fn get(&self, name: &str) -> Option<Tag> {
+        self.defined.get(name).cloned().map(Tag::Custom)
+    }
+
+    // The tag with its definition, for a custom element read without one.
+    pub(crate) fn resolve(&self, tag: &Tag) -> Option<Tag> {
+        match tag {
+            Tag::Custom(custom) if custom.defined.is_none() => self.get(custom.name()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomElementsBuilder {
+    elements: CustomElements,
+}
+
+impl CustomElementsBuilder {
+    // Define a custom element, as `Tag::define` does. An invalid name or
+    // attribute is logged and the element ignored.
+    pub fn define(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        attrs: impl IntoIterator<Item = impl ToString>,
+    ) -> Self {
+        match CustomTag::define(name.into(), attrs) {
+            Ok(tag) => self.insert(tag),
+            Err(error) => {
+                warn!("{error}");
+                self
+            }
+        }
+    }
+
+    // Like `define`, returning an error for an invalid name or attribute.
+    pub fn try_define(
+        self,
+        name: impl Into<Cow<'static, str>>,
+        attrs: impl IntoIterator<Item = impl ToString>,
+    ) -> Result<Self, CustomTagError> {
+        Ok(self.insert(CustomTag::define(name.into(), attrs)?))
+    }
+
+    fn insert(mut self, tag: CustomTag) -> Self {
+        self.elements.defined.insert(tag.name().to_string(), tag);
+        self
+    }
+
+    pub fn build(self) -> CustomElements {
+        self.elements
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_name() {
+        assert_eq!(validate_name("my-date-picker"), Ok(()));
+        assert_eq!(validate_name("x-1.2_ü"), Ok(()));
+        assert_eq!(
+            validate_name("picker"),
+            Err(CustomTagError::MissingHyphen("picker".to_string()))
+        );
+        assert_eq!(
+            validate_name("-picker"),
+            Err(CustomTagError::InvalidStart("-picker".to_string()))
+        );
+        assert_eq!(
+            validate_name("My-picker"),
+            Err(CustomTagError::InvalidStart("My-picker".to_string()))
+        );
+        assert_eq!(
+            validate_name("my-Picker"),
+            Err(CustomTagError::InvalidChar("my-Picker".to_string(), 'P'))
+        );
+        assert_eq!(
+            validate_name("my picker"),
+            Err(CustomTagError::InvalidChar("my picker".to_string(), ' '))
+        );
+        assert_eq!(
+            validate_name("font-face"),
+            Err(CustomTagError::Reserved("font-face".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_define_with_any_attribute_name() {
+        let card = CustomTag::define("user-card".into(), ["href", "variant", "size"]).unwrap();
+        assert_eq!(card.attributes(), Some([Attr::Href].as_slice()));
+        assert!(card.supports_other("variant"));
+        assert!(!card.supports_other("colour"));
+        assert!(CustomTag::new("user-card".into())
+            .unwrap()
+            .supports_other("colour"));
+
+        assert_eq!(
+            CustomTag::define("user-card".into(), ["x onload"]),
+            Err(CustomTagError::InvalidAttribute(
+                "user-card".to_string(),
+                "x onload".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_custom_elements() {
+        let elements = CustomElements::new()
+            .define("user-card", ["variant"])
+            .define("card", ["variant"])
+            .build();
+
+        let card = elements.get("user-card").unwrap();
+        assert_eq!(card, Tag::define("user-card", ["variant"]).unwrap());
+        assert_eq!(elements.get("card"), None);
+        assert_eq!(
+            elements.resolve(&Tag::custom("user-card").unwrap()),
+            Some(card)
+        );
+        assert_eq!(elements.resolve(&Tag::custom("user-menu").unwrap()), None);
+        assert_eq!(
+            CustomElements::new()
+                .try_define("card", ["variant"])
+                .unwrap_err(),
+            CustomTagError::MissingHyphen("card".to_string())
+        );
+    }
+}
@@ -1,19 +1,56 @@
 use crate::attribute::Attr;
+use std::borrow::Cow;
 use std::fmt::Display;
+use std::str::FromStr;
 
+use super::custom::{CustomTag, CustomTagError};
+use super::mathml::MathTag;
+use super::svg::SvgTag;
+
+// The name of an element. `Text` and `Raw` are not elements, but mark the
+// nodes between them: escaped text and trusted markup.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Tag {
     A,
+    Abbr,
+    Address,
+    Area,
+    Article,
+    Aside,
+    Audio,
     B,
+    Base,
+    Bdi,
+    Bdo,
+    Blockquote,
     Body,
     Br,
     Button,
+    Canvas,
+    Caption,
+    Cite,
     Code,
     Comment,
+    Col,
+    Colgroup,
+    Data,
+    Datalist,
+    Dd,
+    Del,
+    Details,
+    Dfn,
+    Dialog,
     Div,
     Doctype,
+    Dl,
+    Dt,
     Em,
+    Embed,
+    Fieldset,
+    Figcaption,
+    Figure,
     Footer,
+    Form,
     H1,
     H2,
     H3,
@@ -22,48 +59,130 @@ pub enum Tag {
     H6,
     Head,
     Header,
+    Hgroup,
+    Hr,
     Html,
     I,
+    Iframe,
     Input,
     Img,
+    Ins,
     Kbd,
     Label,
+    Legend,
     Li,
     Link,
+    Main,
+    Map,
+    Mark,
+    Menu,
     Meta,
+    Meter,
     Nav,
+    Noscript,
+    Object,
     Ol,
+    Optgroup,
+    Option,
+    Output,
     P,
+    Picture,
     Pre,
+    Progress,
     Q,
+    // Not an element: trusted markup, written as is. Created from `Raw`.
+    Raw,
+    Rp,
+    Rt,
+    Ruby,
+    S,
+    Samp,
     Script,
+    Search,
+    Section,
+    Select,
+    Slot,
+    Small,
+    Source,
     Span,
     Strong,
+    Style,
     Sub,
+    Summary,
     Sup,
     Table,
+    // Not an element: text between elements, escaped when written.
+    Text,
+    Template,
+    Textarea,
+    Tfoot,
     Thead,
     Tbody,
     Th,
+    Time,
     Tr,
     Td,
     Title,
+    Track,
+    U,
     Ul,
+    Var,
+    Video,
+    Wbr,
+    // An SVG element, such as `Tag::Svg(SvgTag::Rect)`.
+    Svg(SvgTag),
+    // A MathML element, such as `Tag::Math(MathTag::Mfrac)`.
+    Math(MathTag),
+    // A custom element, such as `my-date-picker`. Create it with
+    // `Tag::custom` or `Tag::define`, which check the name.
+    Custom(CustomTag),
+    // A tag this crate has no variant for. Create it with `Tag::unknown`,
+    // which checks the name.
+    Unknown(TagName),
 }
 impl Display for Tag {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Tag::A => write!(f, "a"),
+            Tag::Abbr => write!(f, "abbr"),
+            Tag::Address => write!(f, "address"),
+            Tag::Area => write!(f, "area"),
+            Tag::Article => write!(f, "article"),
+            Tag::Aside => write!(f, "aside"),
+            Tag::Audio => write!(f, "audio"),
             Tag::B => write!(f, "b"),
+            Tag::Base => write!(f, "base"),
+            Tag::Bdi => write!(f, "bdi"),
+            Tag::Bdo => write!(f, "bdo"),
+            Tag::Blockquote => write!(f, "blockquote"),
             Tag::Body => write!(f, "body"),
             Tag::Br => write!(f, "br"),
             Tag::Button => write!(f, "button"),
+            Tag::Canvas => write!(f, "canvas"),
+            Tag::Caption => write!(f, "caption"),
+            Tag::Cite => write!(f, "cite"),
             Tag::Code => write!(f, "code"),
             Tag::Comment => write!(f, "!--"),
+            Tag::Col => write!(f, "col"),
+            Tag::Colgroup => write!(f, "colgroup"),
+            Tag::Data => write!(f, "data"),
+            Tag::Datalist => write!(f, "datalist"),
+            Tag::Dd => write!(f, "dd"),
+            Tag::Del => write!(f, "del"),
+            Tag::Details => write!(f, "details"),
+            Tag::Dfn => write!(f, "dfn"),
+            Tag::Dialog => write!(f, "dialog"),
             Tag::Div => write!(f, "div"),
             Tag::Doctype => write!(f, "!DOCTYPE"),
-            Tag::Em => write!(f, "!DOCTYPE"),
+            Tag::Dl => write!(f, "dl"),
+            Tag::Dt => write!(f, "dt"),
+            Tag::Em => write!(f, "em"),
+            Tag::Embed => write!(f, "embed"),
+            Tag::Fieldset => write!(f, "fieldset"),
+            Tag::Figcaption => write!(f, "figcaption"),
+            Tag::Figure => write!(f, "figure"),
             Tag::Footer => write!(f, "footer"),
+            Tag::Form => write!(f, "form"),
             Tag::H1 => write!(f, "h1"),
             Tag::H2 => write!(f, "h2"),
             Tag::H3 => write!(f, "h3"),
@@ -72,37 +191,291 @@ impl Display for Tag {
             Tag::H6 => write!(f, "h6"),
             Tag::Head => write!(f, "head"),
             Tag::Header => write!(f, "header"),
+            Tag::Hgroup => write!(f, "hgroup"),
+            Tag::Hr => write!(f, "hr"),
             Tag::Html => write!(f, "html"),
+            Tag::Iframe => write!(f, "iframe"),
             Tag::Input => write!(f, "input"),
             Tag::I => write!(f, "i"),
             Tag::Img => write!(f, "img"),
+            Tag::Ins => write!(f, "ins"),
             Tag::Kbd => write!(f, "kbd"),
             Tag::Label => write!(f, "label"),
+            Tag::Legend => write!(f, "legend"),
             Tag::Li => write!(f, "li"),
             Tag::Link => write!(f, "link"),
+            Tag::Main => write!(f, "main"),
+            Tag::Map => write!(f, "map"),
+            Tag::Mark => write!(f, "mark"),
+            Tag::Menu => write!(f, "menu"),
             Tag::Meta => write!(f, "meta"),
+            Tag::Meter => write!(f, "meter"),
             Tag::Nav => write!(f, "nav"),
+            Tag::Noscript => write!(f, "noscript"),
+            Tag::Object => write!(f, "object"),
             Tag::Ol => write!(f, "ol"),
+            Tag::Optgroup => write!(f, "optgroup"),
+            Tag::Option => write!(f, "option"),
+            Tag::Output => write!(f, "output"),
             Tag::P => write!(f, "p"),
+            Tag::Picture => write!(f, "picture"),
             Tag::Pre => write!(f, "pre"),
+            Tag::Progress => write!(f, "progress"),
             Tag::Q => write!(f, "q"),
+            // Trusted markup is written without a tag.
+            Tag::Raw => write!(f, ""),
+            Tag::Rp => write!(f, "rp"),
+            Tag::Rt => write!(f, "rt"),
+            Tag::Ruby => write!(f, "ruby"),
+            Tag::S => write!(f, "s"),
+            Tag::Samp => write!(f, "samp"),
             Tag::Script => write!(f, "script"),
+            Tag::Search => write!(f, "search"),
+            Tag::Section => write!(f, "section"),
+            Tag::Select => write!(f, "select"),
+            Tag::Slot => write!(f, "slot"),
+            Tag::Small => write!(f, "small"),
+            Tag::Source => write!(f, "source"),
             Tag::Span => write!(f, "span"),
             Tag::Strong => write!(f, "strong"),
+            Tag::Style => write!(f, "style"),
             Tag::Sub => write!(f, "sub"),
+            Tag::Summary => write!(f, "summary"),
             Tag::Sup => write!(f, "sup"),
             Tag::Table => write!(f, "table"),
+            // Text between elements is written without a tag.
+            Tag::Text => write!(f, ""),
+            Tag::Template => write!(f, "template"),
+            Tag::Textarea => write!(f, "textarea"),
+            Tag::Tfoot => write!(f, "tfoot"),
             Tag::Thead => write!(f, "thead"),
             Tag::Tbody => write!(f, "tbody"),
             Tag::Th => write!(f, "th"),
+            Tag::Time => write!(f, "time"),
             Tag::Tr => write!(f, "tr"),
             Tag::Td => write!(f, "td"),
             Tag::Title => write!(f, "title"),
+            Tag::Track => write!(f, "track"),
+            Tag::U => write!(f, "u"),
             Tag::Ul => write!(f, "ul"),
+            Tag::Var => write!(f, "var"),
+            Tag::Video => write!(f, "video"),
+            Tag::Wbr => write!(f, "wbr"),
+            Tag::Svg(tag) => write!(f, "{tag}"),
+            Tag::Math(tag) => write!(f, "{tag}"),
+            Tag::Custom(name) => write!(f, "{name}"),
+            Tag::Unknown(name) => write!(f, "{name}"),
         }
     }
 }
+impl FromStr for Tag {
+    type Err = UnknownTag;
+
+    // The reverse of `Display`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = match s.to_ascii_lowercase().as_str() {
+            "a" => Tag::A,
+            "abbr" => Tag::Abbr,
+            "address" => Tag::Address,
+            "area" => Tag::Area,
+            "article" => Tag::Article,
+            "aside" => Tag::Aside,
+            "audio" => Tag::Audio,
+            "b" => Tag::B,
+            "base" => Tag::Base,
+            "bdi" => Tag::Bdi,
+            "bdo" => Tag::Bdo,
+            "blockquote" => Tag::Blockquote,
+            "body" => Tag::Body,
+            "br" => Tag::Br,
+            "button" => Tag::Button,
+            "canvas" => Tag::Canvas,
+            "caption" => Tag::Caption,
+            "cite" => Tag::Cite,
+            "code" => Tag::Code,
+            "!--" => Tag::Comment,
+            "col" => Tag::Col,
+            "colgroup" => Tag::Colgroup,
+            "data" => Tag::Data,
+            "datalist" => Tag::Datalist,
+            "dd" => Tag::Dd,
+            "del" => Tag::Del,
+            "details" => Tag::Details,
+            "dfn" => Tag::Dfn,
+            "dialog" => Tag::Dialog,
+            "div" => Tag::Div,
+            "!doctype" => Tag::Doctype,
+            "dl" => Tag::Dl,
+            "dt" => Tag::Dt,
+            "em" => Tag::Em,
+            "embed" => Tag::Embed,
+            "fieldset" => Tag::Fieldset,
+            "figcaption" => Tag::Figcaption,
+            "figure" => Tag::Figure,
+            "footer" => Tag::Footer,
+            "form" => Tag::Form,
+            "h1" => Tag::H1,
+            "h2" => Tag::H2,
+            "h3" => Tag::H3,
+            "h4" => Tag::H4,
+            "h5" => Tag::H5,
+            "h6" => Tag::H6,
+            "head" => Tag::Head,
+            "header" => Tag::Header,
+            "hgroup" => Tag::Hgroup,
+            "hr" => Tag::Hr,
+            "html" => Tag::Html,
+            "i" => Tag::I,
+            "iframe" => Tag::Iframe,
+            "input" => Tag::Input,
+            "img" => Tag::Img,
+            "ins" => Tag::Ins,
+            "kbd" => Tag::Kbd,
+            "label" => Tag::Label,
+            "legend" => Tag::Legend,
+            "li" => Tag::Li,
+            "link" => Tag::Link,
+            "main" => Tag::Main,
+            "map" => Tag::Map,
+            "mark" => Tag::Mark,
+            "menu" => Tag::Menu,
+            "meta" => Tag::Meta,
+            "meter" => Tag::Meter,
+            "nav" => Tag::Nav,
+            "noscript" => Tag::Noscript,
+            "object" => Tag::Object,
+            "ol" => Tag::Ol,
+            "optgroup" => Tag::Optgroup,
+            "option" => Tag::Option,
+            "output" => Tag::Output,
+            "p" => Tag::P,
+            "picture" => Tag::Picture,
+            "pre" => Tag::Pre,
+            "progress" => Tag::Progress,
+            "q" => Tag::Q,
+            "rp" => Tag::Rp,
+            "rt" => Tag::Rt,
+            "ruby" => Tag::Ruby,
+            "s" => Tag::S,
+            "samp" => Tag::Samp,
+            "script" => Tag::Script,
+            "search" => Tag::Search,
+            "section" => Tag::Section,
+            "select" => Tag::Select,
+            "slot" => Tag::Slot,
+            "small" => Tag::Small,
+            "source" => Tag::Source,
+            "span" => Tag::Span,
+            "strong" => Tag::Strong,
+            "style" => Tag::Style,
+            "sub" => Tag::Sub,
+            "summary" => Tag::Summary,
+            "sup" => Tag::Sup,
+            "table" => Tag::Table,
+            "template" => Tag::Template,
+            "textarea" => Tag::Textarea,
+            "tfoot" => Tag::Tfoot,
+            "thead" => Tag::Thead,
+            "tbody" => Tag::Tbody,
+            "th" => Tag::Th,
+            "time" => Tag::Time,
+            "tr" => Tag::Tr,
+            "td" => Tag::Td,
+            "title" => Tag::Title,
+            "track" => Tag::Track,
+            "u" => Tag::U,
+            "ul" => Tag::Ul,
+            "var" => Tag::Var,
+            "video" => Tag::Video,
+            "wbr" => Tag::Wbr,
+            // The root of an SVG image. Other SVG elements are only
+            // recognized within it, see `SvgTag`.
+            "svg" => Tag::Svg(SvgTag::Svg),
+            // The root of a MathML formula, see `MathTag`.
+            "math" => Tag::Math(MathTag::Math),
+            // Any other valid custom element name.
+            name => {
+                return Tag::custom(name.to_string()).map_err(|_| UnknownTag(s.to_string()));
+            }
+        };
+        Ok(tag)
+    }
+}
+
+// Error returned when a name does not match a `Tag` variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownTag(pub String);
+
+impl Display for UnknownTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown HTML tag '{}'", self.0)
+    }
+}
+impl std::error::Error for UnknownTag {}
+
+// The name of a `Tag::Unknown`. It is only created by `Tag::unknown`, so it
+// is always a name and never markup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagName(String);
+
+impl TagName {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+impl Display for TagName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// Error returned when a name cannot be used as a tag name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidTagName(pub String);
+
+impl Display for InvalidTagName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid HTML tag name '{}'", self.0)
+    }
+}
+impl std::error::Error for InvalidTagName {}
+
 impl Tag {
+    // A tag without a variant. The name is an ASCII letter followed by
+    // ASCII letters, digits, `-`, `:` or `_`, as the parser reads it.
+    pub fn unknown(name: impl Into<String>) -> Result<Tag, InvalidTagName> {
+        let name = name.into();
+        let mut chars = name.chars();
+        let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | ':' | '_'));
+        match valid {
+            true => Ok(Tag::Unknown(TagName(name))),
+            false => Err(InvalidTagName(name)),
+        }
+    }
+    // A custom element, checking the name against the naming rules. It
+    // supports any attribute.
+    pub fn custom(name: impl Into<Cow<'static, str>>) -> Result<Tag, CustomTagError> {
+        CustomTag::new(name.into()).map(Tag::Custom)
+    }
+    // A custom element supporting the global attributes and `attrs`, so it
+    // is validated like any other tag. The attributes are `Attr` variants or
+    // any attribute names, such as `["variant", "size"]`. Keep the returned
+    // tag to build elements with, as the definition belongs to it.
+    pub fn define(
+        name: impl Into<Cow<'static, str>>,
+        attrs: impl IntoIterator<Item = impl ToString>,
+    ) -> Result<Tag, CustomTagError> {
+        CustomTag::define(name.into(), attrs).map(Tag::Custom)
+    }
+    // Whether the tag supports an attribute without an `Attr` variant. Every
+    // tag does, apart from custom elements defined without it.
+    pub(crate) fn supports_other(&self, name: &str) -> bool {
+        match self {
+            Tag::Custom(custom) => custom.supports_other(name),
+            _ => true,
+        }
+    }
     pub fn attributes(tag: &Tag) -> Vec<Attr> {
         match tag {
             // Elements that support global attributes only.
@@ -112,22 +485,296 @@ impl Tag {
                 attrs.extend_from_slice(&[
                     // A attributes
                     Attr::Href,
-                    Attr::Onclick,
                     Attr::Rel,
                     Attr::Target,
                 ]);
                 attrs
             }
+            Tag::Area => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Area attributes
+                    Attr::Alt,
+                    Attr::Href,
+                    Attr::Rel,
+                    Attr::Target,
+                ]);
+                attrs
+            }
+            Tag::Audio | Tag::Track => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Media attributes
+                    Attr::Src,
+                ]);
+                attrs
+            }
+            Tag::Base => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Base attributes
+                    Attr::Href,
+                    Attr::Target,
+                ]);
+                attrs
+            }
             Tag::Button => {
                 let mut attrs = Attr::global().to_vec();
                 attrs.extend_from_slice(&[
                     // Button attributes
                     Attr::Type,
-                    Attr::Onclick,
+                    Attr::Disabled,
+                    Attr::Name,
+                    Attr::Value,
                 ]);
                 attrs
             }
-            _ => Attr::all().to_vec(),
+            Tag::Canvas => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Canvas attributes
+                    Attr::Height,
+                    Attr::Width,
+                ]);
+                attrs
+            }
+            Tag::Embed | Tag::Source => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Embedded content attributes
+                    Attr::Height,
+                    Attr::Src,
+                    Attr::Type,
+                    Attr::Width,
+                ]);
+                attrs
+            }
+            Tag::Fieldset => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Fieldset attributes
+                    Attr::Disabled,
+                    Attr::Name,
+                ]);
+                attrs
+            }
+            Tag::Form => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Form attributes
+                    Attr::Action,
+                    Attr::Autocomplete,
+                    Attr::Enctype,
+                    Attr::Method,
+                    Attr::Name,
+                    Attr::Rel,
+                    Attr::Target,
+                ]);
+                attrs
+            }
+            Tag::Iframe => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Iframe attributes
+                    Attr::Height,
+                    Attr::Name,
+                    Attr::Src,
+                    Attr::Width,
+                ]);
+                attrs
+            }
+            Tag::Img => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Img attributes
+                    Attr::Alt,
+                    Attr::Height,
+                    Attr::Src,
+                    Attr::Width,
+                ]);
+                attrs
+            }
+            Tag::Input => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Input attributes
+                    Attr::Alt,
+                    Attr::Height,
+                    Attr::Name,
+                    Attr::Src,
+                    Attr::Type,
+                    Attr::Width,
+                    // Form control attributes
+                    Attr::Autocomplete,
+                    Attr::Checked,
+                    Attr::Disabled,
+                    Attr::Max,
+                    Attr::Maxlength,
+                    Attr::Min,
+                    Attr::Minlength,
+                    Attr::Multiple,
+                    Attr::Pattern,
+                    Attr::Placeholder,
+                    Attr::Readonly,
+                    Attr::Required,
+                    Attr::Step,
+                    Attr::Value,
+                ]);
+                attrs
+            }
+            Tag::Label => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Label attributes
+                    Attr::For,
+                ]);
+                attrs
+            }
+            Tag::Link => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Link attributes
+                    Attr::Az,
+                    Attr::Href,
+                    Attr::Rel,
+                    Attr::Type,
+                ]);
+                attrs
+            }
+            Tag::Meta => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Meta attributes
+                    Attr::Charset,
+                    Attr::Content,
+                    Attr::HttpEquiv,
+                    Attr::Name,
+                ]);
+                attrs
+            }
+            Tag::Object => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Object attributes
+                    Attr::Height,
+                    Attr::Name,
+                    Attr::Type,
+                    Attr::Width,
+                ]);
+                attrs
+            }
+            Tag::Optgroup => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Optgroup attributes
+                    Attr::Disabled,
+                ]);
+                attrs
+            }
+            Tag::Option => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Option attributes
+                    Attr::Disabled,
+                    Attr::Selected,
+                    Attr::Value,
+                ]);
+                attrs
+            }
+            Tag::Output => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Output attributes
+                    Attr::For,
+                    Attr::Name,
+                ]);
+                attrs
+            }
+            Tag::Script => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Script attributes
+                    Attr::Charset,
+                    Attr::Defer,
+                    Attr::Src,
+                    Attr::Type,
+                ]);
+                attrs
+            }
+            Tag::Select => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Select attributes
+                    Attr::Autocomplete,
+                    Attr::Disabled,
+                    Attr::Multiple,
+                    Attr::Name,
+                    Attr::Required,
+                ]);
+                attrs
+            }
+            Tag::Textarea => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Textarea attributes
+                    Attr::Autocomplete,
+                    Attr::Cols,
+                    Attr::Disabled,
+                    Attr::Maxlength,
+                    Attr::Minlength,
+                    Attr::Name,
+                    Attr::Placeholder,
+                    Attr::Readonly,
+                    Attr::Required,
+                    Attr::Rows,
+                ]);
+                attrs
+            }
+            Tag::Video => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend_from_slice(&[
+                    // Video attributes
+                    Attr::Height,
+                    Attr::Src,
+                    Attr::Width,
+                ]);
+                attrs
+            }
+            // SVG elements support SVG attributes, rather than HTML ones.
+            Tag::Svg(tag) => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend(tag.attributes().into_iter().map(Attr::Svg));
+                attrs
+            }
+            // MathML elements support MathML attributes.
+            Tag::Math(tag) => {
+                let mut attrs = Attr::global().to_vec();
+                attrs.extend(tag.attributes().into_iter().map(Attr::Math));
+                attrs
+            }
+            // Custom elements support the attributes they were defined with,
+            // or any attribute if they were not defined.
+            Tag::Custom(custom) => match custom.attributes() {
+                Some(defined) => {
+                    let mut attrs = Attr::global().to_vec();
+                    attrs.extend_from_slice(defined);
+                    attrs
+                }
+                None => Attr::all().to_vec(),
+            },
+            // Tags without a variant may have any attribute.
+            Tag::Unknown(_) => Attr::all().to_vec(),
+            // All other elements support global attributes only.
+            _ => Attr::global().to_vec(),
+        }
+    }
+    // Attributes an element must have to be valid.
+    pub fn required_attributes(tag: &Tag) -> Vec<Attr> {
+        match tag {
+            Tag::Img => vec![Attr::Src],
+            Tag::Link => vec![Attr::Href, Attr::Rel],
+            Tag::Track => vec![Attr::Src],
+            _ => vec![],
         }
     }
 }
@@ -146,6 +793,143 @@ mod tests {
         assert_eq!(Tag::Comment.to_string(), "!--");
     }
 
+    #[test]
+    fn test_tag_from_str() {
+        assert_eq!("div".parse::<Tag>(), Ok(Tag::Div));
+        assert_eq!("TBODY".parse::<Tag>(), Ok(Tag::Tbody));
+        assert_eq!("!DOCTYPE".parse::<Tag>(), Ok(Tag::Doctype));
+        assert_eq!("blink".parse::<Tag>(), Err(UnknownTag("blink".to_string())));
+        assert_eq!(Tag::unknown("blink").unwrap().to_string(), "blink");
+        assert_eq!(
+            Tag::unknown("x onclick=alert(1)"),
+            Err(InvalidTagName("x onclick=alert(1)".to_string()))
+        );
+        assert!(Tag::unknown("").is_err());
+    }
+
+    #[test]
+    fn test_every_tag_name() {
+        let tags = [
+            (Tag::A, "a"),
+            (Tag::Abbr, "abbr"),
+            (Tag::Address, "address"),
+            (Tag::Area, "area"),
+            (Tag::Article, "article"),
+            (Tag::Aside, "aside"),
+            (Tag::Audio, "audio"),
+            (Tag::B, "b"),
+            (Tag::Base, "base"),
+            (Tag::Bdi, "bdi"),
+            (Tag::Bdo, "bdo"),
+            (Tag::Blockquote, "blockquote"),
+            (Tag::Body, "body"),
+            (Tag::Br, "br"),
+            (Tag::Button, "button"),
+            (Tag::Canvas, "canvas"),
+            (Tag::Caption, "caption"),
+            (Tag::Cite, "cite"),
+            (Tag::Code, "code"),
+            (Tag::Col, "col"),
+            (Tag::Colgroup, "colgroup"),
+            (Tag::Data, "data"),
+            (Tag::Datalist, "datalist"),
+            (Tag::Dd, "dd"),
+            (Tag::Del, "del"),
+            (Tag::Details, "details"),
+            (Tag::Dfn, "dfn"),
+            (Tag::Dialog, "dialog"),
+            (Tag::Div, "div"),
+            (Tag::Dl, "dl"),
+            (Tag::Dt, "dt"),
+            (Tag::Em, "em"),
+            (Tag::Embed, "embed"),
+            (Tag::Fieldset, "fieldset"),
+            (Tag::Figcaption, "figcaption"),
+            (Tag::Figure, "figure"),
+            (Tag::Footer, "footer"),
+            (Tag::Form, "form"),
+            (Tag::H1, "h1"),
+            (Tag::H2, "h2"),
+            (Tag::H3, "h3"),
+            (Tag::H4, "h4"),
+            (Tag::H5, "h5"),
+            (Tag::H6, "h6"),
+            (Tag::Head, "head"),
+            (Tag::Header, "header"),
+            (Tag::Hgroup, "hgroup"),
+            (Tag::Hr, "hr"),
+            (Tag::Html, "html"),
+            (Tag::I, "i"),
+            (Tag::Iframe, "iframe"),
+            (Tag::Input, "input"),
+            (Tag::Img, "img"),
+            (Tag::Ins, "ins"),
+            (Tag::Kbd, "kbd"),
+            (Tag::Label, "label"),
+            (Tag::Legend, "legend"),
+            (Tag::Li, "li"),
+            (Tag::Link, "link"),
+            (Tag::Main, "main"),
+            (Tag::Map, "map"),
+            (Tag::Mark, "mark"),
+            (Tag::Menu, "menu"),
+            (Tag::Meta, "meta"),
+            (Tag::Meter, "meter"),
+            (Tag::Nav, "nav"),
+            (Tag::Noscript, "noscript"),
+            (Tag::Object, "object"),
+            (Tag::Ol, "ol"),
+            (Tag::Optgroup, "optgroup"),
+            (Tag::Option, "option"),
+            (Tag::Output, "output"),
+            (Tag::P, "p"),
+            (Tag::Picture, "picture"),
+            (Tag::Pre, "pre"),
+            (Tag::Progress, "progress"),
+            (Tag::Q, "q"),
+            (Tag::Rp, "rp"),
+            (Tag::Rt, "rt"),
+            (Tag::Ruby, "ruby"),
+            (Tag::S, "s"),
+            (Tag::Samp, "samp"),
+            (Tag::Script, "script"),
+            (Tag::Search, "search"),
+            (Tag::Section, "section"),
+            (Tag::Select, "select"),
+            (Tag::Slot, "slot"),
+            (Tag::Small, "small"),
+            (Tag::Source, "source"),
+            (Tag::Span, "span"),
+            (Tag::Strong, "strong"),
+            (Tag::Style, "style"),
+            (Tag::Sub, "sub"),
+            (Tag::Summary, "summary"),
+            (Tag::Sup, "sup"),
+            (Tag::Table, "table"),
+            (Tag::Template, "template"),
+            (Tag::Textarea, "textarea"),
+            (Tag::Tfoot, "tfoot"),
+            (Tag::Thead, "thead"),
+            (Tag::Tbody, "tbody"),
+            (Tag::Th, "th"),
+            (Tag::Time, "time"),
+            (Tag::Tr, "tr"),
+            (Tag::Td, "td"),
+            (Tag::Title, "title"),
+            (Tag::Track, "track"),
+            (Tag::U, "u"),
+            (Tag::Ul, "ul"),
+            (Tag::Var, "var"),
+            (Tag::Video, "video"),
+            (Tag::Wbr, "wbr"),
+        ];
+        for (tag, name) in tags {
+            assert_eq!(tag.to_string(), name);
+            assert_eq!(name.parse::<Tag>(), Ok(tag.clone()));
+            assert_eq!(name.to_uppercase().parse::<Tag>(), Ok(tag));
+        }
+    }
+
     // #[test]
     // fn test_global_attributes() {
     //     let global_attrs = vec![Attr::Az, Attr::Hidden, Attr::Id, Attr::Lang, Attr::Tabindex];
@@ -163,7 +947,7 @@ mod tests {
     #[test]
     fn test_a_tag_attributes() {
         let mut expected = Attr::global().to_vec();
-        expected.extend_from_slice(&[Attr::Href, Attr::Onclick, Attr::Rel, Attr::Target]);
+        expected.extend_from_slice(&[Attr::Href, Attr::Rel, Attr::Target]);
 
         assert_eq!(Tag::attributes(&Tag::A), expected);
     }
@@ -171,7 +955,7 @@ mod tests {
     #[test]
     fn test_button_tag_attributes() {
         let mut expected = Attr::global().to_vec();
-        expected.extend_from_slice(&[Attr::Type]);
+        expected.extend_from_slice(&[Attr::Type, Attr::Disabled, Attr::Name, Attr::Value]);
 
         assert_eq!(Tag::attributes(&Tag::Button), expected);
     }
@@ -179,8 +963,56 @@ mod tests {
     #[test]
     fn test_default_attributes() {
         // Test a tag that falls into the default case
-        let all_attrs = Attr::all().to_vec();
-        assert_eq!(Tag::attributes(&Tag::Div), all_attrs);
-        assert_eq!(Tag::attributes(&Tag::Span), all_attrs);
+        let global_attrs = Attr::global().to_vec();
+        assert_eq!(Tag::attributes(&Tag::Div), global_attrs);
+        assert_eq!(Tag::attributes(&Tag::Span), global_attrs);
+        assert_eq!(
+            Tag::attributes(&Tag::unknown("x").unwrap()),
+            Attr::all().to_vec()
+        );
+    }
+
+    #[test]
+    fn test_custom_tag() {
+        let picker = Tag::custom("my-date-picker").unwrap();
+        assert_eq!(picker.to_string(), "my-date-picker");
+        assert_eq!("My-Date-Picker".parse::<Tag>(), Ok(picker.clone()));
+        assert_eq!(Tag::attributes(&picker), Attr::all().to_vec());
+        assert_eq!(
+            Tag::custom("picker"),
+            Err(CustomTagError::MissingHyphen("picker".to_string()))
+        );
+        assert_eq!(
+            Tag::custom("a-b onclick=alert(1)"),
+            Err(CustomTagError::InvalidChar(
+                "a-b onclick=alert(1)".to_string(),
+                ' '
+            ))
+        );
+        assert_eq!(
+            "font-face".parse::<Tag>(),
+            Err(UnknownTag("font-face".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_define_custom_tag() {
+        let tag = Tag::define("user-avatar", [Attr::Src, Attr::Alt]).unwrap();
+        let mut expected = Attr::global().to_vec();
+        expected.extend_from_slice(&[Attr::Src, Attr::Alt]);
+
+        assert_eq!(tag.to_string(), "user-avatar");
+        assert_ne!(tag, Tag::custom("user-avatar").unwrap());
+        assert_eq!(Tag::attributes(&tag), expected);
+        assert_eq!(
+            Tag::define("avatar", [Attr::Src]),
+            Err(CustomTagError::MissingHyphen("avatar".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_required_attributes() {
+        assert_eq!(Tag::required_attributes(&Tag::Img), vec![Attr::Src]);
+        assert!(Tag::required_attributes(&Tag::Div).is_empty());
     }
 }
@@ -0,0 +1,286 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::attribute::MathAttr;
+
+// MathML elements, which can be used within an HTML document as children
+// of a `math` element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MathTag {
+    Annotation,
+    AnnotationXml,
+    Maction,
+    Math,
+    Menclose,
+    Merror,
+    Mfrac,
+    Mi,
+    Mmultiscripts,
+    Mn,
+    Mo,
+    Mover,
+    Mpadded,
+    Mphantom,
+    Mprescripts,
+    Mroot,
+    Mrow,
+    Ms,
+    Mspace,
+    Msqrt,
+    Mstyle,
+    Msub,
+    Msubsup,
+    Msup,
+    Mtable,
+    Mtd,
+    Mtext,
+    Mtr,
+    Munder,
+    Munderover,
+    Semantics,
+}
+impl Display for MathTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            MathTag::Annotation => "annotation",
+            MathTag::AnnotationXml => "annotation-xml",
+            MathTag::Maction => "maction",
+            MathTag::Math => "math",
+            MathTag::Menclose => "menclose",
+            MathTag::Merror => "merror",
+            MathTag::Mfrac => "mfrac",
+            MathTag::Mi => "mi",
+            MathTag::Mmultiscripts => "mmultiscripts",
+            MathTag::Mn => "mn",
+            MathTag::Mo => "mo",
+            MathTag::Mover => "mover",
+            MathTag::Mpadded => "mpadded",
+            MathTag::Mphantom => "mphantom",
+            MathTag::Mprescripts => "mprescripts",
+            MathTag::Mroot => "mroot",
+            MathTag::Mrow => "mrow",
+            MathTag::Ms => "ms",
+            MathTag::Mspace => "mspace",
+            MathTag::Msqrt => "msqrt",
+            MathTag::Mstyle => "mstyle",
+            MathTag::Msub => "msub",
+            MathTag::Msubsup => "msubsup",
+            MathTag::Msup => "msup",
+            MathTag::Mtable => "mtable",
+            MathTag::Mtd => "mtd",
+            MathTag::Mtext => "mtext",
+            MathTag::Mtr => "mtr",
+            MathTag::Munder => "munder",
+            MathTag::Munderover => "munderover",
+            MathTag::Semantics => "semantics",
+        };
+        f.write_str(name)
+    }
+}
+impl FromStr for MathTag {
+    type Err = UnknownMathTag;
+
+    // The reverse of `Display`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = match s.to_ascii_lowercase().as_str() {
+            "annotation" => MathTag::Annotation,
+            "annotation-xml" => MathTag::AnnotationXml,
+            "maction" => MathTag::Maction,
+            "math" => MathTag::Math,
+            "menclose" => MathTag::Menclose,
+            "merror" => MathTag::Merror,
+            "mfrac" => MathTag::Mfrac,
+            "mi" => MathTag::Mi,
+            "mmultiscripts" => MathTag::Mmultiscripts,
+            "mn" => MathTag::Mn,
+            "mo" => MathTag::Mo,
+            "mover" => MathTag::Mover,
+            "mpadded" => MathTag::Mpadded,
+            "mphantom" => MathTag::Mphantom,
+            "mprescripts" => MathTag::Mprescripts,
+            "mroot" => MathTag::Mroot,
+            "mrow" => MathTag::Mrow,
+            "ms" => MathTag::Ms,
+            "mspace" => MathTag::Mspace,
+            "msqrt" => MathTag::Msqrt,
+            "mstyle" => MathTag::Mstyle,
+            "msub" => MathTag::Msub,
+            "msubsup" => MathTag::Msubsup,
+            "msup" => MathTag::Msup,
+            "mtable" => MathTag::Mtable,
+            "mtd" => MathTag::Mtd,
+            "mtext" => MathTag::Mtext,
+            "mtr" => MathTag::Mtr,
+            "munder" => MathTag::Munder,
+            "munderover" => MathTag::Munderover,
+            "semantics" => MathTag::Semantics,
+            _ => return Err(UnknownMathTag(s.to_string())),
+        };
+        Ok(tag)
+    }
+}
+
+// Error returned when a name does not match a `MathTag` variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownMathTag(pub String);
+
+impl Display for UnknownMathTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown MathML tag '{}'", self.0)
+    }
+}
+impl std::error::Error for UnknownMathTag {}
+
+impl MathTag {
+    // The MathML attributes the element supports, in addition to the
+    // attributes every MathML element supports.
+    pub fn attributes(&self) -> Vec<MathAttr> {
+        use MathAttr::*;
+        let mut attrs = MathAttr::global().to_vec();
+        let specific: &[MathAttr] = match self {
+            MathTag::Math => &[Display, Alttext],
+            MathTag::Annotation | MathTag::AnnotationXml => &[Encoding],
+            MathTag::Maction => &[Actiontype, Selection],
+            MathTag::Menclose => &[Notation],
+            MathTag::Mfrac => &[Linethickness],
+            MathTag::Mo => &[
+                Fence,
+                Form,
+                Largeop,
+                Lspace,
+                Maxsize,
+                Minsize,
+                Movablelimits,
+                Rspace,
+                Separator,
+                Stretchy,
+                Symmetric,
+            ],
+            MathTag::Mover => &[Accent],
+            MathTag::Munder => &[Accentunder],
+            MathTag::Munderover => &[Accent, Accentunder],
+            MathTag::Mpadded => &[Depth, Height, Lspace, Voffset, Width],
+            MathTag::Mspace => &[Depth, Height, Width],
+            MathTag::Mtd => &[Columnspan, Rowspan],
+            _ => &[],
+        };
+        attrs.extend_from_slice(specific);
+        attrs
+    }
+
+    // Elements whose children are HTML rather than MathML: the token
+    // elements, which hold text, and `annotation-xml`.
+    pub fn is_html_integration_point(&self) -> bool {
+        matches!(
+            self,
+            MathTag::AnnotationXml
+                | MathTag::Mi
+                | MathTag::Mn
+                | MathTag::Mo
+                | MathTag::Ms
+                | MathTag::Mtext
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn fraction() -> Element {
+        let token =
+            |tag: MathTag, text: &str| ElementBuilder::new(Tag::Math(tag)).content(text).build();
+        ElementBuilder::new(Tag::Math(MathTag::Mfrac))
+            .children(vec![token(MathTag::Mi, "a"), token(MathTag::Mn, "2")])
+            .build()
+    }
+
+    #[test]
+    fn test_math_tag_names() {
+        assert_eq!(MathTag::AnnotationXml.to_string(), "annotation-xml");
+        assert_eq!("MFRAC".parse::<MathTag>(), Ok(MathTag::Mfrac));
+        assert_eq!("math".parse::<Tag>(), Ok(Tag::Math(MathTag::Math)));
+        assert!("mfrac".parse::<Tag>().is_err());
+    }
+
+    #[test]
+    fn test_math_display() {
+        let math = |display: MathDisplay| {
+            ElementBuilder::new(Tag::Math(MathTag::Math))
+                .attrs(Attrs::new().display(display).alttext("a over 2").build())
+                .children(vec![fraction()])
+                .build()
+        };
+        let p = ElementBuilder::new(Tag::P)
+            .content("Half of a is ")
+            .children(vec![math(MathDisplay::Inline)])
+            .build();
+        let div = ElementBuilder::new(Tag::Div)
+            .children(vec![math(MathDisplay::Block)])
+            .build();
+
+        assert_eq!(
+            p.to_string(),
+            concat!(
+                r#"<p>Half of a is <math display="inline" alttext="a over 2">"#,
+                "<mfrac><mi>a</mi><mn>2</mn></mfrac></math></p>"
+            )
+        );
+        assert_eq!(
+            format!(
+                "{:#}",
+                Document {
+                    doctype: Doctype::Html,
+                    elements: vec![div]
+                }
+            ),
+            concat!(
+                "<!DOCTYPE html>\n<div>\n",
+                r#"  <math display="block" alttext="a over 2">"#,
+                "\n    <mfrac><mi>a</mi><mn>2</mn></mfrac>\n  </math>\n</div>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_math_validation() {
+        let math = ElementBuilder::new(Tag::Math(MathTag::Math))
+            .attrs(Attrs::new().display(MathDisplay::Block).href("/").build())
+            .children(vec![fraction(), ElementBuilder::new(Tag::P).build()])
+            .build();
+        let errors: Vec<String> = math
+            .validate()
+            .unwrap_err()
+            .iter()
+            .map(|error| error.to_string())
+            .collect();
+
+        assert_eq!(
+            errors,
+            vec![
+                "math[0]: HTML tag 'math' does not support the 'href' attribute",
+                "math[0] > p[1]: HTML tag 'p' is not allowed within 'math'",
+            ]
+        );
+
+        // Token elements hold HTML, and MathML elements other than the root
+        // are not allowed within HTML.
+        let mi = ElementBuilder::new(Tag::Math(MathTag::Mi))
+            .children(vec![
+                ElementBuilder::new(Tag::B).build(),
+                ElementBuilder::new(Tag::Math(MathTag::Mn)).build(),
+            ])
+            .build();
+        assert_eq!(mi.validate().unwrap_err().len(), 1);
+        assert_eq!(
+            ElementBuilder::new(Tag::Div)
+                .children(vec![fraction()])
+                .build()
+                .validate()
+                .unwrap_err()
+                .len(),
+            1
+        );
+    }
+}
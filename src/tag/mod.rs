@@ -1,3 +1,11 @@
+mod custom;
 mod html;
+mod mathml;
+#[doc(hidden)]
+pub mod names;
+mod svg;
 
-pub use html::Tag;
+pub use custom::{CustomElements, CustomElementsBuilder, CustomTag, CustomTagError};
+pub use html::{InvalidTagName, Tag, TagName, UnknownTag};
+pub use mathml::{MathTag, UnknownMathTag};
+pub use svg::{SvgTag, UnknownSvgTag};
@@ -0,0 +1,193 @@
+// Lowercase names for each `Tag`, so the `html!` macro can resolve tags at
+// compile time. Pseudo tags such as `Tag::Text` are left out on purpose.
+#![allow(non_upper_case_globals)]
+
+use super::{MathTag, SvgTag, Tag};
+
+pub const a: Tag = Tag::A;
+pub const abbr: Tag = Tag::Abbr;
+pub const address: Tag = Tag::Address;
+pub const area: Tag = Tag::Area;
+pub const article: Tag = Tag::Article;
+pub const aside: Tag = Tag::Aside;
+pub const audio: Tag = Tag::Audio;
+pub const b: Tag = Tag::B;
+pub const base: Tag = Tag::Base;
+pub const bdi: Tag = Tag::Bdi;
+pub const bdo: Tag = Tag::Bdo;
+pub const blockquote: Tag = Tag::Blockquote;
+pub const body: Tag = Tag::Body;
+pub const br: Tag = Tag::Br;
+pub const button: Tag = Tag::Button;
+pub const canvas: Tag = Tag::Canvas;
+pub const caption: Tag = Tag::Caption;
+pub const cite: Tag = Tag::Cite;
+pub const code: Tag = Tag::Code;
+pub const col: Tag = Tag::Col;
+pub const colgroup: Tag = Tag::Colgroup;
+pub const data: Tag = Tag::Data;
+pub const datalist: Tag = Tag::Datalist;
+pub const dd: Tag = Tag::Dd;
+pub const del: Tag = Tag::Del;
+pub const details: Tag = Tag::Details;
+pub const dfn: Tag = Tag::Dfn;
+pub const dialog: Tag = Tag::Dialog;
+pub const div: Tag = Tag::Div;
+pub const dl: Tag = Tag::Dl;
+pub const dt: Tag = Tag::Dt;
+pub const em: Tag = Tag::Em;
+pub const embed: Tag = Tag::Embed;
+pub const fieldset: Tag = Tag::Fieldset;
+pub const figcaption: Tag = Tag::Figcaption;
+pub const figure: Tag = Tag::Figure;
+pub const footer: Tag = Tag::Footer;
+pub const form: Tag = Tag::Form;
+pub const h1: Tag = Tag::H1;
+pub const h2: Tag = Tag::H2;
+pub const h3: Tag = Tag::H3;
+pub const h4: Tag = Tag::H4;
+pub const h5: Tag = Tag::H5;
+pub const h6: Tag = Tag::H6;
+pub const head: Tag = Tag::Head;
+pub const header: Tag = Tag::Header;
+pub const hgroup: Tag = Tag::Hgroup;
+pub const hr: Tag = Tag::Hr;
+pub const html: Tag = Tag::Html;
+pub const i: Tag = Tag::I;
+pub const iframe: Tag = Tag::Iframe;
+pub const input: Tag = Tag::Input;
+pub const img: Tag = Tag::Img;
+pub const ins: Tag = Tag::Ins;
+pub const kbd: Tag = Tag::Kbd;
+pub const label: Tag = Tag::Label;
+pub const legend: Tag = Tag::Legend;
+pub const li: Tag = Tag::Li;
+pub const link: Tag = Tag::Link;
+pub const main: Tag = Tag::Main;
+pub const map: Tag = Tag::Map;
+pub const mark: Tag = Tag::Mark;
+pub const math: Tag = Tag::Math(MathTag::Math);
+pub const menu: Tag = Tag::Menu;
+pub const meta: Tag = Tag::Meta;
+pub const meter: Tag = Tag::Meter;
+pub const nav: Tag = Tag::Nav;
+pub const noscript: Tag = Tag::Noscript;
+pub const object: Tag = Tag::Object;
+pub const ol: Tag = Tag::Ol;
+pub const optgroup: Tag = Tag::Optgroup;
+pub const option: Tag = Tag::Option;
+pub const output: Tag = Tag::Output;
+pub const p: Tag = Tag::P;
+pub const picture: Tag = Tag::Picture;
+pub const pre: Tag = Tag::Pre;
+pub const progress: Tag = Tag::Progress;
+pub const q: Tag = Tag::Q;
+pub const rp: Tag = Tag::Rp;
+pub const rt: Tag = Tag::Rt;
+pub const ruby: Tag = Tag::Ruby;
+pub const s: Tag = Tag::S;
+pub const samp: Tag = Tag::Samp;
+pub const script: Tag = Tag::Script;
+pub const search: Tag = Tag::Search;
+pub const section: Tag = Tag::Section;
+pub const select: Tag = Tag::Select;
+pub const slot: Tag = Tag::Slot;
+pub const small: Tag = Tag::Small;
+pub const source: Tag = Tag::Source;
+pub const span: Tag = Tag::Span;
+pub const strong: Tag = Tag::Strong;
+pub const style: Tag = Tag::Style;
+pub const sub: Tag = Tag::Sub;
+pub const summary: Tag = Tag::Summary;
+pub const sup: Tag = Tag::Sup;
+pub const svg: Tag = Tag::Svg(SvgTag::Svg);
+pub const table: Tag = Tag::Table;
+pub const template: Tag = Tag::Template;
+pub const textarea: Tag = Tag::Textarea;
+pub const tfoot: Tag = Tag::Tfoot;
+pub const thead: Tag = Tag::Thead;
+pub const tbody: Tag = Tag::Tbody;
+pub const th: Tag = Tag::Th;
+pub const time: Tag = Tag::Time;
+pub const tr: Tag = Tag::Tr;
+pub const td: Tag = Tag::Td;
+pub const title: Tag = Tag::Title;
+pub const track: Tag = Tag::Track;
+pub const u: Tag = Tag::U;
+pub const ul: Tag = Tag::Ul;
+pub const var: Tag = Tag::Var;
+pub const video: Tag = Tag::Video;
+pub const wbr: Tag = Tag::Wbr;
+
+// SVG elements. Those named like an HTML element (`a`, `style` and `title`)
+// or a keyword (`use`) resolve to the HTML element or are left out.
+pub const animate: Tag = Tag::Svg(SvgTag::Animate);
+pub const animateMotion: Tag = Tag::Svg(SvgTag::AnimateMotion);
+pub const animateTransform: Tag = Tag::Svg(SvgTag::AnimateTransform);
+pub const circle: Tag = Tag::Svg(SvgTag::Circle);
+pub const clipPath: Tag = Tag::Svg(SvgTag::ClipPath);
+pub const defs: Tag = Tag::Svg(SvgTag::Defs);
+pub const desc: Tag = Tag::Svg(SvgTag::Desc);
+pub const ellipse: Tag = Tag::Svg(SvgTag::Ellipse);
+pub const feBlend: Tag = Tag::Svg(SvgTag::FeBlend);
+pub const feColorMatrix: Tag = Tag::Svg(SvgTag::FeColorMatrix);
+pub const feComposite: Tag = Tag::Svg(SvgTag::FeComposite);
+pub const feDropShadow: Tag = Tag::Svg(SvgTag::FeDropShadow);
+pub const feFlood: Tag = Tag::Svg(SvgTag::FeFlood);
+pub const feGaussianBlur: Tag = Tag::Svg(SvgTag::FeGaussianBlur);
+pub const feMerge: Tag = Tag::Svg(SvgTag::FeMerge);
+pub const feMergeNode: Tag = Tag::Svg(SvgTag::FeMergeNode);
+pub const feOffset: Tag = Tag::Svg(SvgTag::FeOffset);
+pub const filter: Tag = Tag::Svg(SvgTag::Filter);
+pub const foreignObject: Tag = Tag::Svg(SvgTag::ForeignObject);
+pub const g: Tag = Tag::Svg(SvgTag::G);
+pub const image: Tag = Tag::Svg(SvgTag::Image);
+pub const line: Tag = Tag::Svg(SvgTag::Line);
+pub const linearGradient: Tag = Tag::Svg(SvgTag::LinearGradient);
+pub const marker: Tag = Tag::Svg(SvgTag::Marker);
+pub const mask: Tag = Tag::Svg(SvgTag::Mask);
+pub const path: Tag = Tag::Svg(SvgTag::Path);
+pub const pattern: Tag = Tag::Svg(SvgTag::Pattern);
+pub const polygon: Tag = Tag::Svg(SvgTag::Polygon);
+pub const polyline: Tag = Tag::Svg(SvgTag::Polyline);
+pub const radialGradient: Tag = Tag::Svg(SvgTag::RadialGradient);
+pub const rect: Tag = Tag::Svg(SvgTag::Rect);
+pub const set: Tag = Tag::Svg(SvgTag::Set);
+pub const stop: Tag = Tag::Svg(SvgTag::Stop);
+pub const switch: Tag = Tag::Svg(SvgTag::Switch);
+pub const symbol: Tag = Tag::Svg(SvgTag::Symbol);
+pub const text: Tag = Tag::Svg(SvgTag::Text);
+pub const textPath: Tag = Tag::Svg(SvgTag::TextPath);
+pub const tspan: Tag = Tag::Svg(SvgTag::Tspan);
+pub const view: Tag = Tag::Svg(SvgTag::View);
+
+// MathML elements. `annotation-xml` is not a valid identifier, so it is left out.
+pub const annotation: Tag = Tag::Math(MathTag::Annotation);
+pub const maction: Tag = Tag::Math(MathTag::Maction);
+pub const menclose: Tag = Tag::Math(MathTag::Menclose);
+pub const merror: Tag = Tag::Math(MathTag::Merror);
+pub const mfrac: Tag = Tag::Math(MathTag::Mfrac);
+pub const mi: Tag = Tag::Math(MathTag::Mi);
+pub const mmultiscripts: Tag = Tag::Math(MathTag::Mmultiscripts);
+pub const mn: Tag = Tag::Math(MathTag::Mn);
+pub const mo: Tag = Tag::Math(MathTag::Mo);
+pub const mover: Tag = Tag::Math(MathTag::Mover);
+pub const mpadded: Tag = Tag::Math(MathTag::Mpadded);
+pub const mphantom: Tag = Tag::Math(MathTag::Mphantom);
+pub const mprescripts: Tag = Tag::Math(MathTag::Mprescripts);
+pub const mroot: Tag = Tag::Math(MathTag::Mroot);
+pub const mrow: Tag = Tag::Math(MathTag::Mrow);
+pub const ms: Tag = Tag::Math(MathTag::Ms);
+pub const mspace: Tag = Tag::Math(MathTag::Mspace);
+pub const msqrt: Tag = Tag::Math(MathTag::Msqrt);
+pub const mstyle: Tag = Tag::Math(MathTag::Mstyle);
+pub const msub: Tag = Tag::Math(MathTag::Msub);
+pub const msubsup: Tag = Tag::Math(MathTag::Msubsup);
+pub const msup: Tag = Tag::Math(MathTag::Msup);
+pub const mtable: Tag = Tag::Math(MathTag::Mtable);
+pub const mtd: Tag = Tag::Math(MathTag::Mtd);
+pub const mtext: Tag = Tag::Math(MathTag::Mtext);
+pub const mtr: Tag = Tag::Math(MathTag::Mtr);
+pub const munder: Tag = Tag::Math(MathTag::Munder);
+pub const munderover: Tag = Tag::Math(MathTag::Munderover);
+pub const semantics: Tag = Tag::Math(MathTag::Semantics);
@@ -0,0 +1,392 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::attribute::SvgAttr;
+
+// SVG elements, which can be used within an HTML document as children of
+// an `svg` element. Names keep their SVG casing, such as `linearGradient`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SvgTag {
+    A,
+    Animate,
+    AnimateMotion,
+    AnimateTransform,
+    Circle,
+    ClipPath,
+    Defs,
+    Desc,
+    Ellipse,
+    FeBlend,
+    FeColorMatrix,
+    FeComposite,
+    FeDropShadow,
+    FeFlood,
+    FeGaussianBlur,
+    FeMerge,
+    FeMergeNode,
+    FeOffset,
+    Filter,
+    ForeignObject,
+    G,
+    Image,
+    Line,
+    LinearGradient,
+    Marker,
+    Mask,
+    Path,
+    Pattern,
+    Polygon,
+    Polyline,
+    RadialGradient,
+    Rect,
+    Set,
+    Stop,
+    Style,
+    Svg,
+    Switch,
+    Symbol,
+    Text,
+    TextPath,
+    Title,
+    Tspan,
+    Use,
+    View,
+}
+impl Display for SvgTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            SvgTag::A => "a",
+            SvgTag::Animate => "animate",
+            SvgTag::AnimateMotion => "animateMotion",
+            SvgTag::AnimateTransform => "animateTransform",
+            SvgTag::Circle => "circle",
+            SvgTag::ClipPath => "clipPath",
+            SvgTag::Defs => "defs",
+            SvgTag::Desc => "desc",
+            SvgTag::Ellipse => "ellipse",
+            SvgTag::FeBlend => "feBlend",
+            SvgTag::FeColorMatrix => "feColorMatrix",
+            SvgTag::FeComposite => "feComposite",
+            SvgTag::FeDropShadow => "feDropShadow",
+            SvgTag::FeFlood => "feFlood",
+            SvgTag::FeGaussianBlur => "feGaussianBlur",
+            SvgTag::FeMerge => "feMerge",
+            SvgTag::FeMergeNode => "feMergeNode",
+            SvgTag::FeOffset => "feOffset",
+            SvgTag::Filter => "filter",
+            SvgTag::ForeignObject => "foreignObject",
+            SvgTag::G => "g",
+            SvgTag::Image => "image",
+            SvgTag::Line => "line",
+            SvgTag::LinearGradient => "linearGradient",
+            SvgTag::Marker => "marker",
+            SvgTag::Mask => "mask",
+            SvgTag::Path => "path",
+            SvgTag::Pattern => "pattern",
+            SvgTag::Polygon => "polygon",
+            SvgTag::Polyline => "polyline",
+            SvgTag::RadialGradient => "radialGradient",
+            SvgTag::Rect => "rect",
+            SvgTag::Set => "set",
+            SvgTag::Stop => "stop",
+            SvgTag::Style => "style",
+            SvgTag::Svg => "svg",
+            SvgTag::Switch => "switch",
+            SvgTag::Symbol => "symbol",
+            SvgTag::Text => "text",
+            SvgTag::TextPath => "textPath",
+            SvgTag::Title => "title",
+            SvgTag::Tspan => "tspan",
+            SvgTag::Use => "use",
+            SvgTag::View => "view",
+        };
+        f.write_str(name)
+    }
+}
+impl FromStr for SvgTag {
+    type Err = UnknownSvgTag;
+
+    // The reverse of `Display`, ignoring case as the HTML parser does.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = match s.to_ascii_lowercase().as_str() {
+            "a" => SvgTag::A,
+            "animate" => SvgTag::Animate,
+            "animatemotion" => SvgTag::AnimateMotion,
+            "animatetransform" => SvgTag::AnimateTransform,
+            "circle" => SvgTag::Circle,
+            "clippath" => SvgTag::ClipPath,
+            "defs" => SvgTag::Defs,
+            "desc" => SvgTag::Desc,
+            "ellipse" => SvgTag::Ellipse,
+            "feblend" => SvgTag::FeBlend,
+            "fecolormatrix" => SvgTag::FeColorMatrix,
+            "fecomposite" => SvgTag::FeComposite,
+            "fedropshadow" => SvgTag::FeDropShadow,
+            "feflood" => SvgTag::FeFlood,
+            "fegaussianblur" => SvgTag::FeGaussianBlur,
+            "femerge" => SvgTag::FeMerge,
+            "femergenode" => SvgTag::FeMergeNode,
+            "feoffset" => SvgTag::FeOffset,
+            "filter" => SvgTag::Filter,
+            "foreignobject" => SvgTag::ForeignObject,
+            "g" => SvgTag::G,
+            "image" => SvgTag::Image,
+            "line" => SvgTag::Line,
+            "lineargradient" => SvgTag::LinearGradient,
+            "marker" => SvgTag::Marker,
+            "mask" => SvgTag::Mask,
+            "path" => SvgTag::Path,
+            "pattern" => SvgTag::Pattern,
+            "polygon" => SvgTag::Polygon,
+            "polyline" => SvgTag::Polyline,
+            "radialgradient" => SvgTag::RadialGradient,
+            "rect" => SvgTag::Rect,
+            "set" => SvgTag::Set,
+            "stop" => SvgTag::Stop,
+            "style" => SvgTag::Style,
+            "svg" => SvgTag::Svg,
+            "switch" => SvgTag::Switch,
+            "symbol" => SvgTag::Symbol,
+            "text" => SvgTag::Text,
+            "textpath" => SvgTag::TextPath,
+            "title" => SvgTag::Title,
+            "tspan" => SvgTag::Tspan,
+            "use" => SvgTag::Use,
+            "view" => SvgTag::View,
+            _ => return Err(UnknownSvgTag(s.to_string())),
+        };
+        Ok(tag)
+    }
+}
+
+// Error returned when a name does not match an `SvgTag` variant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownSvgTag(pub String);
+
+impl Display for UnknownSvgTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown SVG tag '{}'", self.0)
+    }
+}
+impl std::error::Error for UnknownSvgTag {}
+
+impl SvgTag {
+    // The SVG attributes the element supports, in addition to the
+    // presentation attributes every element supports.
+    pub fn attributes(&self) -> Vec<SvgAttr> {
+        use SvgAttr::*;
+        let mut attrs = SvgAttr::presentation().to_vec();
+        let specific: &[SvgAttr] = match self {
+            SvgTag::Svg => &[X, Y, Width, Height, ViewBox, PreserveAspectRatio, Xmlns],
+            SvgTag::A => &[Href, Target],
+            SvgTag::Circle => &[Cx, Cy, R, PathLength],
+            SvgTag::Ellipse => &[Cx, Cy, Rx, Ry, PathLength],
+            SvgTag::Line => &[X1, Y1, X2, Y2, PathLength],
+            SvgTag::Path => &[D, PathLength],
+            SvgTag::Polygon | SvgTag::Polyline => &[Points, PathLength],
+            SvgTag::Rect => &[X, Y, Width, Height, Rx, Ry, PathLength],
+            SvgTag::Image => &[X, Y, Width, Height, Href, PreserveAspectRatio],
+            SvgTag::Use => &[X, Y, Width, Height, Href],
+            SvgTag::ForeignObject => &[X, Y, Width, Height],
+            SvgTag::Mask => &[X, Y, Width, Height, MaskUnits],
+            SvgTag::Symbol => &[
+                X,
+                Y,
+                Width,
+                Height,
+                ViewBox,
+                PreserveAspectRatio,
+                RefX,
+                RefY,
+            ],
+            SvgTag::Marker => &[
+                ViewBox,
+                PreserveAspectRatio,
+                MarkerWidth,
+                MarkerHeight,
+                MarkerUnits,
+                RefX,
+                RefY,
+                Orient,
+            ],
+            SvgTag::Pattern => &[
+                X,
+                Y,
+                Width,
+                Height,
+                Href,
+                ViewBox,
+                PreserveAspectRatio,
+                PatternUnits,
+                PatternContentUnits,
+                PatternTransform,
+            ],
+            SvgTag::ClipPath => &[ClipPathUnits],
+            SvgTag::LinearGradient => &[
+                X1,
+                Y1,
+                X2,
+                Y2,
+                Href,
+                GradientUnits,
+                GradientTransform,
+                SpreadMethod,
+            ],
+            SvgTag::RadialGradient => &[
+                Cx,
+                Cy,
+                R,
+                Fx,
+                Fy,
+                Href,
+                GradientUnits,
+                GradientTransform,
+                SpreadMethod,
+            ],
+            SvgTag::Stop => &[Offset, StopColor, StopOpacity],
+            SvgTag::Text | SvgTag::Tspan => &[X, Y, Dx, Dy, TextLength, LengthAdjust],
+            SvgTag::TextPath => &[Href, StartOffset, TextLength, LengthAdjust],
+            SvgTag::View => &[ViewBox, PreserveAspectRatio],
+            SvgTag::Filter => &[X, Y, Width, Height, FilterUnits],
+            SvgTag::FeBlend => &[X, Y, Width, Height, Result, In, In2, Mode],
+            SvgTag::FeColorMatrix => &[X, Y, Width, Height, Result, In, Type, Values],
+            SvgTag::FeComposite => &[X, Y, Width, Height, Result, In, In2, Operator],
+            SvgTag::FeDropShadow => &[
+                X,
+                Y,
+                Width,
+                Height,
+                Result,
+                In,
+                Dx,
+                Dy,
+                StdDeviation,
+                FloodColor,
+                FloodOpacity,
+            ],
+            SvgTag::FeFlood => &[X, Y, Width, Height, Result, FloodColor, FloodOpacity],
+            SvgTag::FeGaussianBlur => &[X, Y, Width, Height, Result, In, StdDeviation],
+            SvgTag::FeMerge => &[X, Y, Width, Height, Result],
+            SvgTag::FeMergeNode => &[In],
+            SvgTag::FeOffset => &[X, Y, Width, Height, Result, In, Dx, Dy],
+            SvgTag::Animate | SvgTag::Set | SvgTag::AnimateMotion => &[
+                AttributeName,
+                Begin,
+                Dur,
+                From,
+                To,
+                Values,
+                KeyTimes,
+                RepeatCount,
+            ],
+            SvgTag::AnimateTransform => &[
+                AttributeName,
+                Begin,
+                Dur,
+                From,
+                To,
+                Values,
+                KeyTimes,
+                RepeatCount,
+                Type,
+            ],
+            SvgTag::Style => &[Type],
+            SvgTag::Defs | SvgTag::Desc | SvgTag::G | SvgTag::Switch | SvgTag::Title => &[],
+        };
+        attrs.extend_from_slice(specific);
+        attrs
+    }
+
+    // Elements whose children are HTML rather than SVG.
+    pub fn is_html_integration_point(&self) -> bool {
+        matches!(self, SvgTag::ForeignObject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_svg_tag_names() {
+        for tag in [SvgTag::Svg, SvgTag::LinearGradient, SvgTag::FeGaussianBlur] {
+            assert_eq!(tag.to_string().parse::<SvgTag>(), Ok(tag));
+        }
+        assert_eq!(SvgTag::ClipPath.to_string(), "clipPath");
+        assert_eq!("CLIPPATH".parse::<SvgTag>(), Ok(SvgTag::ClipPath));
+        assert_eq!(
+            "blink".parse::<SvgTag>(),
+            Err(UnknownSvgTag("blink".to_string()))
+        );
+        assert_eq!("svg".parse::<Tag>(), Ok(Tag::Svg(SvgTag::Svg)));
+        assert!("rect".parse::<Tag>().is_err());
+    }
+
+    #[test]
+    fn test_svg_within_html() {
+        let chart = ElementBuilder::new(Tag::Svg(SvgTag::Svg))
+            .attrs(
+                Attrs::new()
+                    .view_box("0 0 100 50")
+                    .width("100")
+                    .aria_label("Sales")
+                    .build(),
+            )
+            .children(vec![ElementBuilder::new(Tag::Svg(SvgTag::Rect))
+                .attrs(
+                    Attrs::new()
+                        .x(0)
+                        .y(10)
+                        .width("25")
+                        .height("40")
+                        .fill("steelblue")
+                        .stroke_width(1.5)
+                        .href("/x")
+                        .build(),
+                )
+                .build()])
+            .build();
+        let figure = ElementBuilder::new(Tag::Figure)
+            .children(vec![chart])
+            .build();
+
+        // `href` is not an attribute of `rect`, so it is left out.
+        assert_eq!(
+            figure.to_string(),
+            concat!(
+                r#"<figure><svg viewBox="0 0 100 50" width="100" aria-label="Sales">"#,
+                r#"<rect x="0" y="10" width="25" height="40" fill="steelblue" stroke-width="1.5">"#,
+                "</rect></svg></figure>"
+            )
+        );
+        assert_eq!(
+            figure.validate().unwrap_err(),
+            vec![ValidationError::UnsupportedAttribute {
+                path: NodePath(vec![
+                    (0, Tag::Figure),
+                    (0, Tag::Svg(SvgTag::Svg)),
+                    (0, Tag::Svg(SvgTag::Rect))
+                ]),
+                tag: Tag::Svg(SvgTag::Rect),
+                attr: Attr::Svg(SvgAttr::Href),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_svg_in_html_macro() {
+        let icon = crate::html! {
+            svg(view_box = "0 0 24 24", aria_hidden = true) {
+                circle(cx = 12, cy = 12, r = 10, stroke_width = 2);
+            }
+        };
+        assert_eq!(
+            icon.to_string(),
+            concat!(
+                r#"<svg viewBox="0 0 24 24" aria-hidden="true">"#,
+                r#"<circle cx="12" cy="12" r="10" stroke-width="2"></circle></svg>"#
+            )
+        );
+    }
+}
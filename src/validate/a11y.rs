@@ -0,0 +1,334 @@
+use std::fmt::Display;
+
+use super::NodePath;
+use crate::attribute::Role;
+use crate::document::Document;
+use crate::element::Element;
+use crate::tag::Tag;
+
+// An accessibility problem, found by checking a tree against a subset of
+// the WCAG 2.1 success criteria.
+#[derive(Debug, Clone, PartialEq)]
+pub enum A11yIssue {
+    // An image without `alt` text.
+    MissingAlt {
+        path: NodePath,
+    },
+    // A button without text, `aria-label`, `aria-labelledby` or `title`.
+    MissingAccessibleName {
+        path: NodePath,
+        tag: Tag,
+    },
+    // A heading more than one level below the heading before it.
+    SkippedHeadingLevel {
+        path: NodePath,
+        previous: u8,
+        level: u8,
+    },
+    // A form control without a `label`, `aria-label` or `aria-labelledby`.
+    MissingLabel {
+        path: NodePath,
+        tag: Tag,
+    },
+    // A `role` that is not a WAI-ARIA role.
+    UnknownRole {
+        path: NodePath,
+        role: String,
+    },
+}
+impl A11yIssue {
+    pub fn path(&self) -> &NodePath {
+        match self {
+            A11yIssue::MissingAlt { path }
+            | A11yIssue::MissingAccessibleName { path, .. }
+            | A11yIssue::SkippedHeadingLevel { path, .. }
+            | A11yIssue::MissingLabel { path, .. }
+            | A11yIssue::UnknownRole { path, .. } => path,
+        }
+    }
+}
+impl Display for A11yIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            A11yIssue::MissingAlt { path } => {
+                write!(f, "{path}: image has no 'alt' text")
+            }
+            A11yIssue::MissingAccessibleName { path, tag } => {
+                write!(f, "{path}: HTML tag '{tag}' has no accessible name")
+            }
+            A11yIssue::SkippedHeadingLevel {
+                path,
+                previous,
+                level,
+            } => {
+                write!(f, "{path}: heading h{level} follows h{previous}")
+            }
+            A11yIssue::MissingLabel { path, tag } => {
+                write!(f, "{path}: HTML tag '{tag}' has no label")
+            }
+            A11yIssue::UnknownRole { path, role } => {
+                write!(f, "{path}: unknown ARIA role '{role}'")
+            }
+        }
+    }
+}
+impl std::error::Error for A11yIssue {}
+
+pub(crate) fn lint_document(document: &Document) -> Vec<A11yIssue> {
+    let mut lint = Lint::new(&document.elements);
+    for (index, element) in document.elements.iter().enumerate() {
+        let mut path = NodePath(vec![(index, element.tag.clone())]);
+        lint.element(element, &mut path, false);
+    }
+    lint.issues
+}
+
+pub(crate) fn lint_element(element: &Element) -> Vec<A11yIssue> {
+    let mut lint = Lint::new(std::slice::from_ref(element));
+    lint.element(
+        element,
+        &mut NodePath(vec![(0, element.tag.clone())]),
+        false,
+    );
+    lint.issues
+}
+
+struct Lint<'a> {
+    // The ids named by the `for` attribute of each `label`.
+    label_for: Vec<&'a str>,
+    // The level of the last heading seen.
+    heading: Option<u8>,
+    issues: Vec<A11yIssue>,
+}
+
+impl<'a> Lint<'a> {
+    fn new(roots: &'a [Element]) -> Self {
+        let mut label_for = vec![];
+        for root in roots {
+            collect_label_for(root, &mut label_for);
+        }
+        Lint {
+            label_for,
+            heading: None,
+            issues: vec![],
+        }
+    }
+
+    fn element(&mut self, element: &Element, path: &mut NodePath, in_label: bool) {
+        // Hidden content is not read out, so it is not checked.
+        if attr(element, "aria-hidden") == Some("true") {
+            return;
+        }
+        if let Some(roles) = attr(element, "role") {
+            for role in roles.split_ascii_whitespace() {
+                if role.parse::<Role>().is_err() {
+                    self.issues.push(A11yIssue::UnknownRole {
+                        path: path.clone(),
+                        role: role.to_string(),
+                    });
+                }
+            }
+        }
+
+        let input_type = attr(element, "type").map(str::to_ascii_lowercase);
+        match &element.tag {
+            Tag::Img if attr(element, "alt").is_none() && !is_presentational(element) => self
+                .issues
+                .push(A11yIssue::MissingAlt { path: path.clone() }),
+            Tag::Input
+                if input_type.as_deref() == Some("image")
+                    && !is_filled(attr(element, "alt"))
+                    && !has_label_attr(element) =>
+            {
+                self.issues
+                    .push(A11yIssue::MissingAlt { path: path.clone() })
+            }
+            Tag::Button if !has_label_attr(element) && !has_text(element) => {
+                self.issues.push(A11yIssue::MissingAccessibleName {
+                    path: path.clone(),
+                    tag: element.tag.clone(),
+                })
+            }
+            Tag::Input | Tag::Select | Tag::Textarea => {
+                // Buttons are labelled by their value, `alt` or a default label.
+                let needs_label = !matches!(
+                    input_type.as_deref(),
+                    Some("hidden" | "submit" | "reset" | "button" | "image")
+                );
+                let labelled = in_label
+                    || has_label_attr(element)
+                    || attr(element, "id").is_some_and(|id| self.label_for.contains(&id));
+                if needs_label && !labelled {
+                    self.issues.push(A11yIssue::MissingLabel {
+                        path: path.clone(),
+                        tag: element.tag.clone(),
+                    })
+                }
+            }
+            _ => {}
+        }
+        if let Some(level) = heading_level(&element.tag) {
+            if let Some(previous) = self.heading.filter(|previous| level > previous + 1) {
+                self.issues.push(A11yIssue::SkippedHeadingLevel {
+                    path: path.clone(),
+                    previous,
+                    level,
+                });
+            }
+            self.heading = Some(level);
+        }
+
+        let in_label = in_label || element.tag == Tag::Label;
+        for (index, child) in element.children.iter().flatten().enumerate() {
+            path.0.push((index, child.tag.clone()));
+            self.element(child, path, in_label);
+            path.0.pop();
+        }
+    }
+}
+
+fn collect_label_for<'a>(element: &'a Element, ids: &mut Vec<&'a str>) {
+    if element.tag == Tag::Label {
+        if let Some(id) = attr(element, "for") {
+            ids.push(id);
+        }
+    }
+    for child in element.children.iter().flatten() {
+        collect_label_for(child, ids);
+    }
+}
+
+fn attr<'a>(element: &'a Element, name: &str) -> Option<&'a str> {
+    element.attrs.as_ref()?.get(name)
+}
+
+fn is_filled(value: Option<&str>) -> bool {
+    value.is_some_and(|value| !value.trim().is_empty())
+}
+
+// Whether the element is named by an attribute rather than its content.
+fn has_label_attr(element: &Element) -> bool {
+    ["aria-label", "aria-labelledby", "title"]
+        .iter()
+        .any(|name| is_filled(attr(element, name)))
+}
+
+// Whether the element has text that can be read out.
+fn has_text(element: &Element) -> bool {
+    if attr(element, "aria-hidden") == Some("true") {
+        return false;
+    }
+    match element.tag {
+        Tag::Img => is_filled(attr(element, "alt")),
+        _ => {
+            is_filled(element.content.as_deref())
+                || has_label_attr(element)
+                || element.children.iter().flatten().any(has_text)
+        }
+    }
+}
+
+// Images marked as decorative do not need `alt` text.
+fn is_presentational(element: &Element) -> bool {
+    attr(element, "role").is_some_and(|role| matches!(role, "presentation" | "none"))
+}
+
+fn heading_level(tag: &Tag) -> Option<u8> {
+    match tag {
+        Tag::H1 => Some(1),
+        Tag::H2 => Some(2),
+        Tag::H3 => Some(3),
+        Tag::H4 => Some(4),
+        Tag::H5 => Some(5),
+        Tag::H6 => Some(6),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn element(tag: Tag, attrs: AttrsBuilder) -> Element {
+        ElementBuilder::new(tag).attrs(attrs.build()).build()
+    }
+
+    #[test]
+    fn test_accessible_tree() {
+        let form = ElementBuilder::new(Tag::Form)
+            .children(vec![
+                ElementBuilder::new(Tag::H1).content("Sign in").build(),
+                ElementBuilder::new(Tag::H2).content("Account").build(),
+                element(Tag::Img, Attrs::new().src("/logo.png").alt("")),
+                ElementBuilder::new(Tag::Label)
+                    .attrs(Attrs::new().attr("for", "email").build())
+                    .content("Email")
+                    .build(),
+                element(Tag::Input, Attrs::new().id("email").typ("email")),
+                ElementBuilder::new(Tag::Label)
+                    .content("Remember me")
+                    .children(vec![element(Tag::Input, Attrs::new().typ("checkbox"))])
+                    .build(),
+                element(Tag::Input, Attrs::new().typ("hidden").name("token")),
+                ElementBuilder::new(Tag::Button)
+                    .children(vec![element(
+                        Tag::Img,
+                        Attrs::new().src("/go.png").alt("Submit"),
+                    )])
+                    .build(),
+                element(Tag::Button, Attrs::new().aria_label("Close")),
+            ])
+            .build();
+
+        assert_eq!(form.lint_a11y(), Ok(()));
+    }
+
+    #[test]
+    fn test_a11y_issues() {
+        let body = ElementBuilder::new(Tag::Body)
+            .children(vec![
+                element(Tag::Img, Attrs::new().src("/a.png")),
+                ElementBuilder::new(Tag::Button)
+                    .children(vec![element(Tag::Img, Attrs::new().src("/x.png").alt(""))])
+                    .build(),
+                ElementBuilder::new(Tag::H1).content("Title").build(),
+                ElementBuilder::new(Tag::H3).content("Skipped").build(),
+                element(Tag::Input, Attrs::new().id("q")),
+                element(Tag::Div, Attrs::new().attr("role", "widget")),
+                element(Tag::Img, Attrs::new().src("/b.png").aria_hidden(true)),
+            ])
+            .build();
+
+        let issues = body.lint_a11y().unwrap_err();
+        assert_eq!(
+            issues,
+            vec![
+                A11yIssue::MissingAlt {
+                    path: NodePath(vec![(0, Tag::Body), (0, Tag::Img)]),
+                },
+                A11yIssue::MissingAccessibleName {
+                    path: NodePath(vec![(0, Tag::Body), (1, Tag::Button)]),
+                    tag: Tag::Button,
+                },
+                A11yIssue::SkippedHeadingLevel {
+                    path: NodePath(vec![(0, Tag::Body), (3, Tag::H3)]),
+                    previous: 1,
+                    level: 3,
+                },
+                A11yIssue::MissingLabel {
+                    path: NodePath(vec![(0, Tag::Body), (4, Tag::Input)]),
+                    tag: Tag::Input,
+                },
+                A11yIssue::UnknownRole {
+                    path: NodePath(vec![(0, Tag::Body), (5, Tag::Div)]),
+                    role: "widget".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            issues[2].to_string(),
+            "body[0] > h3[3]: heading h3 follows h1"
+        );
+    }
+}
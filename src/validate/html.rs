@@ -0,0 +1,735 @@
+use std::fmt::Display;
+
+use crate::attribute::Attr;
+use crate::document::Document;
+use crate::element::Element;
+use crate::tag::{CustomElements, MathTag, SvgTag, Tag};
+
+// The position of a node in a tree, as the sibling index and tag of each
+// element from the root down to the node.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodePath(pub Vec<(usize, Tag)>);
+
+impl NodePath {
+    pub fn tag(&self) -> Option<&Tag> {
+        self.0.last().map(|(_, tag)| tag)
+    }
+}
+impl Display for NodePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (index, tag)) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " > ")?;
+            }
+            write!(f, "{tag}[{index}]")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    // The tag does not support the attribute.
+    UnsupportedAttribute {
+        path: NodePath,
+        tag: Tag,
+        attr: Attr,
+    },
+    // The tag requires an attribute that is not set.
+    MissingRequiredAttribute {
+        path: NodePath,
+        tag: Tag,
+        attr: Attr,
+    },
+    // The child is not allowed within the parent.
+    InvalidNesting {
+        path: NodePath,
+        parent: Tag,
+        child: Tag,
+    },
+    // A void element such as `br` has children or content.
+    VoidElementWithChildren {
+        path: NodePath,
+        tag: Tag,
+    },
+    // A custom element was defined without the attribute, which has no
+    // `Attr` variant.
+    UndefinedAttribute {
+        path: NodePath,
+        tag: Tag,
+        name: String,
+    },
+    // Text within the opening tag, which only comments and doctypes have.
+    TextInOpeningTag {
+        path: NodePath,
+        tag: Tag,
+    },
+}
+impl ValidationError {
+    pub fn path(&self) -> &NodePath {
+        match self {
+            ValidationError::UnsupportedAttribute { path, .. }
+            | ValidationError::MissingRequiredAttribute { path, .. }
+            | ValidationError::InvalidNesting { path, .. }
+            | ValidationError::VoidElementWithChildren { path, .. }
+            | ValidationError::UndefinedAttribute { path, .. }
+            | ValidationError::TextInOpeningTag { path, .. } => path,
+        }
+    }
+}
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Without a path, as for a single deserialized element, only the
+        // problem is written.
+        if !self.path().0.is_empty() {
+            write!(f, "{}: ", self.path())?;
+        }
+        match self {
+            ValidationError::UnsupportedAttribute { tag, attr, .. } => {
+                write!(
+                    f,
+                    "HTML tag '{tag}' does not support the '{attr}' attribute"
+                )
+            }
+            ValidationError::MissingRequiredAttribute { tag, attr, .. } => {
+                write!(f, "HTML tag '{tag}' requires the '{attr}' attribute")
+            }
+            ValidationError::InvalidNesting { parent, child, .. } => {
+                write!(f, "HTML tag '{child}' is not allowed within '{parent}'")
+            }
+            ValidationError::VoidElementWithChildren { tag, .. } => {
+                write!(f, "void HTML tag '{tag}' cannot have content or children")
+            }
+            ValidationError::UndefinedAttribute { tag, name, .. } => {
+                write!(
+                    f,
+                    "HTML tag '{tag}' does not support the '{name}' attribute"
+                )
+            }
+            ValidationError::TextInOpeningTag { tag, .. } => {
+                write!(
+                    f,
+                    "HTML tag '{tag}' cannot have text within its opening tag"
+                )
+            }
+        }
+    }
+}
+impl std::error::Error for ValidationError {}
+
+pub(crate) fn validate_document(
+    document: &Document,
+    custom_elements: &CustomElements,
+) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    for (index, element) in document.elements.iter().enumerate() {
+        let mut path = NodePath(vec![(index, element.tag.clone())]);
+        validate(element, custom_elements, &mut path, &mut errors);
+    }
+    errors
+}
+
+pub(crate) fn validate_element(
+    element: &Element,
+    custom_elements: &CustomElements,
+) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    validate(
+        element,
+        custom_elements,
+        &mut NodePath(vec![(0, element.tag.clone())]),
+        &mut errors,
+    );
+    errors
+}
+
+fn validate(
+    element: &Element,
+    custom_elements: &CustomElements,
+    path: &mut NodePath,
+    errors: &mut Vec<ValidationError>,
+) {
+    // Custom elements without a definition are checked against the one
+    // they were registered with, if any.
+    let resolved = custom_elements.resolve(&element.tag);
+    let tag = resolved.as_ref().unwrap_or(&element.tag);
+    // Text, comments and raw markup have no attributes or children to check.
+    if matches!(tag, Tag::Text | Tag::Comment | Tag::Raw | Tag::Doctype) {
+        return;
+    }
+    errors.extend(validate_opening_tag(element, tag, path));
+    let present = element
+        .attrs
+        .as_ref()
+        .map_or(vec![], |attrs| attrs.present_on(tag));
+    for attr in Tag::required_attributes(tag) {
+        if !present.contains(&attr) {
+            errors.push(ValidationError::MissingRequiredAttribute {
+                path: path.clone(),
+                tag: tag.clone(),
+                attr,
+            });
+        }
+    }
+
+    let children = element.children.as_deref().unwrap_or_default();
+    if Element::is_void(tag) && (element.content.is_some() || !children.is_empty()) {
+        errors.push(ValidationError::VoidElementWithChildren {
+            path: path.clone(),
+            tag: tag.clone(),
+        });
+    }
+
+    for (index, child) in children.iter().enumerate() {
+        path.0.push((index, child.tag.clone()));
+        if !allows_child(tag, &child.tag) {
+            errors.push(ValidationError::InvalidNesting {
+                path: path.clone(),
+                parent: tag.clone(),
+                child: child.tag.clone(),
+            });
+        }
+        validate(child, custom_elements, path, errors);
+        path.0.pop();
+    }
+}
+
+// What rendering the opening tag of the element would drop: text, which
+// only comments and doctypes have, and attributes the tag does not support.
+// The deserializers reject these too.
+pub(crate) fn validate_opening_tag(
+    element: &Element,
+    tag: &Tag,
+    path: &NodePath,
+) -> Vec<ValidationError> {
+    let mut errors = vec![];
+    if element.text.is_some() && !matches!(tag, Tag::Comment | Tag::Doctype) {
+        errors.push(ValidationError::TextInOpeningTag {
+            path: path.clone(),
+            tag: tag.clone(),
+        });
+    }
+    let Some(attrs) = &element.attrs else {
+        return errors;
+    };
+    let supported = Tag::attributes(tag);
+    for attr in attrs
+        .present_on(tag)
+        .into_iter()
+        .filter(|attr| !supported.contains(attr))
+    {
+        errors.push(ValidationError::UnsupportedAttribute {
+            path: path.clone(),
+            tag: tag.clone(),
+            attr,
+        });
+    }
+    let others = attrs
+        .iter()
+        .filter(|(name, _)| Attr::from_name_on(tag, name).is_none() && !tag.supports_other(name));
+    for (name, _) in others {
+        errors.push(ValidationError::UndefinedAttribute {
+            path: path.clone(),
+            tag: tag.clone(),
+            name: name.to_string(),
+        });
+    }
+    errors
+}
+
+// Whether `child` may be a direct child of `parent`.
+fn allows_child(parent: &Tag, child: &Tag) -> bool {
+    // Text, comments and raw markup may appear anywhere.
+    if matches!(child, Tag::Text | Tag::Comment | Tag::Raw) {
+        return true;
+    }
+    // Custom elements and tags without a variant are not checked.
+    if matches!(parent, Tag::Custom(_) | Tag::Unknown(_))
+        || matches!(child, Tag::Custom(_) | Tag::Unknown(_))
+    {
+        return true;
+    }
+    // SVG and MathML elements only contain elements of their own namespace,
+    // apart from integration points such as `foreignObject` which contain
+    // HTML. HTML elements only contain the `svg` and `math` roots.
+    match (parent, child) {
+        (Tag::Svg(parent), child) if !parent.is_html_integration_point() => {
+            return matches!(child, Tag::Svg(_));
+        }
+        (Tag::Math(parent), child) if !parent.is_html_integration_point() => {
+            return matches!(child, Tag::Math(_));
+        }
+        (_, Tag::Svg(SvgTag::Svg) | Tag::Math(MathTag::Math)) => {}
+        (_, Tag::Svg(_) | Tag::Math(_)) => return false,
+        _ => {}
+    }
+    // Some elements are only allowed within specific parents.
+    let parent_allowed = match child {
+        Tag::Head | Tag::Body => matches!(parent, Tag::Html),
+        Tag::Li => matches!(parent, Tag::Ul | Tag::Ol | Tag::Menu),
+        Tag::Dt | Tag::Dd => matches!(parent, Tag::Dl | Tag::Div),
+        Tag::Caption | Tag::Colgroup | Tag::Thead | Tag::Tbody | Tag::Tfoot => {
+            matches!(parent, Tag::Table)
+        }
+        Tag::Col => matches!(parent, Tag::Colgroup),
+        Tag::Tr => matches!(parent, Tag::Table | Tag::Thead | Tag::Tbody | Tag::Tfoot),
+        Tag::Th | Tag::Td => matches!(parent, Tag::Tr),
+        Tag::Option => matches!(parent, Tag::Select | Tag::Datalist | Tag::Optgroup),
+        Tag::Optgroup => matches!(parent, Tag::Select),
+        Tag::Source => matches!(parent, Tag::Picture | Tag::Audio | Tag::Video),
+        Tag::Track => matches!(parent, Tag::Audio | Tag::Video),
+        Tag::Figcaption => matches!(parent, Tag::Figure),
+        Tag::Legend => matches!(parent, Tag::Fieldset),
+        Tag::Summary => matches!(parent, Tag::Details),
+        Tag::Rt | Tag::Rp => matches!(parent, Tag::Ruby),
+        Tag::Html | Tag::Doctype => false,
+        _ => true,
+    };
+    parent_allowed
+        && match parent {
+            Tag::Html => matches!(child, Tag::Head | Tag::Body),
+            Tag::Head => is_metadata(child),
+            Tag::Ul | Tag::Ol | Tag::Menu => matches!(child, Tag::Li) || is_script(child),
+            Tag::Dl => matches!(child, Tag::Dt | Tag::Dd | Tag::Div) || is_script(child),
+            Tag::Table => {
+                matches!(
+                    child,
+                    Tag::Caption | Tag::Colgroup | Tag::Thead | Tag::Tbody | Tag::Tfoot | Tag::Tr
+                ) || is_script(child)
+            }
+            Tag::Colgroup => matches!(child, Tag::Col | Tag::Template),
+            Tag::Thead | Tag::Tbody | Tag::Tfoot => matches!(child, Tag::Tr) || is_script(child),
+            Tag::Tr => matches!(child, Tag::Th | Tag::Td) || is_script(child),
+            Tag::Select => {
+                matches!(child, Tag::Option | Tag::Optgroup | Tag::Hr) || is_script(child)
+            }
+            Tag::Optgroup => matches!(child, Tag::Option) || is_script(child),
+            Tag::Datalist => matches!(child, Tag::Option) || is_phrasing(child),
+            Tag::Picture => matches!(child, Tag::Source | Tag::Img) || is_script(child),
+            // Interactive elements cannot be nested.
+            Tag::A | Tag::Button => is_phrasing(child) && !is_interactive(child),
+            // Elements with phrasing content only.
+            Tag::Abbr
+            | Tag::B
+            | Tag::Bdi
+            | Tag::Bdo
+            | Tag::Cite
+            | Tag::Code
+            | Tag::Data
+            | Tag::Dfn
+            | Tag::Dt
+            | Tag::Em
+            | Tag::H1
+            | Tag::H2
+            | Tag::H3
+            | Tag::H4
+            | Tag::H5
+            | Tag::H6
+            | Tag::I
+            | Tag::Kbd
+            | Tag::Label
+            | Tag::Legend
+            | Tag::Mark
+            | Tag::Meter
+            | Tag::Output
+            | Tag::P
+            | Tag::Pre
+            | Tag::Progress
+            | Tag::Q
+            | Tag::Rp
+            | Tag::Rt
+            | Tag::Ruby
+            | Tag::S
+            | Tag::Samp
+            | Tag::Small
+            | Tag::Span
+            | Tag::Strong
+            | Tag::Sub
+            | Tag::Summary
+            | Tag::Sup
+            | Tag::Time
+            | Tag::U
+            | Tag::Var => is_phrasing(child),
+            // Elements with text content only.
+            Tag::Option | Tag::Script | Tag::Style | Tag::Textarea | Tag::Title => false,
+            _ => !is_metadata(child) || is_script(child) || matches!(child, Tag::Style),
+        }
+}
+
+// Elements that belong in the document head.
+fn is_metadata(tag: &Tag) -> bool {
+    matches!(
+        tag,
+        Tag::Base
+            | Tag::Link
+            | Tag::Meta
+            | Tag::Noscript
+            | Tag::Script
+            | Tag::Style
+            | Tag::Template
+            | Tag::Title
+    )
+}
+
+// Elements that may appear wherever scripts are allowed.
+fn is_script(tag: &Tag) -> bool {
+    matches!(tag, Tag::Script | Tag::Template)
+}
+
+// Elements that can appear within a paragraph of text.
+fn is_phrasing(tag: &Tag) -> bool {
+    matches!(
+        tag,
+        Tag::A
+            | Tag::Abbr
+            | Tag::Area
+            | Tag::Audio
+            | Tag::B
+            | Tag::Bdi
+            | Tag::Bdo
+            | Tag::Br
+            | Tag::Button
+            | Tag::Canvas
+            | Tag::Cite
+            | Tag::Code
+            | Tag::Data
+            | Tag::Datalist
+            | Tag::Del
+            | Tag::Dfn
+            | Tag::Em
+            | Tag::Embed
+            | Tag::I
+            | Tag::Iframe
+            | Tag::Img
+            | Tag::Input
+            | Tag::Ins
+            | Tag::Kbd
+            | Tag::Label
+            | Tag::Map
+            | Tag::Mark
+            | Tag::Math(MathTag::Math)
+            | Tag::Meter
+            | Tag::Noscript
+            | Tag::Object
+            | Tag::Output
+            | Tag::Picture
+            | Tag::Progress
+            | Tag::Q
+            | Tag::Ruby
+            | Tag::S
+            | Tag::Samp
+            | Tag::Script
+            | Tag::Select
+            | Tag::Slot
+            | Tag::Small
+            | Tag::Span
+            | Tag::Strong
+            | Tag::Sub
+            | Tag::Sup
+            | Tag::Svg(SvgTag::Svg)
+            | Tag::Template
+            | Tag::Textarea
+            | Tag::Time
+            | Tag::U
+            | Tag::Var
+            | Tag::Video
+            | Tag::Wbr
+    )
+}
+
+// Elements the user can interact with.
+fn is_interactive(tag: &Tag) -> bool {
+    matches!(
+        tag,
+        Tag::A
+            | Tag::Button
+            | Tag::Details
+            | Tag::Embed
+            | Tag::Iframe
+            | Tag::Input
+            | Tag::Label
+            | Tag::Select
+            | Tag::Textarea
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_valid_element() {
+        let nav = ElementBuilder::new(Tag::Nav)
+            .children(vec![ElementBuilder::new(Tag::Ul)
+                .children(vec![ElementBuilder::new(Tag::Li)
+                    .children(vec![ElementBuilder::new(Tag::A)
+                        .attrs(Attrs::new().href("/").build())
+                        .content("Home")
+                        .build()])
+                    .build()])
+                .build()])
+            .build();
+
+        assert_eq!(nav.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_unsupported_attribute() {
+        let div = ElementBuilder::new(Tag::Div)
+            .attrs(Attrs::new().id("x").href("/").build())
+            .build();
+        let body = ElementBuilder::new(Tag::Body).children(vec![div]).build();
+
+        let errors = body.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![ValidationError::UnsupportedAttribute {
+                path: NodePath(vec![(0, Tag::Body), (0, Tag::Div)]),
+                tag: Tag::Div,
+                attr: Attr::Href,
+            }]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "body[0] > div[0]: HTML tag 'div' does not support the 'href' attribute"
+        );
+    }
+
+    #[test]
+    fn test_svg_nesting() {
+        let svg = |tag: SvgTag| ElementBuilder::new(Tag::Svg(tag));
+        let valid = ElementBuilder::new(Tag::Span)
+            .children(vec![svg(SvgTag::Svg)
+                .children(vec![
+                    svg(SvgTag::G)
+                        .children(vec![svg(SvgTag::Circle).build()])
+                        .build(),
+                    svg(SvgTag::ForeignObject)
+                        .children(vec![ElementBuilder::new(Tag::P).build()])
+                        .build(),
+                ])
+                .build()])
+            .build();
+        assert_eq!(valid.validate(), Ok(()));
+
+        let invalid = ElementBuilder::new(Tag::Div)
+            .children(vec![
+                svg(SvgTag::Rect).build(),
+                svg(SvgTag::Svg)
+                    .children(vec![ElementBuilder::new(Tag::P).build()])
+                    .build(),
+            ])
+            .build();
+        let errors: Vec<String> = invalid
+            .validate()
+            .unwrap_err()
+            .iter()
+            .map(|error| error.to_string())
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "div[0] > rect[0]: HTML tag 'rect' is not allowed within 'div'",
+                "div[0] > svg[1] > p[0]: HTML tag 'p' is not allowed within 'svg'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_element_attributes() {
+        let open = ElementBuilder::new(Tag::custom("open-widget").unwrap())
+            .attrs(Attrs::new().href("/").build())
+            .children(vec![ElementBuilder::new(Tag::Li).build()])
+            .build();
+        assert_eq!(open.validate(), Ok(()));
+
+        let tag = Tag::define("rating-stars", ["name", "max-stars"]).unwrap();
+        let rating = ElementBuilder::new(tag.clone())
+            .attrs(
+                Attrs::new()
+                    .name("score")
+                    .href("/")
+                    .attr("max-stars", "5")
+                    .attr("colour", "red")
+                    .build(),
+            )
+            .build();
+        assert_eq!(
+            rating.validate().unwrap_err(),
+            vec![
+                ValidationError::UnsupportedAttribute {
+                    path: NodePath(vec![(0, tag.clone())]),
+                    tag: tag.clone(),
+                    attr: Attr::Href,
+                },
+                ValidationError::UndefinedAttribute {
+                    path: NodePath(vec![(0, tag.clone())]),
+                    tag: tag.clone(),
+                    name: "colour".to_string(),
+                }
+            ]
+        );
+        assert_eq!(
+            rating.to_string(),
+            r#"<rating-stars name="score" max-stars="5"></rating-stars>"#
+        );
+
+        // Without a definition, the same name is open unless validated
+        // with the registered elements.
+        let other = ElementBuilder::new(Tag::custom("rating-stars").unwrap())
+            .attrs(Attrs::new().attr("colour", "red").build())
+            .build();
+        assert_eq!(other.validate(), Ok(()));
+        let elements = CustomElements::new()
+            .define("rating-stars", ["name", "max-stars"])
+            .build();
+        assert_eq!(
+            other.validate_with(&elements).unwrap_err(),
+            vec![ValidationError::UndefinedAttribute {
+                path: NodePath(vec![(0, Tag::custom("rating-stars").unwrap())]),
+                tag: tag.clone(),
+                name: "colour".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_with_custom_elements() {
+        let elements = CustomElements::new()
+            .define("rating-stars", ["max-stars"])
+            .build();
+        let html = r#"<div><rating-stars max-stars="5" id="r" colour="red"></rating-stars></div>"#;
+
+        let parsed = parse_fragment_with(html, &elements).unwrap().remove(0);
+        let rating = &parsed.children.as_ref().unwrap()[0];
+        assert_eq!(rating.tag, elements.get("rating-stars").unwrap());
+        assert_eq!(
+            parsed.validate().unwrap_err()[0].to_string(),
+            "div[0] > rating-stars[0]: HTML tag 'rating-stars' does not support the 'colour' attribute"
+        );
+        // Rendering drops what the definition does not allow.
+        assert_eq!(
+            parsed.to_string(),
+            r#"<div><rating-stars max-stars="5" id="r"></rating-stars></div>"#
+        );
+        assert_eq!(parse_fragment(html).unwrap()[0].validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_missing_required_attribute() {
+        let img = ElementBuilder::new(Tag::Img)
+            .attrs(Attrs::new().alt("logo").build())
+            .build();
+
+        assert_eq!(
+            img.validate().unwrap_err(),
+            vec![ValidationError::MissingRequiredAttribute {
+                path: NodePath(vec![(0, Tag::Img)]),
+                tag: Tag::Img,
+                attr: Attr::Src,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_nesting() {
+        let p = ElementBuilder::new(Tag::P)
+            .content("text")
+            .children(vec![
+                ElementBuilder::new(Tag::Span).build(),
+                ElementBuilder::new(Tag::Div).build(),
+            ])
+            .build();
+        let ul = ElementBuilder::new(Tag::Ul)
+            .children(vec![ElementBuilder::new(Tag::Div).build()])
+            .build();
+        let a = ElementBuilder::new(Tag::A)
+            .children(vec![ElementBuilder::new(Tag::A).build()])
+            .build();
+        let li = ElementBuilder::new(Tag::Li).build();
+
+        assert_eq!(
+            p.validate().unwrap_err(),
+            vec![ValidationError::InvalidNesting {
+                path: NodePath(vec![(0, Tag::P), (1, Tag::Div)]),
+                parent: Tag::P,
+                child: Tag::Div,
+            }]
+        );
+        assert_eq!(ul.validate().unwrap_err().len(), 1);
+        assert_eq!(a.validate().unwrap_err().len(), 1);
+        assert_eq!(li.validate(), Ok(()));
+        let div = ElementBuilder::new(Tag::Div).children(vec![li]).build();
+        assert_eq!(div.validate().unwrap_err().len(), 1);
+    }
+
+    #[test]
+    fn test_nesting_of_form_and_media_elements() {
+        let select = ElementBuilder::new(Tag::Select)
+            .children(vec![
+                ElementBuilder::new(Tag::Option).content("One").build(),
+                ElementBuilder::new(Tag::Optgroup)
+                    .children(vec![ElementBuilder::new(Tag::Option).build()])
+                    .build(),
+            ])
+            .build();
+        let video = ElementBuilder::new(Tag::Video)
+            .children(vec![ElementBuilder::new(Tag::Track)
+                .attrs(Attrs::new().src("/captions.vtt").build())
+                .build()])
+            .build();
+        let option = ElementBuilder::new(Tag::Div)
+            .children(vec![ElementBuilder::new(Tag::Option).build()])
+            .build();
+        let summary = ElementBuilder::new(Tag::Section)
+            .children(vec![ElementBuilder::new(Tag::Summary).build()])
+            .build();
+
+        assert_eq!(select.validate(), Ok(()));
+        assert_eq!(video.validate(), Ok(()));
+        assert_eq!(option.validate().unwrap_err().len(), 1);
+        assert_eq!(summary.validate().unwrap_err().len(), 1);
+    }
+
+    #[test]
+    fn test_void_element_with_children() {
+        let br = ElementBuilder::new(Tag::Br).content("text").build();
+
+        assert_eq!(
+            br.validate().unwrap_err(),
+            vec![ValidationError::VoidElementWithChildren {
+                path: NodePath(vec![(0, Tag::Br)]),
+                tag: Tag::Br,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_document_reports_every_error() {
+        let doc = Document {
+            doctype: Doctype::Html,
+            elements: vec![ElementBuilder::new(Tag::Html)
+                .children(vec![
+                    ElementBuilder::new(Tag::Head)
+                        .children(vec![ElementBuilder::new(Tag::Div).build()])
+                        .build(),
+                    ElementBuilder::new(Tag::Body)
+                        .children(vec![
+                            ElementBuilder::new(Tag::Text).content("hi").build(),
+                            ElementBuilder::new(Tag::Img).build(),
+                            ElementBuilder::new(Tag::Span)
+                                .attrs(Attrs::new().src("x").build())
+                                .build(),
+                        ])
+                        .build(),
+                ])
+                .build()],
+        };
+
+        let errors = doc.validate().unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[1].path().to_string(), "html[0] > body[1] > img[1]");
+        assert_eq!(errors[2].path().tag(), Some(&Tag::Span));
+    }
+}
@@ -0,0 +1,10 @@
+mod a11y;
+mod html;
+
+pub use a11y::A11yIssue;
+pub use html::{NodePath, ValidationError};
+
+pub(crate) use a11y::{lint_document, lint_element};
+#[cfg(any(feature = "serde", feature = "jsonml"))]
+pub(crate) use html::validate_opening_tag;
+pub(crate) use html::{validate_document, validate_element};
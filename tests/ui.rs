@@ -0,0 +1,16 @@
+// Code that must not compile, checked against the compiler output in
+// `tests/ui/*.stderr`. The output changes between compiler versions, so
+// this only runs on the version it was written with. After upgrading,
+// update the version and rerun with `TRYBUILD=overwrite`.
+#[rustversion::attr(not(stable(1.95)), ignore = "compiler output differs between versions")]
+#[test]
+fn test_html_macro_rejects_unknown_names() {
+    trybuild::TestCases::new().compile_fail("tests/ui/html_*.rs");
+}
+
+#[cfg(feature = "derive")]
+#[rustversion::attr(not(stable(1.95)), ignore = "compiler output differs between versions")]
+#[test]
+fn test_derive_rejects_misplaced_options() {
+    trybuild::TestCases::new().compile_fail("tests/ui/derive_*.rs");
+}
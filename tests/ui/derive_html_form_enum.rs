@@ -0,0 +1,9 @@
+use htmlatron::prelude::*;
+
+#[derive(HtmlForm)]
+enum Plan {
+    Free,
+    Team,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use htmlatron::prelude::*;
+
+#[derive(HtmlForm)]
+#[html_form(label = "Sign up")]
+struct Signup {
+    name: String,
+}
+
+#[derive(HtmlForm)]
+struct Login {
+    #[html_form(action = "/login")]
+    name: String,
+}
+
+#[derive(FormValue)]
+enum Plan {
+    #[html_form(input = "radio")]
+    Free,
+}
+
+fn main() {}
@@ -0,0 +1,3 @@
+fn main() {
+    let _page = htmlatron::html! { div(colour = "red") {} };
+}